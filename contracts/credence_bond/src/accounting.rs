@@ -0,0 +1,172 @@
+//! Accounting Self-Check
+//!
+//! Reconciles the contract's counters against the records they summarise. Bonds are scanned a
+//! page at a time through the identity index; a running sum is kept in instance storage between
+//! calls and compared with the total bonded counter on the final page. Discrepancies are
+//! reported as typed entries instead of failing the call.
+//!
+//! A run observes bonds as they are when each page is scanned, so bond writes in the middle of
+//! a run can produce a spurious `TotalBonded` entry. Run it while the contract is paused for an
+//! exact result.
+//!
+//! When a completed run finds the total bonded counter off, the drift is kept so the admin can
+//! apply it with `repair_total_bonded`. Later bond writes move the counter by correct deltas,
+//! so the drift stays valid until repaired or replaced by the next completed run.
+
+use soroban_sdk::{contracttype, panic_with_error, Env, Symbol, Vec};
+
+use crate::{
+    bond_store, events, insurance, AccountingCheck, AccountingDiscrepancy, BondError,
+    VerificationReport,
+};
+
+/// Storage key for the in-progress verification run.
+const KEY_VERIFY_STATE: &str = "acct_verify";
+/// Storage key for the total bonded drift found by the last completed run.
+const KEY_TOTAL_DRIFT: &str = "acct_drift";
+
+/// Maximum bonds scanned per call.
+pub const MAX_VERIFY_PAGE: u32 = 50;
+
+/// Running state of a verification run.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VerificationState {
+    pub next_cursor: u32,
+    pub bonded_sum: i128,
+    pub discrepancies: Vec<AccountingDiscrepancy>,
+}
+
+fn discrepancy(check: AccountingCheck, expected: i128, actual: i128) -> AccountingDiscrepancy {
+    AccountingDiscrepancy {
+        check,
+        expected,
+        actual,
+    }
+}
+
+/// Scan up to `limit` bonds (capped at `MAX_VERIFY_PAGE`) starting at `cursor`. A cursor of 0
+/// starts a new run; any other cursor must continue the run in progress.
+///
+/// # Panics
+/// * `BondError::VerificationOutOfOrder` if `cursor` does not continue the run in progress
+pub fn verify_page(e: &Env, cursor: u32, limit: u32) -> VerificationReport {
+    let key = Symbol::new(e, KEY_VERIFY_STATE);
+    let mut state = if cursor == 0 {
+        VerificationState {
+            next_cursor: 0,
+            bonded_sum: 0,
+            discrepancies: Vec::new(e),
+        }
+    } else {
+        e.storage()
+            .instance()
+            .get::<_, VerificationState>(&key)
+            .filter(|s| s.next_cursor == cursor)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::VerificationOutOfOrder))
+    };
+
+    let page = bond_store::list_identities(e, cursor, limit.min(MAX_VERIFY_PAGE));
+    for identity in page.iter() {
+        if let Some(bond) = bond_store::load_bond(e, &identity) {
+            state.bonded_sum = state
+                .bonded_sum
+                .checked_add(bond.bonded_amount)
+                .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+            if bond.slashed_amount > bond.bonded_amount {
+                state.discrepancies.push_back(discrepancy(
+                    AccountingCheck::BondSlashed(identity.clone()),
+                    bond.bonded_amount,
+                    bond.slashed_amount,
+                ));
+            }
+        }
+    }
+    let scanned = page.len();
+    let count = bond_store::get_identity_count(e);
+    state.next_cursor = cursor.saturating_add(limit.min(MAX_VERIFY_PAGE)).min(count);
+
+    if state.next_cursor < count {
+        e.storage().instance().set(&key, &state);
+        return VerificationReport {
+            scanned,
+            next_cursor: state.next_cursor,
+            complete: false,
+            discrepancies: Vec::new(e),
+        };
+    }
+
+    e.storage().instance().remove(&key);
+    let mut discrepancies = state.discrepancies;
+    let total_bonded = bond_store::get_total_bonded(e);
+    let drift_key = Symbol::new(e, KEY_TOTAL_DRIFT);
+    if state.bonded_sum != total_bonded {
+        discrepancies.push_back(discrepancy(
+            AccountingCheck::TotalBonded,
+            state.bonded_sum,
+            total_bonded,
+        ));
+        e.storage()
+            .instance()
+            .set(&drift_key, &(state.bonded_sum - total_bonded));
+    } else {
+        e.storage().instance().remove(&drift_key);
+    }
+
+    let stats = insurance::get_deposit_stats(e);
+    // Premiums reserved or paid as referral rewards have left the pool but stay accounted
+    let pool = insurance::get_pool_balance(e).saturating_add(insurance::get_referral_outflow(e));
+    if pool != stats.total_premium {
+        discrepancies.push_back(discrepancy(
+            AccountingCheck::InsurancePool,
+            stats.total_premium,
+            pool,
+        ));
+    }
+    let accounted = stats.total_premium.saturating_add(stats.total_net);
+    if accounted != stats.total_gross {
+        discrepancies.push_back(discrepancy(
+            AccountingCheck::DepositStats,
+            accounted,
+            stats.total_gross,
+        ));
+    }
+
+    events::publish(
+        e,
+        (Symbol::new(e, "accounting_verified"),),
+        (discrepancies.len(), e.ledger().timestamp()),
+    );
+    VerificationReport {
+        scanned,
+        next_cursor: state.next_cursor,
+        complete: true,
+        discrepancies,
+    }
+}
+
+/// Apply the total bonded drift found by the last completed run. Only admin should call
+/// (enforced by caller). Emits `total_bonded_repaired` with (old_total, new_total).
+///
+/// # Panics
+/// * `BondError::NoAccountingDrift` if no drift is recorded
+pub fn repair_total_bonded(e: &Env) -> i128 {
+    let drift_key = Symbol::new(e, KEY_TOTAL_DRIFT);
+    let drift: i128 = e
+        .storage()
+        .instance()
+        .get(&drift_key)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NoAccountingDrift));
+    let old_total = bond_store::get_total_bonded(e);
+    let new_total = old_total
+        .checked_add(drift)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    bond_store::set_total_bonded(e, new_total);
+    e.storage().instance().remove(&drift_key);
+    events::publish(
+        e,
+        (Symbol::new(e, "total_bonded_repaired"),),
+        (old_total, new_total),
+    );
+    new_total
+}
@@ -0,0 +1,87 @@
+//! Budget Benchmarks
+//!
+//! Stress entry points for profiling the batch code paths against Soroban resource limits.
+//! Each function registers a fresh contract, builds `n` synthetic bonds through the public
+//! entry points, then makes exactly one measured call to the real batch path and returns the
+//! resources the host metered for that call alone.
+//!
+//! Only compiled for tests or with the `bench` feature. Figures come from the native test
+//! host, so Wasm VM costs are not included; treat them as lower bounds when comparing against
+//! network limits.
+
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{CredenceBond, CredenceBondClient};
+
+/// Bond amount used for every synthetic bond.
+const BENCH_BOND_AMOUNT: i128 = 1_000_000_000;
+/// Amount slashed from each bond by `bench_batch_slash`.
+const BENCH_SLASH_AMOUNT: i128 = 1_000_000;
+
+/// Resources metered for one measured invocation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BenchReport {
+    pub instructions: i64,
+    pub mem_bytes: i64,
+    pub read_entries: u32,
+    pub write_entries: u32,
+    pub read_bytes: u32,
+    pub write_bytes: u32,
+}
+
+impl BenchReport {
+    fn last_invocation(e: &Env) -> Self {
+        let res = e.cost_estimate().resources();
+        BenchReport {
+            instructions: res.instructions,
+            mem_bytes: res.mem_bytes,
+            read_entries: res.read_entries,
+            write_entries: res.write_entries,
+            read_bytes: res.read_bytes,
+            write_bytes: res.write_bytes,
+        }
+    }
+}
+
+/// Register and initialize a contract holding `n` fixed bonds; returns the admin and the
+/// bonded identities in creation order.
+fn setup_bonds(e: &Env, n: u32) -> (CredenceBondClient<'_>, Address, Vec<Address>) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let mut identities = Vec::new(e);
+    for _ in 0..n {
+        let identity = Address::generate(e);
+        client.create_bond(&identity, &BENCH_BOND_AMOUNT, &86400_u64, &false, &0_u64);
+        identities.push_back(identity);
+    }
+    (client, admin, identities)
+}
+
+/// Slash `n` bonds in one `batch_slash` call.
+pub fn bench_batch_slash(e: &Env, n: u32) -> BenchReport {
+    let (client, admin, identities) = setup_bonds(e, n);
+    let mut slashes = Vec::new(e);
+    for identity in identities.iter() {
+        slashes.push_back((identity, BENCH_SLASH_AMOUNT));
+    }
+    client.batch_slash(&admin, &slashes);
+    BenchReport::last_invocation(e)
+}
+
+/// Scan `n` bonds with one `verify_accounting` page of `page` bonds from cursor 0.
+pub fn bench_verify_accounting(e: &Env, n: u32, page: u32) -> BenchReport {
+    let (client, _admin, _identities) = setup_bonds(e, n);
+    client.verify_accounting(&0, &page);
+    BenchReport::last_invocation(e)
+}
+
+/// List one page of `page` identities out of `n` bonded with `list_bond_identities`.
+pub fn bench_pagination(e: &Env, n: u32, page: u32) -> BenchReport {
+    let (client, _admin, _identities) = setup_bonds(e, n);
+    client.list_bond_identities(&0, &page);
+    BenchReport::last_invocation(e)
+}
@@ -0,0 +1,65 @@
+//! Bond Delegation
+//!
+//! An identity can nominate one delegate, typically a hot wallet, to maintain its bond:
+//! `top_up_as` and `extend_duration_as` accept either the identity or its delegate. A delegate
+//! pays for its own top-ups. A contract identity's controller (see `contract_identity`) may
+//! make the same calls. Withdrawals, closure and every other operation still require the
+//! identity itself.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+use crate::DelegateError;
+
+/// Storage keys for bond delegates.
+#[contracttype]
+pub enum DelegateKey {
+    /// Delegate nominated by the identity (persistent storage).
+    Delegate(Address),
+}
+
+/// Returns the identity's delegate, if any.
+#[must_use]
+pub fn get_delegate(e: &Env, identity: &Address) -> Option<Address> {
+    e.storage()
+        .persistent()
+        .get(&DelegateKey::Delegate(identity.clone()))
+}
+
+/// Nominate `delegate` for `identity`, replacing any previous one. Auth enforced by caller.
+///
+/// # Panics
+/// * `DelegateError::SelfDelegation` if `delegate` is the identity
+pub fn set_delegate(e: &Env, identity: &Address, delegate: &Address) {
+    if delegate == identity {
+        panic_with_error!(e, DelegateError::SelfDelegation);
+    }
+    e.storage()
+        .persistent()
+        .set(&DelegateKey::Delegate(identity.clone()), delegate);
+}
+
+/// Remove and return the identity's delegate, if any. Auth enforced by caller.
+pub fn revoke_delegate(e: &Env, identity: &Address) -> Option<Address> {
+    let delegate = get_delegate(e, identity);
+    if delegate.is_some() {
+        e.storage()
+            .persistent()
+            .remove(&DelegateKey::Delegate(identity.clone()));
+    }
+    delegate
+}
+
+/// Require `caller`'s auth and that it is the identity, the identity's delegate or its
+/// controller.
+///
+/// # Panics
+/// * `DelegateError::NotDelegate` if `caller` is none of them
+pub fn require_identity_or_delegate(e: &Env, caller: &Address, identity: &Address) {
+    caller.require_auth();
+    if caller != identity
+        && get_delegate(e, identity).as_ref() != Some(caller)
+        && !crate::contract_identity::is_controller(e, caller, identity)
+    {
+        panic_with_error!(e, DelegateError::NotDelegate);
+    }
+}
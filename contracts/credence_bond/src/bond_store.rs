@@ -0,0 +1,216 @@
+//! Bond Storage
+//!
+//! Bonds are stored per identity under `DataKey::Bond(identity)`. Every bond read and write
+//! goes through this module so the storage layout, the total bonded counter, the changelog
+//! and the tier qualification record stay consistent.
+//!
+//! Each identity is also appended to an enumeration index the first time its bond is
+//! written, so off-chain tooling can list bonded identities without tracking them itself.
+//!
+//! Bonds live in persistent storage, so each bond has its own TTL rather than sharing the
+//! contract instance's. Every read and write extends it, and anyone can extend it with
+//! `refresh_bond_ttl`. Both also extend the identity's per-bond entries kept by other modules
+//! (such as the recorded tier), so those never expire before the bond does.
+//!
+//! ## Legacy layouts
+//! Earlier deployments kept a single bond under the unit key `DataKey::Bond`, and later ones
+//! kept per-identity bonds in instance storage. Reads fall back to either slot, and the first
+//! write for that identity moves the bond into persistent storage and deletes the old slot.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::{
+    changelog, referral, rolling_bond, slashing, tiered_bond, BondError, DataKey, IdentityBond,
+};
+
+/// Storage key for the total bonded amount across all identities.
+const KEY_TOTAL_BONDED: &str = "total_bonded";
+
+/// Remaining TTL (in ledgers) below which a bond's TTL is extended (~1 day at 5 s/ledger).
+pub const BUMP_THRESHOLD: u32 = 17_280;
+/// TTL (in ledgers) a bond is extended to (~30 days).
+pub const BUMP_TARGET: u32 = 518_400;
+
+/// Key layout used by single-bond deployments. Encodes identically to the former
+/// unit variant `DataKey::Bond`.
+#[contracttype]
+pub(crate) enum LegacyDataKey {
+    Bond,
+}
+
+/// Read the legacy single-bond slot if it belongs to `identity`.
+fn load_legacy(e: &Env, identity: &Address) -> Option<IdentityBond> {
+    e.storage()
+        .instance()
+        .get::<_, IdentityBond>(&LegacyDataKey::Bond)
+        .filter(|bond| &bond.identity == identity)
+}
+
+/// Extend the TTL of a persistent entry if it exists.
+pub fn bump_if_present<K: IntoVal<Env, Val>>(e: &Env, key: &K) {
+    let storage = e.storage().persistent();
+    if storage.has(key) {
+        storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_TARGET);
+    }
+}
+
+/// Extend the TTL of the per-identity entries that bond writes read, so they stay live as
+/// long as the bond does.
+fn bump_identity_entries(e: &Env, identity: &Address) {
+    tiered_bond::bump_ttl(e, identity);
+    rolling_bond::bump_ttl(e, identity);
+    changelog::bump_ttl(e, identity);
+}
+
+/// Returns the identity's bond, if it has ever bonded, extending its TTL (and that of the
+/// identity's other per-bond entries).
+#[must_use]
+pub fn load(e: &Env, identity: &Address) -> Option<IdentityBond> {
+    let bond = load_bond(e, identity);
+    if bond.is_some() {
+        bump_identity_entries(e, identity);
+    }
+    bond
+}
+
+/// Returns the identity's bond, if it has ever bonded, extending only the bond's TTL. Used by
+/// scans that read many bonds and nothing else.
+#[must_use]
+pub fn load_bond(e: &Env, identity: &Address) -> Option<IdentityBond> {
+    let key = DataKey::Bond(identity.clone());
+    let storage = e.storage().persistent();
+    if let Some(bond) = storage.get(&key) {
+        storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        return Some(bond);
+    }
+    e.storage()
+        .instance()
+        .get(&key)
+        .or_else(|| load_legacy(e, identity))
+}
+
+/// Extend the TTL of the identity's bond. A bond still in a legacy layout is moved into
+/// persistent storage, which sets its TTL.
+///
+/// # Panics
+/// * `BondError::NoBond` if the identity has never bonded
+pub fn refresh_ttl(e: &Env, identity: &Address) {
+    let key = DataKey::Bond(identity.clone());
+    if e.storage().persistent().has(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        bump_identity_entries(e, identity);
+    } else {
+        let bond = get(e, identity);
+        save(e, Some(&bond), &bond, "refresh_ttl");
+    }
+}
+
+/// Returns the identity's bond. Fails with `BondError::NoBond` if the identity has never bonded.
+#[must_use]
+pub fn get(e: &Env, identity: &Address) -> IdentityBond {
+    load(e, identity).unwrap_or_else(|| panic_with_error!(e, BondError::NoBond))
+}
+
+/// Reject operations on a bond that is no longer active (fully withdrawn or closed).
+///
+/// # Panics
+/// * `BondError::BondNotActive` if `bond.active` is false
+pub fn require_bond_active(e: &Env, bond: &IdentityBond) {
+    if !bond.active {
+        panic_with_error!(e, BondError::BondNotActive);
+    }
+}
+
+/// Returns the total bonded amount across all identities.
+#[must_use]
+pub fn get_total_bonded(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_TOTAL_BONDED))
+        .unwrap_or(0)
+}
+
+/// Overwrite the total bonded counter. Only used to repair a counter found to be out of sync;
+/// `save` keeps it current otherwise.
+pub fn set_total_bonded(e: &Env, total: i128) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_TOTAL_BONDED), &total);
+}
+
+/// Returns the number of identities that have ever bonded.
+#[must_use]
+pub fn get_identity_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::BondIdentityCount)
+        .unwrap_or(0)
+}
+
+/// Returns up to `limit` bonded identities starting at index `start` (first bonded first).
+#[must_use]
+pub fn list_identities(e: &Env, start: u32, limit: u32) -> Vec<Address> {
+    let end = start.saturating_add(limit).min(get_identity_count(e));
+    let mut page = Vec::new(e);
+    for i in start..end {
+        if let Some(identity) = e.storage().persistent().get(&DataKey::BondIdentity(i)) {
+            page.push_back(identity);
+        }
+    }
+    page
+}
+
+/// Append an identity to the enumeration index.
+fn index_identity(e: &Env, identity: &Address) {
+    let count = get_identity_count(e);
+    e.storage()
+        .persistent()
+        .set(&DataKey::BondIdentity(count), identity);
+    let next = count
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&DataKey::BondIdentityCount, &next);
+}
+
+/// Persist a bond under its identity, keep the total bonded counter in sync and record the
+/// changelog and tier qualification. `before` is the bond as it was loaded (`None` for a new bond).
+pub fn save(e: &Env, before: Option<&IdentityBond>, bond: &IdentityBond, cause: &str) {
+    let key = DataKey::Bond(bond.identity.clone());
+    let in_instance = e.storage().instance().has(&key);
+    let is_new = !in_instance && !e.storage().persistent().has(&key);
+    let migrating = is_new && load_legacy(e, &bond.identity).is_some();
+    // A legacy bond was never included in the total, so it is counted in full on migration.
+    let old_amount = if migrating {
+        0
+    } else {
+        before.map(|b| b.bonded_amount).unwrap_or(0)
+    };
+    let total = get_total_bonded(e)
+        .checked_sub(old_amount)
+        .and_then(|t| t.checked_add(bond.bonded_amount))
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    set_total_bonded(e, total);
+
+    e.storage().persistent().set(&key, bond);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+    if is_new {
+        index_identity(e, &bond.identity);
+    }
+    if in_instance {
+        e.storage().instance().remove(&key);
+    }
+    if migrating {
+        e.storage().instance().remove(&LegacyDataKey::Bond);
+    }
+
+    changelog::record_changes(e, before, bond, cause);
+    tiered_bond::track_qualification(e, bond);
+    referral::settle_on_close(e, before, bond);
+    slashing::track_creation(e, before, bond);
+}
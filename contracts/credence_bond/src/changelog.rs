@@ -0,0 +1,164 @@
+//! Bond Changelog
+//!
+//! Opt-in, per-identity log of field-level bond mutations (field, old value, new value,
+//! cause, timestamp) for support and debugging. Disabled by default to keep write costs
+//! down; once enabled, every bond write records one entry per changed field.
+//! Retention is bounded: when the log exceeds the configured count, the oldest entries
+//! are evicted first. Each identity's log lives in persistent storage with the same TTL bumps as
+//! its bond, so it does not grow the contract instance; an identity's changelog is enabled once
+//! its log exists.
+
+use soroban_sdk::{panic_with_error, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::bond_store::{BUMP_TARGET, BUMP_THRESHOLD};
+use crate::{ChangelogEntry, ChangelogError, DataKey, IdentityBond};
+
+/// Storage key for the changelog retention count.
+const KEY_RETENTION: &str = "changelog_retention";
+
+/// Default number of entries retained per identity.
+pub const DEFAULT_CHANGELOG_RETENTION: u32 = 100;
+
+/// Returns true if the changelog is enabled for the identity.
+#[must_use]
+pub fn is_enabled(e: &Env, identity: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&DataKey::Changelog(identity.clone()))
+}
+
+fn store<V: IntoVal<Env, Val>>(e: &Env, key: &DataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, BUMP_THRESHOLD, BUMP_TARGET);
+}
+
+/// Enable the changelog for an identity by creating its (empty) log. Authorization is enforced
+/// by the caller.
+pub fn enable(e: &Env, identity: &Address) {
+    if !is_enabled(e, identity) {
+        store(
+            e,
+            &DataKey::Changelog(identity.clone()),
+            &Vec::<ChangelogEntry>::new(e),
+        );
+    }
+}
+
+/// Extend the TTL of the identity's changelog alongside its bond.
+pub fn bump_ttl(e: &Env, identity: &Address) {
+    crate::bond_store::bump_if_present(e, &DataKey::Changelog(identity.clone()));
+}
+
+/// Returns the configured retention count (entries kept per identity).
+#[must_use]
+pub fn get_retention(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_RETENTION))
+        .unwrap_or(DEFAULT_CHANGELOG_RETENTION)
+}
+
+/// Set the retention count. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `ChangelogError::InvalidRetention` if `retention` is zero
+pub fn set_retention(e: &Env, retention: u32) {
+    if retention == 0 {
+        panic_with_error!(e, ChangelogError::InvalidRetention);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_RETENTION), &retention);
+}
+
+/// Returns up to `limit` retained entries for the identity, starting at index `start`
+/// (oldest first).
+#[must_use]
+pub fn get_entries(e: &Env, identity: &Address, start: u32, limit: u32) -> Vec<ChangelogEntry> {
+    let log: Vec<ChangelogEntry> = e
+        .storage()
+        .persistent()
+        .get(&DataKey::Changelog(identity.clone()))
+        .unwrap_or(Vec::new(e));
+    let mut page = Vec::new(e);
+    let end = start.saturating_add(limit).min(log.len());
+    for i in start..end {
+        page.push_back(log.get(i).unwrap());
+    }
+    page
+}
+
+/// Record the field-level differences between `before` and `after` for `after.identity`.
+/// `before = None` diffs against an empty bond (all fields zero/false).
+/// No-op unless the changelog is enabled for the identity.
+pub fn record_changes(e: &Env, before: Option<&IdentityBond>, after: &IdentityBond, cause: &str) {
+    let identity = &after.identity;
+    if !is_enabled(e, identity) {
+        return;
+    }
+
+    let old = |f: fn(&IdentityBond) -> i128| before.map(f).unwrap_or(0);
+    let fields: [(&str, i128, i128); 8] = [
+        (
+            "bonded_amount",
+            old(|b| b.bonded_amount),
+            after.bonded_amount,
+        ),
+        (
+            "slashed_amount",
+            old(|b| b.slashed_amount),
+            after.slashed_amount,
+        ),
+        (
+            "bond_start",
+            old(|b| b.bond_start as i128),
+            after.bond_start as i128,
+        ),
+        (
+            "bond_duration",
+            old(|b| b.bond_duration as i128),
+            after.bond_duration as i128,
+        ),
+        ("active", old(|b| b.active as i128), after.active as i128),
+        (
+            "is_rolling",
+            old(|b| b.is_rolling as i128),
+            after.is_rolling as i128,
+        ),
+        (
+            "withdrawal_requested_at",
+            old(|b| b.withdrawal_requested_at as i128),
+            after.withdrawal_requested_at as i128,
+        ),
+        (
+            "notice_period_duration",
+            old(|b| b.notice_period_duration as i128),
+            after.notice_period_duration as i128,
+        ),
+    ];
+
+    let key = DataKey::Changelog(identity.clone());
+    let mut log: Vec<ChangelogEntry> = e.storage().persistent().get(&key).unwrap_or(Vec::new(e));
+    let timestamp = e.ledger().timestamp();
+    let cause = Symbol::new(e, cause);
+    for (field, old_value, new_value) in fields {
+        if old_value != new_value {
+            log.push_back(ChangelogEntry {
+                field: Symbol::new(e, field),
+                old_value,
+                new_value,
+                cause: cause.clone(),
+                timestamp,
+            });
+        }
+    }
+
+    let retention = get_retention(e);
+    while log.len() > retention {
+        log.pop_front();
+    }
+
+    store(e, &key, &log);
+}
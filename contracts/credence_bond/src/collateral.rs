@@ -0,0 +1,127 @@
+//! Collateral Floor
+//!
+//! An admin-configured floor of every open bond's unslashed balance cannot be withdrawn: it
+//! stays in the bond purely as slashable collateral. Withdrawals may only take the balance
+//! above the floor. When the bond is closed, whatever is left of the floor moves to a
+//! claimable balance that the identity can withdraw once a cooling-off period has passed.
+//!
+//! The floor defaults to 0, which disables it. Changes apply to every open bond at once.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{BondError, ClaimableCollateral, CollateralError, IdentityBond};
+
+/// Storage keys for collateral released at bond closure.
+#[contracttype]
+pub enum CollateralKey {
+    /// Collateral the identity can claim once cooled off (persistent storage).
+    Claimable(Address),
+}
+
+/// Storage key for the collateral floor.
+const KEY_FLOOR: &str = "collateral_floor";
+/// Storage key for the cooling-off period.
+const KEY_COOLING_PERIOD: &str = "collateral_cooling";
+
+/// Default cooling-off period: 7 days.
+pub const DEFAULT_COOLING_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// Returns the collateral floor (0 = disabled).
+#[must_use]
+pub fn get_floor(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_FLOOR))
+        .unwrap_or(0)
+}
+
+/// Set the collateral floor. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if `floor` is negative
+pub fn set_floor(e: &Env, floor: i128) {
+    if floor < 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_FLOOR), &floor);
+}
+
+/// Returns the cooling-off period in seconds.
+#[must_use]
+pub fn get_cooling_period(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_COOLING_PERIOD))
+        .unwrap_or(DEFAULT_COOLING_PERIOD)
+}
+
+/// Set the cooling-off period. Only admin should call (enforced by caller).
+pub fn set_cooling_period(e: &Env, period: u64) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_COOLING_PERIOD), &period);
+}
+
+/// Returns the part of the bond held back by the floor: the floor, or the whole unslashed
+/// balance if that is smaller. Closed bonds hold nothing back.
+#[must_use]
+pub fn locked(e: &Env, bond: &IdentityBond) -> i128 {
+    if !bond.active {
+        return 0;
+    }
+    get_floor(e).min(
+        bond.bonded_amount
+            .saturating_sub(bond.slashed_amount)
+            .max(0),
+    )
+}
+
+/// Returns the identity's collateral released at closure (zero if none).
+#[must_use]
+pub fn get_claimable(e: &Env, identity: &Address) -> ClaimableCollateral {
+    e.storage()
+        .persistent()
+        .get(&CollateralKey::Claimable(identity.clone()))
+        .unwrap_or(ClaimableCollateral {
+            amount: 0,
+            release_at: 0,
+        })
+}
+
+/// Add collateral released at closure to the identity's claimable balance. The whole balance
+/// cools off until `now + cooling period`.
+pub fn release(e: &Env, identity: &Address, amount: i128) -> ClaimableCollateral {
+    let current = get_claimable(e, identity);
+    let claimable = ClaimableCollateral {
+        amount: current
+            .amount
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow)),
+        release_at: e.ledger().timestamp().saturating_add(get_cooling_period(e)),
+    };
+    e.storage()
+        .persistent()
+        .set(&CollateralKey::Claimable(identity.clone()), &claimable);
+    claimable
+}
+
+/// Clear and return the identity's cooled-off collateral.
+///
+/// # Panics
+/// * `BondError::NothingToClaim` if there is none
+/// * `CollateralError::CoolingOff` before `release_at`
+pub fn take_claimable(e: &Env, identity: &Address) -> i128 {
+    let claimable = get_claimable(e, identity);
+    if claimable.amount <= 0 {
+        panic_with_error!(e, BondError::NothingToClaim);
+    }
+    if e.ledger().timestamp() < claimable.release_at {
+        panic_with_error!(e, CollateralError::CoolingOff);
+    }
+    e.storage()
+        .persistent()
+        .remove(&CollateralKey::Claimable(identity.clone()));
+    claimable.amount
+}
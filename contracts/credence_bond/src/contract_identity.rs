@@ -0,0 +1,60 @@
+use soroban_sdk::xdr::{ScAddressType, ToXdr};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env};
+
+use crate::DelegateError;
+
+/// Byte offset of the `ScAddressType` discriminant in an `ScVal::Address` XDR encoding.
+const ADDRESS_TYPE_OFFSET: u32 = 7;
+
+/// Storage keys for contract identity controllers.
+#[contracttype]
+pub enum ControllerKey {
+    /// Controller appointed by the contract identity (persistent storage).
+    Controller(Address),
+}
+
+#[must_use]
+pub fn is_contract_identity(e: &Env, identity: &Address) -> bool {
+    identity.clone().to_xdr(e).get(ADDRESS_TYPE_OFFSET) == Some(ScAddressType::Contract as u8)
+}
+
+#[must_use]
+pub fn get_controller(e: &Env, identity: &Address) -> Option<Address> {
+    e.storage()
+        .persistent()
+        .get(&ControllerKey::Controller(identity.clone()))
+}
+
+pub fn set_controller(e: &Env, identity: &Address, controller: &Address) {
+    if !is_contract_identity(e, identity) {
+        panic_with_error!(e, DelegateError::NotContractIdentity);
+    }
+    if controller == identity {
+        panic_with_error!(e, DelegateError::SelfDelegation);
+    }
+    e.storage()
+        .persistent()
+        .set(&ControllerKey::Controller(identity.clone()), controller);
+}
+
+pub fn remove_controller(e: &Env, identity: &Address) -> Option<Address> {
+    let controller = get_controller(e, identity);
+    if controller.is_some() {
+        e.storage()
+            .persistent()
+            .remove(&ControllerKey::Controller(identity.clone()));
+    }
+    controller
+}
+
+#[must_use]
+pub fn is_controller(e: &Env, caller: &Address, identity: &Address) -> bool {
+    get_controller(e, identity).as_ref() == Some(caller)
+}
+
+pub fn require_identity_or_controller(e: &Env, caller: &Address, identity: &Address) {
+    caller.require_auth();
+    if caller != identity && !is_controller(e, caller, identity) {
+        panic_with_error!(e, DelegateError::NotDelegate);
+    }
+}
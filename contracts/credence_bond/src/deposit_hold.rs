@@ -0,0 +1,180 @@
+//! Large Deposit Review
+//!
+//! Top-ups above an admin-configured size are held for review instead of being bonded. Held
+//! funds sit in the contract but do not count toward the bond, its tier or its withdrawable
+//! balance until the admin releases them. A rejected deposit is returned to a claimable
+//! balance for the depositor. If the admin does nothing, anyone may release the deposit once
+//! its review deadline has passed.
+//!
+//! The threshold defaults to 0, which disables review.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::{events, BondError, PendingDeposit};
+
+/// Storage keys for held deposits and rejected balances.
+#[contracttype]
+pub enum DepositHoldKey {
+    /// Held deposit by id (persistent storage).
+    Pending(u64),
+    /// Rejected deposits the identity can claim back (persistent storage).
+    Claimable(Address),
+}
+
+/// Storage key for the large deposit threshold.
+const KEY_THRESHOLD: &str = "large_deposit";
+/// Storage key for the review window.
+const KEY_REVIEW_WINDOW: &str = "deposit_review";
+/// Storage key for the last assigned deposit id.
+const KEY_DEPOSIT_SEQ: &str = "deposit_seq";
+
+/// Default review window: 7 days.
+pub const DEFAULT_REVIEW_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+/// Returns the large deposit threshold (0 = review disabled).
+#[must_use]
+pub fn get_threshold(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_THRESHOLD))
+        .unwrap_or(0)
+}
+
+/// Set the large deposit threshold. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if `threshold` is negative
+pub fn set_threshold(e: &Env, threshold: i128) {
+    if threshold < 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_THRESHOLD), &threshold);
+}
+
+/// Returns the review window in seconds.
+#[must_use]
+pub fn get_review_window(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_REVIEW_WINDOW))
+        .unwrap_or(DEFAULT_REVIEW_WINDOW)
+}
+
+/// Set the review window. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if `window` is 0
+pub fn set_review_window(e: &Env, window: u64) {
+    if window == 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_REVIEW_WINDOW), &window);
+}
+
+/// Returns true if a gross deposit of `amount` must be held for review.
+#[must_use]
+pub fn requires_review(e: &Env, amount: i128) -> bool {
+    let threshold = get_threshold(e);
+    threshold > 0 && amount > threshold
+}
+
+/// Store a held deposit under a new id and emit `deposit_held` with
+/// (identity, deposit_id, amount, release_at).
+pub fn hold(e: &Env, identity: &Address, amount: i128, premium: i128, net: i128) -> PendingDeposit {
+    let id = e
+        .storage()
+        .instance()
+        .get::<_, u64>(&Symbol::new(e, KEY_DEPOSIT_SEQ))
+        .unwrap_or(0)
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_DEPOSIT_SEQ), &id);
+
+    let now = e.ledger().timestamp();
+    let deposit = PendingDeposit {
+        id,
+        identity: identity.clone(),
+        amount,
+        premium,
+        net,
+        held_at: now,
+        release_at: now.saturating_add(get_review_window(e)),
+    };
+    e.storage()
+        .persistent()
+        .set(&DepositHoldKey::Pending(id), &deposit);
+    events::publish_or_compact(
+        e,
+        (Symbol::new(e, "deposit_held"),),
+        (identity.clone(), id, amount, deposit.release_at),
+        (identity.clone(), id, amount),
+    );
+    deposit
+}
+
+/// Returns the held deposit with the given id.
+///
+/// # Panics
+/// * `BondError::DepositNotFound` if no deposit with that id is pending
+#[must_use]
+pub fn get(e: &Env, id: u64) -> PendingDeposit {
+    e.storage()
+        .persistent()
+        .get(&DepositHoldKey::Pending(id))
+        .unwrap_or_else(|| panic_with_error!(e, BondError::DepositNotFound))
+}
+
+/// Remove and return the identity's held deposit.
+///
+/// # Panics
+/// * `BondError::DepositNotFound` if the deposit is not pending or belongs to another identity
+pub fn take(e: &Env, identity: &Address, id: u64) -> PendingDeposit {
+    let deposit = get(e, id);
+    if &deposit.identity != identity {
+        panic_with_error!(e, BondError::DepositNotFound);
+    }
+    e.storage()
+        .persistent()
+        .remove(&DepositHoldKey::Pending(id));
+    deposit
+}
+
+/// Returns the rejected amount the identity can claim.
+#[must_use]
+pub fn get_claimable(e: &Env, identity: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DepositHoldKey::Claimable(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Add a rejected deposit to the identity's claimable balance.
+pub fn add_claimable(e: &Env, identity: &Address, amount: i128) {
+    let total = get_claimable(e, identity)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .persistent()
+        .set(&DepositHoldKey::Claimable(identity.clone()), &total);
+}
+
+/// Clear and return the identity's claimable balance.
+///
+/// # Panics
+/// * `BondError::NothingToClaim` if the balance is zero
+pub fn take_claimable(e: &Env, identity: &Address) -> i128 {
+    let amount = get_claimable(e, identity);
+    if amount <= 0 {
+        panic_with_error!(e, BondError::NothingToClaim);
+    }
+    e.storage()
+        .persistent()
+        .remove(&DepositHoldKey::Claimable(identity.clone()));
+    amount
+}
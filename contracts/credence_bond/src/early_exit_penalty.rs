@@ -2,34 +2,146 @@
 //!
 //! Charges a configurable fee when users withdraw before the lock-up period ends.
 //! Penalty is proportional to remaining lock time and is transferred to the treasury.
+//!
+//! The penalty rate is bounded by an admin-set ceiling, itself at most `HARD_MAX_PENALTY_BPS`.
+//! A rate stored above the ceiling (e.g. before the ceiling existed) is charged at the ceiling.
+//!
+//! An optional absolute cap limits the penalty on any one withdrawal, surge included, so very
+//! large withdrawals are not charged more than a fixed amount. 0 disables it.
+//!
+//! Waivers: the admin can exempt a single identity until an expiry timestamp (e.g. a
+//! partnership wind-down). Its early exits are charged nothing; an expired waiver is ignored.
+//!
+//! Brackets: instead of the single pro-rated rate, the admin can set a table of
+//! (remaining_fraction_bps, penalty_bps) pairs. The first bracket whose bound is at least the
+//! share of the lock-up still remaining sets a flat rate for the withdrawal. With no brackets
+//! configured the single rate applies.
+//!
+//! Surge pricing: to damp bank-run dynamics, the penalty is scaled up by a multiplier while
+//! early outflow within a rolling window exceeds configured thresholds (share of bonded value).
+//!
+//! Accounting: every penalty collected is added to a running total and count, so treasury
+//! receipts can be reconciled on chain.
+//!
+//! Treasury: the contract's own address is never accepted as the treasury, and a penalty is
+//! never routed back to the identity paying it. Deployments that want penalties destroyed
+//! instead switch on burn mode; burned penalties are tracked separately and nobody can claim
+//! them.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+use crate::{BondError, FeeAccounting, TreasuryError};
 
-use soroban_sdk::{Address, Env, Symbol};
+/// Storage keys for per-identity early exit state.
+#[contracttype]
+pub enum EarlyExitKey {
+    /// Expiry of the identity's penalty waiver (persistent storage).
+    Waiver(Address),
+}
 
 /// Storage key for treasury address.
 const KEY_TREASURY: &str = "treasury";
 /// Storage key for early exit penalty rate in basis points (e.g. 500 = 5%).
 const KEY_PENALTY_BPS: &str = "early_exit_penalty_bps";
+/// Storage key for the penalty rate ceiling in basis points.
+const KEY_MAX_PENALTY_BPS: &str = "early_exit_max_bps";
+/// Storage key for the absolute penalty cap per withdrawal (0 = no cap).
+const KEY_MAX_PENALTY_AMOUNT: &str = "early_exit_max_amount";
+/// Storage key for penalty brackets: (remaining_fraction_bps, penalty_bps), ascending.
+const KEY_PENALTY_BRACKETS: &str = "early_exit_brackets";
+/// Storage key for surge thresholds: (outflow_bps, penalty_multiplier_bps), ascending.
+const KEY_SURGE_THRESHOLDS: &str = "surge_thresholds";
+/// Storage key for the surge rolling window in seconds.
+const KEY_SURGE_WINDOW: &str = "surge_window";
+/// Storage key for early outflows within the window: (timestamp, amount).
+const KEY_EARLY_OUTFLOWS: &str = "early_outflows";
+/// Storage key for the total early exit penalties collected.
+const KEY_PENALTIES_COLLECTED: &str = "penalties_collected";
+/// Storage key for the number of early exits charged a penalty.
+const KEY_PENALTY_COUNT: &str = "penalty_count";
+/// Storage key for burn mode (penalties burned instead of sent to the treasury).
+const KEY_BURN: &str = "early_exit_burn";
+/// Storage key for the total early exit penalties burned.
+const KEY_PENALTIES_BURNED: &str = "penalties_burned";
 
-/// Returns (treasury, penalty_bps). Panics if config not set.
-pub fn get_config(e: &Env) -> (Address, u32) {
+/// Multiplier applied when no surge threshold is exceeded (1x).
+pub const BASE_MULTIPLIER_BPS: u32 = 10_000;
+
+/// Highest ceiling the admin can set: 50%. Also the ceiling until one is set.
+pub const HARD_MAX_PENALTY_BPS: u32 = 5_000;
+
+/// Returns the penalty rate ceiling in bps (`HARD_MAX_PENALTY_BPS` if never set).
+#[must_use]
+pub fn get_max_penalty(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_MAX_PENALTY_BPS))
+        .unwrap_or(HARD_MAX_PENALTY_BPS)
+}
+
+/// Set the penalty rate ceiling. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidPenaltyBps` if `max_bps` exceeds `HARD_MAX_PENALTY_BPS` or is below the
+///   configured penalty rate or any bracket rate (lower those first)
+pub fn set_max_penalty(e: &Env, max_bps: u32) {
+    let current = e
+        .storage()
+        .instance()
+        .get::<_, u32>(&Symbol::new(e, KEY_PENALTY_BPS))
+        .unwrap_or(0);
+    let highest_bracket = get_brackets(e)
+        .iter()
+        .map(|(_, penalty_bps)| penalty_bps)
+        .max()
+        .unwrap_or(0);
+    if max_bps > HARD_MAX_PENALTY_BPS || max_bps < current || max_bps < highest_bracket {
+        panic_with_error!(e, BondError::InvalidPenaltyBps);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_MAX_PENALTY_BPS), &max_bps);
+}
+
+/// Returns (treasury, penalty_bps), the rate capped at the ceiling, or `None` if never set.
+#[must_use]
+pub fn load_config(e: &Env) -> Option<(Address, u32)> {
     let treasury = e
         .storage()
         .instance()
-        .get::<_, Address>(&Symbol::new(e, KEY_TREASURY))
-        .unwrap_or_else(|| panic!("early exit config not set"));
+        .get::<_, Address>(&Symbol::new(e, KEY_TREASURY))?;
     let bps = e
         .storage()
         .instance()
-        .get::<_, u32>(&Symbol::new(e, KEY_PENALTY_BPS))
-        .unwrap_or_else(|| panic!("early exit penalty bps not set"));
-    (treasury, bps)
+        .get::<_, u32>(&Symbol::new(e, KEY_PENALTY_BPS))?;
+    Some((treasury, bps.min(get_max_penalty(e))))
+}
+
+/// Returns (treasury, penalty_bps), the rate capped at the ceiling.
+///
+/// # Panics
+/// * `BondError::EarlyExitConfigNotSet` if the config was never set
+pub fn get_config(e: &Env) -> (Address, u32) {
+    load_config(e).unwrap_or_else(|| panic_with_error!(e, BondError::EarlyExitConfigNotSet))
+}
+
+/// Validate an early exit penalty rate against the ceiling.
+///
+/// # Panics
+/// * `BondError::InvalidPenaltyBps` if `penalty_bps` exceeds the ceiling
+pub fn validate_config(e: &Env, penalty_bps: u32) {
+    if penalty_bps > get_max_penalty(e) {
+        panic_with_error!(e, BondError::InvalidPenaltyBps);
+    }
 }
 
 /// Set early exit config. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidPenaltyBps` if `penalty_bps` exceeds the ceiling
 pub fn set_config(e: &Env, treasury: Address, penalty_bps: u32) {
-    if penalty_bps > 10_000 {
-        panic!("penalty_bps must be <= 10000 (100%)");
-    }
+    validate_config(e, penalty_bps);
+    validate_treasury(e, &treasury);
     e.storage()
         .instance()
         .set(&Symbol::new(e, KEY_TREASURY), &treasury);
@@ -38,6 +150,46 @@ pub fn set_config(e: &Env, treasury: Address, penalty_bps: u32) {
         .set(&Symbol::new(e, KEY_PENALTY_BPS), &penalty_bps);
 }
 
+/// Reject the contract's own address as the treasury, which would route penalties in a circle.
+///
+/// # Panics
+/// * `TreasuryError::InvalidTreasury` if `treasury` is the contract address
+pub fn validate_treasury(e: &Env, treasury: &Address) {
+    if treasury == &e.current_contract_address() {
+        panic_with_error!(e, TreasuryError::InvalidTreasury);
+    }
+}
+
+/// Returns true if penalties are burned instead of sent to the treasury.
+#[must_use]
+pub fn is_burn_mode(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_BURN))
+        .unwrap_or(false)
+}
+
+/// Switch burn mode on or off. Only admin should call (enforced by caller).
+pub fn set_burn_mode(e: &Env, burn: bool) {
+    e.storage().instance().set(&Symbol::new(e, KEY_BURN), &burn);
+}
+
+/// Returns where the penalty charged to `identity` goes: the treasury, or the contract's own
+/// address in burn mode. The treasury is checked again here since the identity varies.
+///
+/// # Panics
+/// * `TreasuryError::InvalidTreasury` if the treasury is the contract address or `identity`
+pub fn penalty_recipient(e: &Env, treasury: Address, identity: &Address) -> Address {
+    if is_burn_mode(e) {
+        return e.current_contract_address();
+    }
+    validate_treasury(e, &treasury);
+    if &treasury == identity {
+        panic_with_error!(e, TreasuryError::InvalidTreasury);
+    }
+    treasury
+}
+
 /// Calculate early exit penalty based on remaining lock time.
 /// penalty = (amount * penalty_bps / 10000) * remaining_time / total_duration
 /// Uses integer math to avoid overflow: (amount * penalty_bps / 10000) * remaining_time / total_duration
@@ -55,21 +207,313 @@ pub fn calculate_penalty(
     (base * (remaining_time as i128)) / (total_duration as i128)
 }
 
-/// Emit early exit penalty event.
-pub fn emit_penalty_event(
+/// Returns the absolute penalty cap per withdrawal (0 = no cap).
+#[must_use]
+pub fn get_max_penalty_amount(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_MAX_PENALTY_AMOUNT))
+        .unwrap_or(0)
+}
+
+/// Set the absolute penalty cap per withdrawal. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if `max_amount` is negative
+pub fn set_max_penalty_amount(e: &Env, max_amount: i128) {
+    if max_amount < 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_MAX_PENALTY_AMOUNT), &max_amount);
+}
+
+/// Exempt the identity from early exit penalties until `expires_at`. Only admin should call
+/// (enforced by caller). Replaces any existing waiver.
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if `expires_at` is not in the future
+pub fn add_waiver(e: &Env, identity: &Address, expires_at: u64) {
+    if expires_at <= e.ledger().timestamp() {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .persistent()
+        .set(&EarlyExitKey::Waiver(identity.clone()), &expires_at);
+}
+
+/// Remove the identity's penalty waiver, if any.
+pub fn remove_waiver(e: &Env, identity: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&EarlyExitKey::Waiver(identity.clone()));
+}
+
+/// Returns true if the identity holds a waiver that has not expired at `now`.
+#[must_use]
+pub fn has_waiver(e: &Env, identity: &Address, now: u64) -> bool {
+    e.storage()
+        .persistent()
+        .get::<_, u64>(&EarlyExitKey::Waiver(identity.clone()))
+        .is_some_and(|expires_at| now < expires_at)
+}
+
+/// Returns the penalties collected so far.
+#[must_use]
+pub fn get_fee_accounting(e: &Env) -> FeeAccounting {
+    FeeAccounting {
+        total_penalties_collected: e
+            .storage()
+            .instance()
+            .get(&Symbol::new(e, KEY_PENALTIES_COLLECTED))
+            .unwrap_or(0),
+        penalty_count: e
+            .storage()
+            .instance()
+            .get(&Symbol::new(e, KEY_PENALTY_COUNT))
+            .unwrap_or(0),
+        total_penalties_burned: e
+            .storage()
+            .instance()
+            .get(&Symbol::new(e, KEY_PENALTIES_BURNED))
+            .unwrap_or(0),
+    }
+}
+
+/// Add a collected penalty to the running totals, and to the burned total if it was burned,
+/// and return them. A zero penalty (e.g. a waived exit) is not counted.
+///
+/// # Panics
+/// * `BondError::Overflow` if a counter overflows
+pub fn record_penalty_collected(e: &Env, penalty: i128, burned: bool) -> FeeAccounting {
+    let mut accounting = get_fee_accounting(e);
+    if penalty <= 0 {
+        return accounting;
+    }
+    accounting.total_penalties_collected = accounting
+        .total_penalties_collected
+        .checked_add(penalty)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    accounting.penalty_count = accounting
+        .penalty_count
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage().instance().set(
+        &Symbol::new(e, KEY_PENALTIES_COLLECTED),
+        &accounting.total_penalties_collected,
+    );
+    e.storage().instance().set(
+        &Symbol::new(e, KEY_PENALTY_COUNT),
+        &accounting.penalty_count,
+    );
+    if burned {
+        accounting.total_penalties_burned = accounting
+            .total_penalties_burned
+            .checked_add(penalty)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+        e.storage().instance().set(
+            &Symbol::new(e, KEY_PENALTIES_BURNED),
+            &accounting.total_penalties_burned,
+        );
+    }
+    accounting
+}
+
+/// Set the penalty brackets. Only admin should call (enforced by caller). Bounds must be
+/// strictly ascending and at most 10000; an empty list restores the single rate.
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if the bounds are not strictly ascending or exceed 10000
+/// * `BondError::InvalidPenaltyBps` if a bracket rate exceeds the ceiling
+pub fn set_brackets(e: &Env, brackets: Vec<(u32, u32)>) {
+    let max_bps = get_max_penalty(e);
+    let mut prev: Option<u32> = None;
+    for (fraction_bps, penalty_bps) in brackets.iter() {
+        if fraction_bps > 10_000 || prev.is_some_and(|p| fraction_bps <= p) {
+            panic_with_error!(e, BondError::InvalidConfig);
+        }
+        if penalty_bps > max_bps {
+            panic_with_error!(e, BondError::InvalidPenaltyBps);
+        }
+        prev = Some(fraction_bps);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_PENALTY_BRACKETS), &brackets);
+}
+
+/// Returns the penalty brackets (empty when not configured).
+#[must_use]
+pub fn get_brackets(e: &Env) -> Vec<(u32, u32)> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_PENALTY_BRACKETS))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Select the bracket for `remaining_time` out of `total_duration`: the first whose bound is at
+/// least the remaining share in bps, or the last bracket if the share is above every bound.
+/// `None` when no brackets are configured or the duration is zero.
+#[must_use]
+pub fn select_bracket(
+    brackets: &Vec<(u32, u32)>,
+    remaining_time: u64,
+    total_duration: u64,
+) -> Option<(u32, u32)> {
+    if total_duration == 0 {
+        return None;
+    }
+    let fraction_bps = (remaining_time as u128 * 10_000 / total_duration as u128) as u32;
+    brackets
+        .iter()
+        .find(|(bound, _)| fraction_bps <= *bound)
+        .or_else(|| brackets.last())
+}
+
+/// Penalty for withdrawing `amount` early with `remaining_time` of `total_duration` left,
+/// before surge. Uses the selected bracket's flat rate when brackets are configured, the
+/// pro-rated single rate otherwise. Returns (penalty, rate_bps, selected bracket).
+#[must_use]
+pub fn penalty_for(
     e: &Env,
-    identity: &Address,
-    withdraw_amount: i128,
-    penalty_amount: i128,
-    treasury: &Address,
-) {
-    e.events().publish(
-        (Symbol::new(e, "early_exit_penalty"),),
-        (
-            identity.clone(),
-            withdraw_amount,
-            penalty_amount,
-            treasury.clone(),
+    amount: i128,
+    remaining_time: u64,
+    total_duration: u64,
+    penalty_bps: u32,
+) -> (i128, u32, Option<(u32, u32)>) {
+    match select_bracket(&get_brackets(e), remaining_time, total_duration) {
+        Some((bound, bracket_bps)) => {
+            let rate = bracket_bps.min(get_max_penalty(e));
+            let penalty = amount
+                .checked_mul(rate as i128)
+                .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow))
+                / 10_000;
+            (penalty, rate, Some((bound, bracket_bps)))
+        }
+        None => (
+            calculate_penalty(amount, remaining_time, total_duration, penalty_bps),
+            penalty_bps,
+            None,
         ),
-    );
+    }
+}
+
+/// Set surge thresholds and rolling window. Only admin should call (enforced by caller).
+/// Thresholds must be strictly ascending by outflow_bps (each <= 10000) and multipliers
+/// must be at least 1x (10000 bps). An empty list disables surge pricing.
+pub fn set_surge_config(e: &Env, thresholds: Vec<(u32, u32)>, window_seconds: u64) {
+    let mut prev: Option<u32> = None;
+    for (outflow_bps, multiplier_bps) in thresholds.iter() {
+        if outflow_bps > 10_000 {
+            panic_with_error!(e, BondError::InvalidSurgeConfig);
+        }
+        if multiplier_bps < BASE_MULTIPLIER_BPS {
+            panic_with_error!(e, BondError::InvalidSurgeConfig);
+        }
+        if let Some(p) = prev {
+            if outflow_bps <= p {
+                panic_with_error!(e, BondError::InvalidSurgeConfig);
+            }
+        }
+        prev = Some(outflow_bps);
+    }
+    if !thresholds.is_empty() && window_seconds == 0 {
+        panic_with_error!(e, BondError::InvalidSurgeConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_SURGE_THRESHOLDS), &thresholds);
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_SURGE_WINDOW), &window_seconds);
+}
+
+/// Returns (thresholds, window_seconds). Empty thresholds when surge pricing is not configured.
+#[must_use]
+pub fn get_surge_config(e: &Env) -> (Vec<(u32, u32)>, u64) {
+    let thresholds = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_SURGE_THRESHOLDS))
+        .unwrap_or(Vec::new(e));
+    let window = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_SURGE_WINDOW))
+        .unwrap_or(0);
+    (thresholds, window)
+}
+
+/// Early outflows still inside the rolling window ending at `now`.
+fn live_outflows(e: &Env, now: u64) -> Vec<(u64, i128)> {
+    let (_, window) = get_surge_config(e);
+    let all: Vec<(u64, i128)> = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_EARLY_OUTFLOWS))
+        .unwrap_or(Vec::new(e));
+    let mut live = Vec::new(e);
+    for (ts, amount) in all.iter() {
+        if ts.saturating_add(window) > now {
+            live.push_back((ts, amount));
+        }
+    }
+    live
+}
+
+/// Total early outflow within the rolling window ending at `now`.
+#[must_use]
+pub fn get_window_outflow(e: &Env, now: u64) -> i128 {
+    live_outflows(e, now)
+        .iter()
+        .fold(0_i128, |acc, (_, amount)| acc.saturating_add(amount))
+}
+
+/// Record an early withdrawal in the rolling outflow accounting (expired entries are pruned).
+/// No-op when surge pricing is not configured.
+pub fn record_early_outflow(e: &Env, now: u64, amount: i128) {
+    let (thresholds, _) = get_surge_config(e);
+    if thresholds.is_empty() {
+        return;
+    }
+    let mut live = live_outflows(e, now);
+    live.push_back((now, amount));
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_EARLY_OUTFLOWS), &live);
+}
+
+/// Current surge multiplier in bps for a bonded value of `total_bonded`: the multiplier of the
+/// highest threshold whose outflow share is exceeded by the window's early outflow.
+#[must_use]
+pub fn get_surge_multiplier(e: &Env, now: u64, total_bonded: i128) -> u32 {
+    let (thresholds, _) = get_surge_config(e);
+    if thresholds.is_empty() || total_bonded <= 0 {
+        return BASE_MULTIPLIER_BPS;
+    }
+    let outflow_bps = get_window_outflow(e, now)
+        .checked_mul(10_000)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow))
+        / total_bonded;
+    let mut multiplier = BASE_MULTIPLIER_BPS;
+    for (threshold_bps, multiplier_bps) in thresholds.iter() {
+        if outflow_bps > threshold_bps as i128 {
+            multiplier = multiplier_bps;
+        }
+    }
+    multiplier
+}
+
+/// Apply a surge multiplier to a base penalty, capped at the withdrawal amount.
+///
+/// # Panics
+/// * `BondError::Overflow` if `penalty * multiplier_bps` overflows
+#[must_use]
+pub fn apply_surge(e: &Env, penalty: i128, amount: i128, multiplier_bps: u32) -> i128 {
+    let scaled = penalty
+        .checked_mul(multiplier_bps as i128)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow))
+        / BASE_MULTIPLIER_BPS as i128;
+    scaled.min(amount)
 }
@@ -0,0 +1,54 @@
+//! Event Emission
+//!
+//! Every contract event is published through this module so the admin-selected `EventMode`
+//! applies consistently. In `Rich` mode (the default) events carry their full payload. In
+//! `Compact` mode, events that have a compact form carry only ids and amounts; the rest of the
+//! detail is available from the matching getter (bond state, slash record, pending deposit,
+//! pledge, attestation or withdrawal receipt). Events without a compact form are the same in
+//! both modes.
+
+use soroban_sdk::{Env, IntoVal, Symbol, Topics, Val};
+
+use crate::EventMode;
+
+/// Storage key for the event mode.
+const KEY_EVENT_MODE: &str = "event_mode";
+
+/// Returns the event mode (`Rich` if never set).
+#[must_use]
+pub fn get_mode(e: &Env) -> EventMode {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_EVENT_MODE))
+        .unwrap_or(EventMode::Rich)
+}
+
+/// Set the event mode. Only admin should call (enforced by caller). Applies to every event
+/// published afterwards, including later in the same call.
+pub fn set_mode(e: &Env, mode: EventMode) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_EVENT_MODE), &mode);
+}
+
+/// Publish an event that has the same payload in both modes.
+pub fn publish<T, D>(e: &Env, topics: T, data: D)
+where
+    T: Topics,
+    D: IntoVal<Env, Val>,
+{
+    e.events().publish(topics, data);
+}
+
+/// Publish an event with `rich` as its payload, or `compact` in compact mode.
+pub fn publish_or_compact<T, R, C>(e: &Env, topics: T, rich: R, compact: C)
+where
+    T: Topics,
+    R: IntoVal<Env, Val>,
+    C: IntoVal<Env, Val>,
+{
+    match get_mode(e) {
+        EventMode::Rich => e.events().publish(topics, rich),
+        EventMode::Compact => e.events().publish(topics, compact),
+    }
+}
@@ -0,0 +1,160 @@
+//! Insurance Pool
+//!
+//! Programs that offer slashing insurance can charge a premium on every deposit.
+//! `create_bond` and `top_up` split the incoming amount: `amount * premium_bps / 10_000`
+//! (rounded up) goes to the insurance pool and the remainder is bonded.
+//! The premium defaults to 0 bps, which preserves plain bonding behavior.
+
+use soroban_sdk::{panic_with_error, Address, Env, Symbol};
+
+use crate::{events, BondError, DepositStats};
+
+/// Storage key for the deposit premium rate in basis points.
+const KEY_PREMIUM_BPS: &str = "deposit_premium_bps";
+/// Storage key for the insurance pool balance.
+const KEY_POOL_BALANCE: &str = "insurance_pool";
+/// Storage key for pool funds reserved or paid out as referral rewards.
+const KEY_REFERRAL_OUTFLOW: &str = "insurance_referral_out";
+/// Storage key for lifetime deposit statistics.
+const KEY_DEPOSIT_STATS: &str = "deposit_stats";
+
+/// Maximum deposit premium (10%).
+pub const MAX_DEPOSIT_PREMIUM_BPS: u32 = 1_000;
+
+/// Returns the configured deposit premium in basis points (0 if not set).
+#[must_use]
+pub fn get_premium_bps(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_PREMIUM_BPS))
+        .unwrap_or(0)
+}
+
+/// Set the deposit premium. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if premium_bps exceeds `MAX_DEPOSIT_PREMIUM_BPS`
+pub fn set_premium_bps(e: &Env, premium_bps: u32) {
+    if premium_bps > MAX_DEPOSIT_PREMIUM_BPS {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_PREMIUM_BPS), &premium_bps);
+}
+
+/// Calculate the premium owed on a deposit: ceil(amount * premium_bps / 10_000).
+/// Non-positive amounts carry no premium (they are rejected by amount validation).
+///
+/// # Panics
+/// * `BondError::Overflow` if `amount * premium_bps` overflows
+#[must_use]
+pub fn calculate_premium(e: &Env, amount: i128, premium_bps: u32) -> i128 {
+    if amount <= 0 || premium_bps == 0 {
+        return 0;
+    }
+    let scaled = amount
+        .checked_mul(premium_bps as i128)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    (scaled + 9_999) / 10_000
+}
+
+/// Split a gross deposit into (premium, net) using the configured premium rate.
+#[must_use]
+pub fn split_deposit(e: &Env, amount: i128) -> (i128, i128) {
+    let premium = calculate_premium(e, amount, get_premium_bps(e));
+    (premium, amount - premium)
+}
+
+/// Returns the current insurance pool balance.
+#[must_use]
+pub fn get_pool_balance(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_POOL_BALANCE))
+        .unwrap_or(0)
+}
+
+/// Returns the pool funds reserved or paid out as referral rewards, net of forfeits.
+#[must_use]
+pub fn get_referral_outflow(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_REFERRAL_OUTFLOW))
+        .unwrap_or(0)
+}
+
+fn add_to_pool(e: &Env, amount: i128) {
+    let pool = get_pool_balance(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    let outflow = get_referral_outflow(e)
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_POOL_BALANCE), &pool);
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_REFERRAL_OUTFLOW), &outflow);
+}
+
+/// Move `amount` out of the pool to back a referral reward (caller caps it at the balance).
+pub fn reserve_for_referral(e: &Env, amount: i128) {
+    if amount > 0 {
+        add_to_pool(e, -amount);
+    }
+}
+
+/// Return a forfeited referral reward to the pool.
+pub fn release_from_referral(e: &Env, amount: i128) {
+    if amount > 0 {
+        add_to_pool(e, amount);
+    }
+}
+
+/// Returns lifetime deposit statistics (zeroed if nothing was deposited yet).
+#[must_use]
+pub fn get_deposit_stats(e: &Env) -> DepositStats {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_DEPOSIT_STATS))
+        .unwrap_or(DepositStats {
+            total_gross: 0,
+            total_premium: 0,
+            total_net: 0,
+        })
+}
+
+/// Credit the premium to the insurance pool, update lifetime stats and emit the deposit event.
+pub fn record_deposit(e: &Env, identity: &Address, gross: i128, premium: i128, net: i128) {
+    let pool = get_pool_balance(e)
+        .checked_add(premium)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_POOL_BALANCE), &pool);
+
+    let mut stats = get_deposit_stats(e);
+    stats.total_gross = stats
+        .total_gross
+        .checked_add(gross)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    stats.total_premium = stats
+        .total_premium
+        .checked_add(premium)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    stats.total_net = stats
+        .total_net
+        .checked_add(net)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_DEPOSIT_STATS), &stats);
+
+    events::publish(
+        e,
+        (Symbol::new(e, "bond_deposit"), identity.clone()),
+        (gross, premium, net),
+    );
+}
@@ -1,18 +1,41 @@
 #![no_std]
 
+mod accounting;
+#[cfg(any(test, feature = "bench"))]
+pub mod bench;
+mod bond_delegation;
+mod bond_store;
+mod changelog;
+mod collateral;
+mod contract_identity;
+mod deposit_hold;
 mod early_exit_penalty;
+mod events;
+mod insurance;
 mod nonce;
+mod pause;
+mod pledges;
+mod privacy;
+mod receipts;
+mod referral;
+mod roles;
 mod rolling_bond;
 mod slashing;
+mod storage_budget;
 mod tiered_bond;
+mod token_transfer;
 mod validation;
 mod weighted_attestation;
 
 pub mod types;
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, panic_with_error, Address, BytesN, Env,
+    IntoVal, Map, String, Symbol, Val, Vec,
+};
 
-/// Identity tier based on bonded amount (Bronze < Silver < Gold < Platinum).
+/// Identity tier based on bonded amount (Bronze < Silver < Gold < Platinum < Diamond).
+/// Order tiers with `tiered_bond::tier_rank`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum BondTier {
@@ -20,6 +43,28 @@ pub enum BondTier {
     Silver,
     Gold,
     Platinum,
+    Diamond,
+}
+
+/// Minimum bonded amount for each tier. Minimums must be strictly ascending.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierConfig {
+    pub bronze_min: i128,
+    pub silver_min: i128,
+    pub gold_min: i128,
+    pub platinum_min: i128,
+    pub diamond_min: i128,
+}
+
+/// A recorded tier change: the bonded amount that caused it and when it happened.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierChangeRecord {
+    pub old_tier: BondTier,
+    pub new_tier: BondTier,
+    pub amount: i128,
+    pub timestamp: u64,
 }
 
 #[contracttype]
@@ -39,13 +84,479 @@ pub struct IdentityBond {
     pub notice_period_duration: u64,
 }
 
+/// Counter checked by `verify_accounting`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccountingCheck {
+    /// Sum of bonded amounts (expected) vs the total bonded counter (actual).
+    TotalBonded,
+    /// Premiums collected (expected) vs the insurance pool balance (actual).
+    InsurancePool,
+    /// Premiums plus net deposits (expected) vs gross deposits (actual).
+    DepositStats,
+    /// Bond whose slashed amount (actual) exceeds its bonded amount (expected).
+    BondSlashed(Address),
+}
+
+/// A counter that does not reconcile with the records it summarises.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountingDiscrepancy {
+    pub check: AccountingCheck,
+    pub expected: i128,
+    pub actual: i128,
+}
+
+/// Result of one `verify_accounting` page. `discrepancies` is filled in on the final page
+/// (`complete`); otherwise continue with `next_cursor`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VerificationReport {
+    pub scanned: u32,
+    pub next_cursor: u32,
+    pub complete: bool,
+    pub discrepancies: Vec<AccountingDiscrepancy>,
+}
+
+/// Lifetime deposit statistics: gross deposits, premiums routed to the insurance pool, net bonded.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositStats {
+    pub total_gross: i128,
+    pub total_premium: i128,
+    pub total_net: i128,
+}
+
+/// One field-level bond mutation recorded in the opt-in changelog.
+/// Booleans and timestamps are widened to i128 (false = 0, true = 1).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChangelogEntry {
+    pub field: Symbol,
+    pub old_value: i128,
+    pub new_value: i128,
+    pub cause: Symbol,
+    pub timestamp: u64,
+}
+
+/// Withdrawal signed offline by the identity and submitted later by a relayer.
+/// The whole struct is covered by the identity's authorization, so no field can be altered.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalIntent {
+    pub identity: Address,
+    pub amount: i128,
+    pub destination: Address,
+    /// Ledger timestamp after which the intent can no longer be executed.
+    pub deadline: u64,
+    /// Single-use nonce (any order, each at most once).
+    pub nonce: u64,
+}
+
+/// Receipt for a completed withdrawal, retrievable by id.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalReceipt {
+    pub id: u64,
+    pub identity: Address,
+    /// Amount removed from the bond.
+    pub gross: i128,
+    /// Early exit penalty charged (0 for post lock-up withdrawals).
+    pub penalty: i128,
+    /// Amount credited to the destination (gross - penalty).
+    pub net: i128,
+    /// Withdrawal entry point taken (`withdraw`, `withdraw_early`, `withdraw_bond`).
+    pub path: Symbol,
+    pub destination: Address,
+    pub timestamp: u64,
+}
+
+/// Immutable audit record written by `slash` and `slash_with_reason`, retrievable by id.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashRecord {
+    pub id: u64,
+    pub identity: Address,
+    /// Amount the admin asked to slash.
+    pub requested_amount: i128,
+    /// Amount actually slashed by this call (after capping at the bonded amount).
+    pub applied_amount: i128,
+    /// Bond's `slashed_amount` after this slash.
+    pub cumulative_slashed: i128,
+    /// Reason code supplied by the admin.
+    pub reason: Symbol,
+    pub admin: Address,
+    pub timestamp: u64,
+    /// Time of the incident the slash is for, as given by the admin (0 if not given).
+    pub incident_at: u64,
+    /// True until the slash is final: the dispute window has passed or the dispute was decided.
+    pub pending: bool,
+    /// Set when the slashed identity disputes the slash.
+    pub disputed: bool,
+    /// End of the dispute window (equal to `timestamp` when no window is configured).
+    pub dispute_ends_at: u64,
+}
+
+/// An identity's slashes within a time window, from `get_slash_summary`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashSummary {
+    /// Slashes that stand (executed and not overturned).
+    pub count: u32,
+    /// Total amount of those slashes.
+    pub total: i128,
+    /// Audit record ids of those slashes; slashes made without a record have none.
+    pub record_ids: Vec<u64>,
+    /// Slashes overturned on dispute. Not included in `count` or `total`.
+    pub overturned_count: u32,
+}
+
+/// Event payload mode. `Compact` events carry only ids and amounts; see the `events` module.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventMode {
+    Rich,
+    Compact,
+}
+
+/// What `renew_if_rolling` does with a matured withdrawal left unclaimed past the reclaim
+/// window.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnclaimedPolicy {
+    /// Leave the bond waiting for the withdrawal (no renewal).
+    Hold,
+    /// Cancel the request; the amount stays bonded and the bond renews.
+    Reabsorb,
+    /// Move the withdrawable amount out of the bond into a claimable balance.
+    Claimable,
+}
+
+/// Slashing configuration. With a zero dispute window, slashes are final when written.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashConfig {
+    pub slash_dispute_window_secs: u64,
+}
+
+/// Part of a bond pledged to a service agreement. Encumbers the bond until `ends_at`; the
+/// counterparty may slash up to `amount` before then.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pledge {
+    pub agreement_id: BytesN<32>,
+    pub identity: Address,
+    pub counterparty: Address,
+    pub amount: i128,
+    /// Amount slashed by the counterparty so far.
+    pub slashed: i128,
+    pub ends_at: u64,
+}
+
+/// Collateral released from the floor at bond closure, claimable from `release_at`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimableCollateral {
+    pub amount: i128,
+    pub release_at: u64,
+}
+
+/// Referrer attributed to a bond at creation and the reward reserved for them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Referral {
+    pub referrer: Address,
+    /// Reward reserved from the insurance pool (0 if none was available).
+    pub reward: i128,
+    /// Creation time of the referred bond; the reward vests `min_age` later.
+    pub bond_start: u64,
+    /// True once the reward has vested or been forfeited.
+    pub settled: bool,
+}
+
+/// Referral totals for a referrer.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralStats {
+    /// Bonds created with this referrer.
+    pub referred_count: u32,
+    /// Rewards waiting for their bond to reach the minimum age.
+    pub pending: i128,
+    /// Vested rewards not yet claimed.
+    pub claimable: i128,
+    pub claimed: i128,
+    /// Rewards returned to the pool because the bond closed too early.
+    pub forfeited: i128,
+}
+
+/// Parts of a bond's unslashed balance that cannot currently be withdrawn.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Encumbrances {
+    /// Unslashed remainder of live service agreement pledges.
+    pub pledged: i128,
+    /// Part held back by the collateral floor.
+    pub collateral_floor: i128,
+    /// Slashes still pending (in their dispute window or under dispute).
+    pub pending_slash: i128,
+}
+
+/// Top-up above the large deposit threshold, held for admin review. Funds are in the contract
+/// but not bonded until released.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingDeposit {
+    pub id: u64,
+    pub identity: Address,
+    /// Gross amount transferred in.
+    pub amount: i128,
+    /// Insurance premium charged on release (not charged if rejected).
+    pub premium: i128,
+    /// Amount bonded on release.
+    pub net: i128,
+    pub held_at: u64,
+    /// From this time anyone may release the deposit.
+    pub release_at: u64,
+}
+
+/// How a charged fee or penalty was derived, assembled at charge time from the code path taken.
+/// `final_amount = base_amount + sum(surcharges) - sum(discounts)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChargeBreakdown {
+    /// Charge before adjustments (e.g. pro-rated early exit penalty).
+    pub base_amount: i128,
+    /// Rate the base charge was computed with.
+    pub rate_bps: u32,
+    /// Rounding applied to the base charge (`floor`, or `none` when nothing was charged).
+    pub rounding: Symbol,
+    pub discounts: Vec<(Symbol, i128)>,
+    pub surcharges: Vec<(Symbol, i128)>,
+    /// Amount actually charged.
+    pub final_amount: i128,
+}
+
+/// Early exit penalty config, from `get_early_exit_config`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyExitConfig {
+    /// Address that receives penalties.
+    pub treasury: Address,
+    /// Penalty rate in bps, capped at the ceiling.
+    pub penalty_bps: u32,
+}
+
+/// Preview of an early withdrawal from `quote_early_exit`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyExitQuote {
+    /// Penalty sent to the treasury (surge included).
+    pub penalty: i128,
+    /// Amount paid out: the withdrawal amount less the penalty.
+    pub net: i128,
+    /// Seconds left until the lock-up ends.
+    pub remaining_secs: u64,
+    /// Penalty as a share of the withdrawal amount.
+    pub effective_bps: u32,
+    /// Surge multiplier applied (10000 = none).
+    pub surge_multiplier_bps: u32,
+    /// The lock-up has ended; `withdraw` applies and nothing is charged.
+    pub lockup_ended: bool,
+}
+
+/// Running totals of early exit penalties collected, from `get_fee_accounting`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeAccounting {
+    pub total_penalties_collected: i128,
+    /// Early exits charged a non-zero penalty.
+    pub penalty_count: u64,
+    /// Part of `total_penalties_collected` burned in burn mode rather than sent to the treasury.
+    pub total_penalties_burned: i128,
+}
+
+/// Error codes returned by the bond contract's public entry points. A contract error enum is
+/// limited to 50 variants, so newer subsystems define their own enum from code 100 upward.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BondError {
+    NotInitialized = 1,
+    NotAdmin = 2,
+    NoBond = 3,
+    InsufficientBalance = 4,
+    InvalidAmount = 5,
+    Overflow = 6,
+    BondNotActive = 7,
+    NotRollingBond = 8,
+    WithdrawalAlreadyRequested = 9,
+    NoPendingWithdrawal = 10,
+    WithdrawalNotRequested = 11,
+    NoticePeriodNotElapsed = 12,
+    LockupEnded = 13,
+    UnauthorizedAttester = 14,
+    DuplicateAttestation = 15,
+    AttestationNotFound = 16,
+    AttestationAlreadyRevoked = 17,
+    NotOriginalAttester = 18,
+    NotIdentityOrAdmin = 19,
+    IntentExpired = 20,
+    SlashExceedsBond = 21,
+    Reentrancy = 22,
+    StorageBudgetExceeded = 23,
+    ReadRestricted = 24,
+    InvalidConfig = 25,
+    EarlyExitConfigNotSet = 26,
+    InvalidPenaltyBps = 27,
+    InvalidSurgeConfig = 28,
+    AlreadyInitialized = 29,
+    SlashRecordNotFound = 30,
+    ContractPaused = 31,
+    LockupNotEnded = 32,
+    NoFixedMaturity = 33,
+    NoPendingAdmin = 34,
+    NotPendingAdmin = 35,
+    BondAlreadyActive = 36,
+    BondNotEmpty = 37,
+    AlreadyRollingBond = 38,
+    InvalidNoticePeriod = 39,
+    FixedConversionPending = 40,
+    DepositNotFound = 41,
+    DepositReviewPending = 42,
+    NothingToClaim = 43,
+    DepositRequiresReview = 44,
+    VerificationOutOfOrder = 45,
+    NoAccountingDrift = 46,
+    RecoveryExceedsSlashed = 47,
+    DuplicateTarget = 48,
+    BondInWarmup = 49,
+}
+
+/// Errors returned by the service agreement pledge entry points.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PledgeError {
+    AlreadyExists = 100,
+    NotFound = 101,
+    NotCounterparty = 102,
+    Ended = 103,
+    ExceedsPledge = 104,
+    TooMany = 105,
+}
+
+/// Errors returned by the collateral floor entry points.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CollateralError {
+    CoolingOff = 300,
+}
+
+/// Errors returned by the slash dispute entry points.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SlashDisputeError {
+    NotPending = 200,
+    AlreadyDisputed = 201,
+    WindowClosed = 202,
+    WindowOpen = 203,
+    UnderDispute = 204,
+    NotDisputed = 205,
+}
+
+/// Errors returned when routing fees to the treasury.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TreasuryError {
+    InvalidTreasury = 500,
+}
+
+/// Errors returned by the bond delegation entry points.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DelegateError {
+    NotDelegate = 600,
+    SelfDelegation = 601,
+    NotContractIdentity = 602,
+}
+
+/// Errors returned by the referral entry points.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReferralError {
+    SelfReferral = 400,
+    CircularReferral = 401,
+}
+
+/// Errors returned by the shared amount validation.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AmountError {
+    NonConformingAmount = 700,
+    BelowMinimum = 701,
+    AboveMaximum = 702,
+}
+
+/// Errors returned by the replay protection nonces.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum NonceError {
+    InvalidNonce = 800,
+    IntentNonceUsed = 801,
+}
+
+/// Errors returned by the withdrawal receipt queries.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReceiptError {
+    NotFound = 900,
+    InvalidRetention = 901,
+}
+
+/// Errors returned by the changelog configuration.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ChangelogError {
+    InvalidRetention = 1000,
+}
+
+/// Errors returned by attestation weight and attester stake validation.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AttestationError {
+    ZeroWeight = 1100,
+    WeightExceedsMaximum = 1101,
+    NegativeStake = 1102,
+}
+
 // Re-export attestation type (definitions and validation in types::attestation).
 pub use types::Attestation;
 
 #[contracttype]
 pub enum DataKey {
     Admin,
-    Bond,
+    /// Admin proposed by `propose_admin`, awaiting `accept_admin`.
+    PendingAdmin,
+    /// Holder of the slashing role (falls back to `Admin`).
+    SlashAdmin,
+    /// Holder of the fee and treasury configuration role (falls back to `Admin`).
+    TreasuryAdmin,
+    /// Bond state for an identity.
+    Bond(Address),
+    /// Number of identities that have ever bonded.
+    BondIdentityCount,
+    /// Identity at an enumeration index (persistent storage).
+    BondIdentity(u32),
     Attester(Address),
     Attestation(u64),
     AttestationCounter,
@@ -56,6 +567,17 @@ pub enum DataKey {
     Nonce(Address),
     /// Attester stake used for weighted attestation (set by admin or from bond).
     AttesterStake(Address),
+    /// Retained changelog entries for an identity, oldest first (persistent storage). Present
+    /// once the changelog is enabled.
+    Changelog(Address),
+    /// Consumed withdrawal intent nonce for an identity (persistent; holds the intent deadline).
+    IntentNonce(Address, u64),
+    /// Withdrawal receipt by id (persistent storage).
+    WithdrawalReceipt(u64),
+    /// Receipt ids issued to an identity (persistent storage).
+    IdentityReceipts(Address),
+    /// Charge breakdown attached to a withdrawal receipt (persistent storage).
+    ChargeBreakdown(u64),
 }
 
 #[contract]
@@ -63,522 +585,2611 @@ pub struct CredenceBond;
 
 #[contractimpl]
 impl CredenceBond {
-    /// Initialize the contract (admin).
+    /// Initialize the contract (admin). Can only be called once; later calls fail with
+    /// `BondError::AlreadyInitialized`. The admin also receives the slash and treasury admin
+    /// roles until it hands them out with `set_slash_admin` / `set_treasury_admin`.
     pub fn initialize(e: Env, admin: Address) {
         admin.require_auth();
+        if e.storage().instance().has(&DataKey::Admin) {
+            panic_with_error!(e, BondError::AlreadyInitialized);
+        }
         e.storage().instance().set(&DataKey::Admin, &admin);
+        roles::set_slash_admin(&e, &admin);
+        roles::set_treasury_admin(&e, &admin);
     }
 
-    /// Set early exit penalty config. Only admin should call.
-    pub fn set_early_exit_config(e: Env, admin: Address, treasury: Address, penalty_bps: u32) {
-        admin.require_auth();
-        let stored_admin: Address = e
-            .storage()
+    /// Returns the contract admin. Fails with `BondError::NotInitialized` before `initialize`.
+    pub fn get_admin(e: Env) -> Address {
+        e.storage()
             .instance()
             .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
-        if stored_admin != admin {
-            panic!("not admin");
-        }
-        early_exit_penalty::set_config(&e, treasury, penalty_bps);
+            .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized))
     }
 
-    /// Register an authorized attester (only admin can call).
-    pub fn register_attester(e: Env, attester: Address) {
-        let admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
-        admin.require_auth();
-
+    /// Propose `new_admin` as the next admin (current admin only). The current admin keeps all
+    /// privileges until `new_admin` calls `accept_admin`; a later proposal replaces this one.
+    /// Emits `admin_transfer_proposed` with (current_admin, new_admin, timestamp).
+    pub fn propose_admin(e: Env, current_admin: Address, new_admin: Address) {
+        current_admin.require_auth();
+        slashing::validate_admin(&e, &current_admin);
         e.storage()
             .instance()
-            .set(&DataKey::Attester(attester.clone()), &true);
-        e.events()
-            .publish((Symbol::new(&e, "attester_registered"),), attester);
+            .set(&DataKey::PendingAdmin, &new_admin);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "admin_transfer_proposed"),),
+            (current_admin, new_admin, e.ledger().timestamp()),
+        );
     }
 
-    /// Remove an attester's authorization (only admin can call).
-    pub fn unregister_attester(e: Env, attester: Address) {
-        let admin: Address = e
+    /// Accept a pending admin transfer. Must be called (and authorized) by the proposed admin.
+    /// The slash and treasury admin roles move with it unless they were handed to other keys.
+    /// Emits `admin_transfer_accepted` with (old_admin, new_admin, timestamp).
+    ///
+    /// # Panics
+    /// - `BondError::NoPendingAdmin` if no transfer has been proposed
+    /// - `BondError::NotPendingAdmin` if `new_admin` is not the proposed admin
+    pub fn accept_admin(e: Env, new_admin: Address) {
+        new_admin.require_auth();
+        let pending: Address = e
             .storage()
             .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
+            .get(&DataKey::PendingAdmin)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::NoPendingAdmin));
+        if pending != new_admin {
+            panic_with_error!(e, BondError::NotPendingAdmin);
+        }
+        let old_admin = Self::get_admin(e.clone());
+        roles::migrate_admin(&e, &old_admin, &new_admin);
+        e.storage().instance().set(&DataKey::Admin, &new_admin);
+        e.storage().instance().remove(&DataKey::PendingAdmin);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "admin_transfer_accepted"),),
+            (old_admin, new_admin, e.ledger().timestamp()),
+        );
+    }
+
+    /// Returns the admin awaiting `accept_admin`, if a transfer is pending.
+    pub fn get_pending_admin(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Give the slashing role (`slash`, `slash_with_reason`, `batch_slash`) to `slash_admin`.
+    /// Admin only. Emits `slash_admin_updated` with (old, new).
+    pub fn set_slash_admin(e: Env, admin: Address, slash_admin: Address) {
         admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        let old = roles::get_slash_admin(&e);
+        roles::set_slash_admin(&e, &slash_admin);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "slash_admin_updated"),),
+            (old, slash_admin),
+        );
+    }
 
-        e.storage()
-            .instance()
-            .remove(&DataKey::Attester(attester.clone()));
-        e.events()
-            .publish((Symbol::new(&e, "attester_unregistered"),), attester);
+    /// Give the fee and treasury configuration role (early exit config, ceiling, cap, brackets
+    /// and surge, deposit premium) to `treasury_admin`. Admin only. Emits
+    /// `treasury_admin_updated` with (old, new).
+    pub fn set_treasury_admin(e: Env, admin: Address, treasury_admin: Address) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        let old = roles::get_treasury_admin(&e);
+        roles::set_treasury_admin(&e, &treasury_admin);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "treasury_admin_updated"),),
+            (old, treasury_admin),
+        );
     }
 
-    /// Check if an address is an authorized attester.
-    pub fn is_attester(e: Env, attester: Address) -> bool {
-        e.storage()
-            .instance()
-            .get(&DataKey::Attester(attester))
-            .unwrap_or(false)
+    /// Returns the slash admin (the admin unless delegated).
+    pub fn get_slash_admin(e: Env) -> Address {
+        roles::get_slash_admin(&e)
     }
 
-    /// Create or top-up a bond for an identity. In a full implementation this would
-    /// transfer USDC from the caller and store the bond.
-    pub fn create_bond(
-        e: Env,
-        identity: Address,
-        amount: i128,
-        duration: u64,
-        is_rolling: bool,
-        notice_period_duration: u64,
-    ) -> IdentityBond {
-        // Validate bond amount before creating the bond
-        validation::validate_bond_amount(amount);
-        
-        let bond_start = e.ledger().timestamp();
+    /// Returns the treasury admin (the admin unless delegated).
+    pub fn get_treasury_admin(e: Env) -> Address {
+        roles::get_treasury_admin(&e)
+    }
 
-        // Verify the end timestamp wouldn't overflow
-        let _end_timestamp = bond_start
-            .checked_add(duration)
-            .expect("bond end timestamp would overflow");
+    /// Returns true once `initialize` has been called.
+    pub fn is_initialized(e: Env) -> bool {
+        e.storage().instance().has(&DataKey::Admin)
+    }
 
-        let bond = IdentityBond {
-            identity: identity.clone(),
-            bonded_amount: amount,
-            bond_start,
-            bond_duration: duration,
-            slashed_amount: 0,
-            active: true,
-            is_rolling,
-            withdrawal_requested_at: 0,
-            notice_period_duration,
-        };
-        let key = DataKey::Bond;
-        e.storage().instance().set(&key, &bond);
-        let tier = tiered_bond::get_tier_for_amount(amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &identity, BondTier::Bronze, tier);
-        bond
+    /// Set the token bonds are denominated in. Once set, deposits and withdrawals move real
+    /// balances. Admin only.
+    pub fn set_token(e: Env, admin: Address, token: Address) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        token_transfer::set_token(&e, &token);
     }
 
-    /// Return current bond state for an identity (simplified: single bond per contract instance).
-    pub fn get_identity_state(e: Env) -> IdentityBond {
-        e.storage()
-            .instance()
-            .get::<_, IdentityBond>(&DataKey::Bond)
-            .unwrap_or_else(|| panic!("no bond"))
+    /// Returns the bond token, if one has been set.
+    pub fn get_token(e: Env) -> Option<Address> {
+        token_transfer::get_token(&e)
     }
 
-    /// Add an attestation for a subject (only authorized attesters can call).
-    /// Requires correct nonce for replay prevention; rejects duplicate (verifier, identity, data).
-    /// Weight is computed from attester stake (weighted attestation system).
-    ///
-    /// @param e Contract environment
-    /// @param attester Authorized verifier (must be registered and must pass require_auth)
-    /// @param subject Identity being attested
-    /// @param attestation_data Opaque attestation payload
-    /// @param nonce Current nonce for attester (get_nonce(attester)); incremented on success
-    /// @return The created Attestation (id, verifier, identity, timestamp, weight, data, revoked)
-    pub fn add_attestation(
-        e: Env,
-        attester: Address,
-        subject: Address,
-        attestation_data: String,
-        nonce: u64,
-    ) -> Attestation {
-        attester.require_auth();
+    /// Set early exit penalty config. Treasury admin only. Fails with
+    /// `BondError::InvalidPenaltyBps` if the rate is above the ceiling and with
+    /// `TreasuryError::InvalidTreasury` if `treasury` is this contract. Emits
+    /// `early_exit_config_updated` with (old config, or `None` on first set; new config).
+    pub fn set_early_exit_config(e: Env, admin: Address, treasury: Address, penalty_bps: u32) {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        let old =
+            early_exit_penalty::load_config(&e).map(|(treasury, penalty_bps)| EarlyExitConfig {
+                treasury,
+                penalty_bps,
+            });
+        early_exit_penalty::set_config(&e, treasury.clone(), penalty_bps);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "early_exit_config_updated"),),
+            (
+                old,
+                EarlyExitConfig {
+                    treasury,
+                    penalty_bps,
+                },
+            ),
+        );
+    }
 
-        let is_authorized = e
-            .storage()
-            .instance()
-            .get(&DataKey::Attester(attester.clone()))
-            .unwrap_or(false);
-        if !is_authorized {
-            panic!("unauthorized attester");
+    /// Dry run of `set_early_exit_config`: runs the same role, rate and treasury checks and
+    /// returns the config that would be in force, without writing storage.
+    pub fn preview_early_exit_config(
+        e: Env,
+        admin: Address,
+        treasury: Address,
+        penalty_bps: u32,
+    ) -> EarlyExitConfig {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        early_exit_penalty::validate_config(&e, penalty_bps);
+        early_exit_penalty::validate_treasury(&e, &treasury);
+        EarlyExitConfig {
+            treasury,
+            penalty_bps,
         }
+    }
 
-        nonce::consume_nonce(&e, &attester, nonce);
+    /// Burn early exit penalties instead of sending them to the treasury (treasury admin only).
+    /// Burned penalties are counted in `get_fee_accounting` and cannot be claimed by anyone.
+    /// The early exit config is still required for the rate. Emits `early_exit_burn_updated`
+    /// with the new setting.
+    pub fn set_early_exit_burn(e: Env, admin: Address, burn: bool) {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        early_exit_penalty::set_burn_mode(&e, burn);
+        events::publish(&e, (Symbol::new(&e, "early_exit_burn_updated"),), burn);
+    }
 
-        let dedup_key = types::AttestationDedupKey {
-            verifier: attester.clone(),
-            identity: subject.clone(),
-            attestation_data: attestation_data.clone(),
-        };
-        if e.storage().instance().has(&dedup_key) {
-            panic!("duplicate attestation");
+    /// Whether early exit penalties are burned (false if never set).
+    pub fn get_early_exit_burn(e: Env) -> bool {
+        early_exit_penalty::is_burn_mode(&e)
+    }
+
+    /// Returns the early exit config, the rate capped at the ceiling. Fails with
+    /// `BondError::EarlyExitConfigNotSet` if never set.
+    pub fn get_early_exit_config(e: Env) -> EarlyExitConfig {
+        let (treasury, penalty_bps) = early_exit_penalty::get_config(&e);
+        EarlyExitConfig {
+            treasury,
+            penalty_bps,
         }
+    }
 
-        let counter_key = DataKey::AttestationCounter;
-        let id: u64 = e.storage().instance().get(&counter_key).unwrap_or(0);
-        let next_id = id.checked_add(1).expect("attestation counter overflow");
-        e.storage().instance().set(&counter_key, &next_id);
+    /// Set the ceiling for the early exit penalty rate (treasury admin only). At most 5000 bps (50%) and
+    /// not below the configured rate, otherwise fails with `BondError::InvalidPenaltyBps`.
+    pub fn set_early_exit_max_penalty(e: Env, admin: Address, max_bps: u32) {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        early_exit_penalty::set_max_penalty(&e, max_bps);
+    }
 
-        let weight = weighted_attestation::compute_weight(&e, &attester);
-        types::Attestation::validate_weight(weight);
+    /// Returns the early exit penalty rate ceiling in bps (5000 if never set).
+    pub fn get_early_exit_max_penalty(e: Env) -> u32 {
+        early_exit_penalty::get_max_penalty(&e)
+    }
 
-        let attestation = Attestation {
-            id,
-            verifier: attester.clone(),
-            identity: subject.clone(),
-            timestamp: e.ledger().timestamp(),
-            weight,
-            attestation_data: attestation_data.clone(),
-            revoked: false,
-        };
+    /// Set surge penalty thresholds: (outflow_bps, penalty_multiplier_bps) ascending, applied when
+    /// early outflow within `window_seconds` exceeds outflow_bps of bonded value. Treasury admin
+    /// only.
+    pub fn set_surge_penalty(
+        e: Env,
+        admin: Address,
+        thresholds: Vec<(u32, u32)>,
+        window_seconds: u64,
+    ) {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        early_exit_penalty::set_surge_config(&e, thresholds, window_seconds);
+    }
+
+    /// Set the absolute early exit penalty cap per withdrawal, applied after surge pricing.
+    /// 0 (the default) means no cap. Treasury admin only. Fails with `BondError::InvalidConfig` if negative.
+    pub fn set_early_exit_max_amount(e: Env, admin: Address, max_penalty_amount: i128) {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        early_exit_penalty::set_max_penalty_amount(&e, max_penalty_amount);
+    }
+
+    /// Returns the absolute early exit penalty cap per withdrawal (0 = no cap).
+    pub fn get_early_exit_max_amount(e: Env) -> i128 {
+        early_exit_penalty::get_max_penalty_amount(&e)
+    }
+
+    /// Exempt `identity` from early exit penalties until `expires_at` (treasury admin only).
+    /// Its `withdraw_early` calls are charged 0 and the `early_exit_penalty` event is flagged
+    /// as waived. Replaces any existing waiver. Emits `penalty_waiver_added` with
+    /// (identity, expires_at). Fails with `BondError::InvalidConfig` unless `expires_at` is in
+    /// the future.
+    pub fn add_penalty_waiver(e: Env, admin: Address, identity: Address, expires_at: u64) {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        early_exit_penalty::add_waiver(&e, &identity, expires_at);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "penalty_waiver_added"),),
+            (identity, expires_at),
+        );
+    }
+
+    /// Remove the identity's penalty waiver (treasury admin only). Emits
+    /// `penalty_waiver_removed` with the identity.
+    pub fn remove_penalty_waiver(e: Env, admin: Address, identity: Address) {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        early_exit_penalty::remove_waiver(&e, &identity);
+        events::publish(&e, (Symbol::new(&e, "penalty_waiver_removed"),), identity);
+    }
+
+    /// Returns true if the identity holds a penalty waiver that has not expired.
+    pub fn has_penalty_waiver(e: Env, identity: Address) -> bool {
+        early_exit_penalty::has_waiver(&e, &identity, e.ledger().timestamp())
+    }
+
+    /// Running totals of early exit penalties sent to the treasury: the amount collected and
+    /// the number of penalized exits. Waived exits are not counted.
+    pub fn get_fee_accounting(e: Env) -> FeeAccounting {
+        early_exit_penalty::get_fee_accounting(&e)
+    }
+
+    /// Set sliding-scale penalty brackets: (remaining_fraction_bps, penalty_bps) with strictly
+    /// ascending bounds up to 10000. An early exit pays the flat rate of the first bracket whose
+    /// bound is at least the share of the lock-up remaining (the last bracket above every bound).
+    /// An empty list restores the single pro-rated rate. Treasury admin only. Fails with
+    /// `BondError::InvalidConfig` for bad bounds and `BondError::InvalidPenaltyBps` for a rate
+    /// above the ceiling.
+    pub fn set_early_exit_brackets(e: Env, admin: Address, brackets: Vec<(u32, u32)>) {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        early_exit_penalty::set_brackets(&e, brackets);
+    }
+
+    /// Returns the early exit penalty brackets (empty when the single rate applies).
+    pub fn get_early_exit_brackets(e: Env) -> Vec<(u32, u32)> {
+        early_exit_penalty::get_brackets(&e)
+    }
+
+    /// Get the surge multiplier (bps, 10000 = none) an early withdrawal would pay right now.
+    pub fn get_surge_multiplier(e: Env) -> u32 {
+        let total_bonded = bond_store::get_total_bonded(&e);
+        early_exit_penalty::get_surge_multiplier(&e, e.ledger().timestamp(), total_bonded)
+    }
+
+    /// Get the total bonded amount across all identities.
+    pub fn get_total_bonded(e: Env) -> i128 {
+        bond_store::get_total_bonded(&e)
+    }
+
+    /// Reconcile the contract's counters, scanning up to `limit` bonds (max 50) per call.
+    /// Start with cursor 0 and pass each report's `next_cursor` until `complete`; the final
+    /// report lists any discrepancies. Callable by anyone; starting at 0 restarts the run.
+    /// Fails with `BondError::VerificationOutOfOrder` if `cursor` does not continue the run.
+    pub fn verify_accounting(e: Env, cursor: u32, limit: u32) -> VerificationReport {
+        accounting::verify_page(&e, cursor, limit)
+    }
+
+    /// Correct the total bonded counter by the drift found by the last completed
+    /// `verify_accounting` run (admin only). Returns the new total. Fails with
+    /// `BondError::NoAccountingDrift` if that run found the counter in sync.
+    pub fn repair_total_bonded(e: Env, admin: Address) -> i128 {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        accounting::repair_total_bonded(&e)
+    }
+
+    /// Set the deposit premium routed to the insurance pool (bps, max 1000). Treasury admin only.
+    pub fn set_deposit_premium(e: Env, admin: Address, premium_bps: u32) {
+        admin.require_auth();
+        roles::validate_treasury_admin(&e, &admin);
+        insurance::set_premium_bps(&e, premium_bps);
+    }
+
+    /// Get the deposit premium in basis points (0 = no premium).
+    pub fn get_deposit_premium(e: Env) -> u32 {
+        insurance::get_premium_bps(&e)
+    }
+
+    /// Get the insurance pool balance accumulated from deposit premiums.
+    pub fn get_insurance_pool_balance(e: Env) -> i128 {
+        insurance::get_pool_balance(&e)
+    }
+
+    /// Get lifetime deposit statistics (gross, premium, net).
+    pub fn get_deposit_stats(e: Env) -> DepositStats {
+        insurance::get_deposit_stats(&e)
+    }
+
+    /// Enable or disable strict amounts: every amount must be a multiple of the quantum. Admin only.
+    pub fn set_strict_amounts(e: Env, admin: Address, enabled: bool) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        validation::set_strict_amounts(&e, enabled);
+    }
+
+    /// Set the quantum that amounts must be a multiple of in strict mode (must be > 0). Admin only.
+    pub fn set_amount_quantum(e: Env, admin: Address, quantum: i128) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        validation::set_amount_quantum(&e, quantum);
+    }
+
+    /// Set the minimum net amount a new bond must lock (admin only). Must lie within the
+    /// protocol bounds (`MIN_BOND_AMOUNT..=MAX_BOND_AMOUNT`). Existing bonds are unaffected.
+    pub fn set_min_bond_amount(e: Env, admin: Address, min_amount: i128) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        validation::set_min_bond_amount(&e, min_amount);
+    }
+
+    /// Dry run of `set_min_bond_amount`: runs the same checks and returns the minimum that
+    /// would be in force, without writing storage.
+    pub fn preview_min_bond_amount(e: Env, admin: Address, min_amount: i128) -> i128 {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        validation::validate_min_bond_amount(&e, min_amount);
+        min_amount
+    }
+
+    /// Get the minimum bond amount enforced by `create_bond`.
+    pub fn get_min_bond_amount(e: Env) -> i128 {
+        validation::get_min_bond_amount(&e)
+    }
+
+    /// Get strict amount config (enabled, quantum).
+    pub fn get_strict_amounts(e: Env) -> (bool, i128) {
+        (
+            validation::is_strict_amounts(&e),
+            validation::get_amount_quantum(&e),
+        )
+    }
+
+    /// Enable the bond changelog for an identity. Callable by the identity itself or the admin.
+    pub fn enable_changelog(e: Env, caller: Address, identity: Address) {
+        caller.require_auth();
+        if caller != identity {
+            let stored_admin: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized));
+            if stored_admin != caller {
+                panic_with_error!(e, BondError::NotIdentityOrAdmin);
+            }
+        }
+        changelog::enable(&e, &identity);
+    }
+
+    /// Set how many changelog entries are retained per identity (oldest evicted first). Admin only.
+    /// Fails with `ChangelogError::InvalidRetention` if `retention` is zero.
+    pub fn set_changelog_retention(e: Env, admin: Address, retention: u32) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        changelog::set_retention(&e, retention);
+    }
+
+    /// Get up to `limit` changelog entries for an identity starting at index `start` (oldest first).
+    pub fn get_changelog(e: Env, identity: Address, start: u32, limit: u32) -> Vec<ChangelogEntry> {
+        changelog::get_entries(&e, &identity, start, limit)
+    }
+
+    /// Set the soft limit (bytes) for non-critical instance storage. Admin only.
+    pub fn set_storage_soft_limit(e: Env, admin: Address, limit: u32) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        storage_budget::set_soft_limit(&e, limit);
+    }
+
+    /// Returns (approximate bytes used, soft limit) for non-critical instance storage.
+    pub fn get_storage_budget(e: Env) -> (u32, u32) {
+        (
+            storage_budget::get_usage(&e),
+            storage_budget::get_soft_limit(&e),
+        )
+    }
+
+    /// Register an authorized attester (only admin can call).
+    pub fn register_attester(e: Env, attester: Address) {
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized));
+        admin.require_auth();
 
         e.storage()
             .instance()
-            .set(&DataKey::Attestation(id), &attestation);
-        e.storage().instance().set(&dedup_key, &id);
+            .set(&DataKey::Attester(attester.clone()), &true);
+        events::publish(&e, (Symbol::new(&e, "attester_registered"),), attester);
+    }
 
-        let subject_key = DataKey::SubjectAttestations(subject.clone());
-        let mut attestations: Vec<u64> = e
+    /// Remove an attester's authorization (only admin can call).
+    pub fn unregister_attester(e: Env, attester: Address) {
+        let admin: Address = e
             .storage()
             .instance()
-            .get(&subject_key)
-            .unwrap_or(Vec::new(&e));
-        attestations.push_back(id);
-        e.storage().instance().set(&subject_key, &attestations);
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized));
+        admin.require_auth();
 
-        let count_key = DataKey::SubjectAttestationCount(subject.clone());
-        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
         e.storage()
             .instance()
-            .set(&count_key, &count.saturating_add(1));
+            .remove(&DataKey::Attester(attester.clone()));
+        events::publish(&e, (Symbol::new(&e, "attester_unregistered"),), attester);
+    }
 
-        e.events().publish(
-            (Symbol::new(&e, "attestation_added"), subject),
-            (id, attester, attestation_data, weight),
+    /// Check if an address is an authorized attester.
+    pub fn is_attester(e: Env, attester: Address) -> bool {
+        e.storage()
+            .instance()
+            .get(&DataKey::Attester(attester))
+            .unwrap_or(false)
+    }
+
+    /// Create a bond for an identity. When a bond token is set, the full amount (including any
+    /// deposit premium) is transferred from the identity to the contract. Fails with
+    /// `BondError::BondAlreadyActive` if the identity already has an active bond (use `top_up`);
+    /// a closed (inactive) bond may be replaced by a new one.
+    /// Emits `bond_created` with (identity, net_amount, bond_start, bond_duration, is_rolling,
+    /// notice_period_duration, tier).
+    pub fn create_bond(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        duration: u64,
+        is_rolling: bool,
+        notice_period_duration: u64,
+    ) -> IdentityBond {
+        Self::create_bond_impl(
+            e,
+            identity,
+            amount,
+            duration,
+            is_rolling,
+            notice_period_duration,
+            None,
+        )
+    }
+
+    /// `create_bond` with a referrer recorded for the life of the bond. Reserves the configured
+    /// referral reward from the insurance pool; it vests once the bond reaches the minimum age
+    /// and is forfeited if the bond closes first. Emits `referral_recorded` with (identity,
+    /// referrer, reward).
+    ///
+    /// # Panics
+    /// - `ReferralError::SelfReferral` if `referrer` is the identity
+    /// - `ReferralError::CircularReferral` if the referrer's chain leads back to the identity
+    pub fn create_bond_with_referrer(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        duration: u64,
+        is_rolling: bool,
+        notice_period_duration: u64,
+        referrer: Address,
+    ) -> IdentityBond {
+        Self::create_bond_impl(
+            e,
+            identity,
+            amount,
+            duration,
+            is_rolling,
+            notice_period_duration,
+            Some(referrer),
+        )
+    }
+
+    fn create_bond_impl(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        duration: u64,
+        is_rolling: bool,
+        notice_period_duration: u64,
+        referrer: Option<Address>,
+    ) -> IdentityBond {
+        identity.require_auth();
+        pause::require_not_paused(&e);
+        validation::validate_amount_conforms(&e, amount);
+        // Route the deposit premium to the insurance pool; the net must satisfy amount bounds
+        let (premium, net) = insurance::split_deposit(&e, amount);
+        validation::validate_bond_amount(&e, net);
+        validation::validate_min_bond(&e, net);
+        let before = bond_store::load(&e, &identity);
+        if before.as_ref().is_some_and(|b| b.active) {
+            panic_with_error!(e, BondError::BondAlreadyActive);
+        }
+        rolling_bond::clear_fixed_conversion(&e, &identity);
+        if let Some(referrer) = &referrer {
+            referral::validate_referrer(&e, &identity, referrer);
+        }
+
+        let bond_start = e.ledger().timestamp();
+
+        // Verify the end timestamp wouldn't overflow
+        let _end_timestamp = bond_start
+            .checked_add(duration)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+
+        let bond = IdentityBond {
+            identity: identity.clone(),
+            bonded_amount: net,
+            bond_start,
+            bond_duration: duration,
+            slashed_amount: 0,
+            active: true,
+            is_rolling,
+            withdrawal_requested_at: 0,
+            notice_period_duration,
+        };
+        token_transfer::transfer_in(&e, &identity, amount);
+        bond_store::save(&e, before.as_ref(), &bond, "create_bond");
+        tiered_bond::clear_recorded_tier(&e, &identity);
+        let tier = tiered_bond::get_tier(&e, net);
+        events::publish_or_compact(
+            &e,
+            (Symbol::new(&e, "bond_created"),),
+            (
+                identity.clone(),
+                net,
+                bond_start,
+                duration,
+                is_rolling,
+                notice_period_duration,
+                tier.clone(),
+            ),
+            (identity.clone(), net),
         );
+        insurance::record_deposit(&e, &identity, amount, premium, net);
+        tiered_bond::update_tier(&e, &identity, 0, net);
+        match &referrer {
+            Some(referrer) => referral::attribute(&e, &bond, referrer),
+            None => referral::clear_settled(&e, &identity),
+        }
+        bond
+    }
 
-        attestation
+    /// Timestamp at which a fixed bond matures (`bond_start + bond_duration`); from then on
+    /// `withdraw` is allowed without penalty.
+    ///
+    /// # Panics
+    /// - `BondError::NoBond` if the identity has no bond
+    /// - `BondError::NoFixedMaturity` for rolling bonds (they exit via the notice period)
+    pub fn get_bond_maturity(e: Env, identity: Address) -> u64 {
+        let bond = bond_store::get(&e, &identity);
+        if bond.is_rolling {
+            panic_with_error!(e, BondError::NoFixedMaturity);
+        }
+        bond.bond_start.saturating_add(bond.bond_duration)
     }
 
-    /// Revoke an attestation (only the original attester can revoke). Requires correct nonce.
-    pub fn revoke_attestation(e: Env, attester: Address, attestation_id: u64, nonce: u64) {
-        attester.require_auth();
-        nonce::consume_nonce(&e, &attester, nonce);
+    /// Whether the identity's bond can be withdrawn without penalty now: a fixed bond once its
+    /// lock-up has ended, a rolling bond once a requested withdrawal's notice period has passed.
+    ///
+    /// # Panics
+    /// - `BondError::NoBond` if the identity has no bond
+    pub fn is_withdrawal_ready(e: Env, identity: Address) -> bool {
+        let bond = bond_store::get(&e, &identity);
+        rolling_bond::is_ready_for_withdrawal(&bond, e.ledger().timestamp())
+    }
 
-        let key = DataKey::Attestation(attestation_id);
-        let mut attestation: Attestation = e
-            .storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("attestation not found"));
+    /// Seconds until `is_withdrawal_ready` holds; 0 once it does. A rolling bond without a
+    /// withdrawal request returns `u64::MAX` (call `request_withdrawal` to start the notice).
+    ///
+    /// # Panics
+    /// - `BondError::NoBond` if the identity has no bond
+    pub fn time_until_withdrawal_ready(e: Env, identity: Address) -> u64 {
+        let bond = bond_store::get(&e, &identity);
+        rolling_bond::time_until_ready(&bond, e.ledger().timestamp())
+    }
 
-        if attestation.verifier != attester {
-            panic!("only original attester can revoke");
+    /// Close a bond whose available balance (bonded minus slashed) is zero or held back by the
+    /// collateral floor, e.g. after a fully slashed bond has been settled. The entry is kept as
+    /// an inactive tombstone so the identity stays enumerable; `create_bond` may then open a
+    /// new bond. Emits `bond_closed` with (identity, timestamp). Withdrawals that empty a bond
+    /// deactivate it automatically.
+    ///
+    /// Collateral held by the floor leaves the bond and becomes claimable with
+    /// `claim_collateral` after the cooling-off period (`collateral_released` event with
+    /// (identity, amount, release_at)).
+    ///
+    /// # Panics
+    /// - `BondError::NoBond` / `BondError::BondNotActive` if there is no open bond
+    /// - `BondError::BondNotEmpty` if any balance is still withdrawable, or collateral would be
+    ///   released while a pledge is live
+    pub fn close_bond(e: Env, identity: Address) -> IdentityBond {
+        identity.require_auth();
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        let floor = collateral::locked(&e, &bond);
+        if bond.bonded_amount.saturating_sub(bond.slashed_amount) > floor
+            || (floor > 0 && pledges::encumbered(&e, &identity, e.ledger().timestamp()) > 0)
+        {
+            panic_with_error!(e, BondError::BondNotEmpty);
         }
-        if attestation.revoked {
-            panic!("attestation already revoked");
+        bond.bonded_amount -= floor;
+        bond.active = false;
+        tiered_bond::update_tier(&e, &identity, before.bonded_amount, bond.bonded_amount);
+        bond_store::save(&e, Some(&before), &bond, "close_bond");
+        if floor > 0 {
+            let claimable = collateral::release(&e, &identity, floor);
+            events::publish(
+                &e,
+                (Symbol::new(&e, "collateral_released"),),
+                (identity.clone(), floor, claimable.release_at),
+            );
         }
+        events::publish(
+            &e,
+            (Symbol::new(&e, "bond_closed"),),
+            (identity, e.ledger().timestamp()),
+        );
+        bond
+    }
 
-        attestation.revoked = true;
-        e.storage().instance().set(&key, &attestation);
+    /// Returns up to `limit` identities that have bonded, starting at index `start`.
+    pub fn list_bond_identities(e: Env, start: u32, limit: u32) -> Vec<Address> {
+        bond_store::list_identities(&e, start, limit)
+    }
 
-        let dedup_key = types::AttestationDedupKey {
-            verifier: attestation.verifier.clone(),
-            identity: attestation.identity.clone(),
-            attestation_data: attestation.attestation_data.clone(),
-        };
-        e.storage().instance().remove(&dedup_key);
+    /// Returns the number of identities that have ever bonded.
+    pub fn get_bond_identity_count(e: Env) -> u32 {
+        bond_store::get_identity_count(&e)
+    }
 
-        let count_key = DataKey::SubjectAttestationCount(attestation.identity.clone());
-        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
-        e.storage()
-            .instance()
-            .set(&count_key, &count.saturating_sub(1));
+    /// Extend the storage TTL of the identity's bond. Anyone may call (and pay) to keep a bond
+    /// from being archived. Fails with `BondError::NoBond` if the identity has never bonded.
+    pub fn refresh_bond_ttl(e: Env, identity: Address) {
+        bond_store::refresh_ttl(&e, &identity);
+    }
+
+    /// Return current bond state for an identity. Fails with `BondError::NoBond` if it has never bonded.
+    /// In privacy mode the identity must authorize the read; use `get_identity_state_as` to
+    /// read as the admin.
+    pub fn get_identity_state(e: Env, identity: Address) -> IdentityBond {
+        privacy::require_reader(&e, &identity, &identity);
+        bond_store::get(&e, &identity)
+    }
+
+    /// Return current bond state for an identity, read by `reader`. In privacy mode `reader`
+    /// must authorize and be the identity or the admin, otherwise fails with `BondError::ReadRestricted`.
+    pub fn get_identity_state_as(e: Env, reader: Address, identity: Address) -> IdentityBond {
+        privacy::require_reader(&e, &reader, &identity);
+        bond_store::get(&e, &identity)
+    }
+
+    /// Returns the identity's unslashed balance, `bonded_amount - slashed_amount` (never
+    /// negative). Slashes still pending are not counted; see `get_encumbrances`. Fails with `BondError::NoBond` if it has never bonded. In privacy mode the
+    /// identity must authorize the read, as for `get_identity_state`.
+    pub fn get_available_balance(e: Env, identity: Address) -> i128 {
+        privacy::require_reader(&e, &identity, &identity);
+        let bond = bond_store::get(&e, &identity);
+        bond.bonded_amount
+            .saturating_sub(bond.slashed_amount)
+            .max(0)
+    }
+
+    /// Returns the identity's bonded amount, slashed part included. Same access rules as
+    /// `get_available_balance`.
+    pub fn get_bonded_amount(e: Env, identity: Address) -> i128 {
+        privacy::require_reader(&e, &identity, &identity);
+        bond_store::get(&e, &identity).bonded_amount
+    }
+
+    /// Returns the identity's slashed amount. Same access rules as `get_available_balance`.
+    pub fn get_slashed_amount(e: Env, identity: Address) -> i128 {
+        privacy::require_reader(&e, &identity, &identity);
+        bond_store::get(&e, &identity).slashed_amount
+    }
+
+    /// Enable or disable privacy mode for bond detail views. Admin only.
+    pub fn set_privacy_mode(e: Env, admin: Address, enabled: bool) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        privacy::set_enabled(&e, enabled);
+    }
+
+    /// Returns true if privacy mode is enabled.
+    pub fn get_privacy_mode(e: Env) -> bool {
+        privacy::is_enabled(&e)
+    }
+
+    /// Set the event payload mode (admin only). `Compact` events carry only ids and amounts;
+    /// the rest is available from the record getters. Takes effect immediately.
+    pub fn set_event_mode(e: Env, admin: Address, mode: EventMode) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        events::set_mode(&e, mode);
+    }
+
+    /// Returns the event payload mode (`Rich` by default).
+    pub fn get_event_mode(e: Env) -> EventMode {
+        events::get_mode(&e)
+    }
+
+    /// Pause user-initiated operations (admin only). While paused, `create_bond`, `top_up`,
+    /// withdrawals, `request_withdrawal` and `renew_if_rolling` fail with
+    /// `BondError::ContractPaused`; slashing still works. Emits `paused` with (admin, timestamp).
+    pub fn pause(e: Env, admin: Address) {
+        Self::set_paused(&e, &admin, true);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "paused"),),
+            (admin, e.ledger().timestamp()),
+        );
+    }
+
+    /// Resume user-initiated operations (admin only). Emits `unpaused` with (admin, timestamp).
+    pub fn unpause(e: Env, admin: Address) {
+        Self::set_paused(&e, &admin, false);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "unpaused"),),
+            (admin, e.ledger().timestamp()),
+        );
+    }
+
+    /// Returns true if user-initiated operations are paused.
+    pub fn is_paused(e: Env) -> bool {
+        pause::is_paused(&e)
+    }
+
+    fn set_paused(e: &Env, admin: &Address, paused: bool) {
+        admin.require_auth();
+        slashing::validate_admin(e, admin);
+        pause::set_paused(e, paused);
+    }
+
+    /// Add an attestation for a subject (only authorized attesters can call).
+    /// Requires correct nonce for replay prevention; rejects duplicate (verifier, identity, data).
+    /// Weight is computed from attester stake (weighted attestation system).
+    ///
+    /// @param e Contract environment
+    /// @param attester Authorized verifier (must be registered and must pass require_auth)
+    /// @param subject Identity being attested
+    /// @param attestation_data Opaque attestation payload
+    /// @param nonce Current nonce for attester (get_nonce(attester)); incremented on success
+    /// @return The created Attestation (id, verifier, identity, timestamp, weight, data, revoked)
+    pub fn add_attestation(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        attestation_data: String,
+        nonce: u64,
+    ) -> Attestation {
+        attester.require_auth();
+
+        let is_authorized = e
+            .storage()
+            .instance()
+            .get(&DataKey::Attester(attester.clone()))
+            .unwrap_or(false);
+        if !is_authorized {
+            panic_with_error!(e, BondError::UnauthorizedAttester);
+        }
+
+        nonce::consume_nonce(&e, &attester, nonce);
+
+        let dedup_key = types::AttestationDedupKey {
+            verifier: attester.clone(),
+            identity: subject.clone(),
+            attestation_data: attestation_data.clone(),
+        };
+        if e.storage().instance().has(&dedup_key) {
+            panic_with_error!(e, BondError::DuplicateAttestation);
+        }
+
+        storage_budget::charge(
+            &e,
+            storage_budget::ATTESTATION_BASE_BYTES.saturating_add(attestation_data.len()),
+        );
+
+        let counter_key = DataKey::AttestationCounter;
+        let id: u64 = e.storage().instance().get(&counter_key).unwrap_or(0);
+        let next_id = id
+            .checked_add(1)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+        e.storage().instance().set(&counter_key, &next_id);
+
+        let weight = weighted_attestation::compute_weight(&e, &attester);
+        types::Attestation::validate_weight(&e, weight);
+
+        let attestation = Attestation {
+            id,
+            verifier: attester.clone(),
+            identity: subject.clone(),
+            timestamp: e.ledger().timestamp(),
+            weight,
+            attestation_data: attestation_data.clone(),
+            revoked: false,
+        };
+
+        e.storage()
+            .instance()
+            .set(&DataKey::Attestation(id), &attestation);
+        e.storage().instance().set(&dedup_key, &id);
+
+        let subject_key = DataKey::SubjectAttestations(subject.clone());
+        let mut attestations: Vec<u64> = e
+            .storage()
+            .instance()
+            .get(&subject_key)
+            .unwrap_or(Vec::new(&e));
+        attestations.push_back(id);
+        e.storage().instance().set(&subject_key, &attestations);
+
+        let count_key = DataKey::SubjectAttestationCount(subject.clone());
+        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&count_key, &count.saturating_add(1));
+
+        events::publish_or_compact(
+            &e,
+            (Symbol::new(&e, "attestation_added"), subject),
+            (id, attester, attestation_data, weight),
+            (id, weight),
+        );
+
+        attestation
+    }
+
+    /// Revoke an attestation (only the original attester can revoke). Requires correct nonce.
+    pub fn revoke_attestation(e: Env, attester: Address, attestation_id: u64, nonce: u64) {
+        attester.require_auth();
+        nonce::consume_nonce(&e, &attester, nonce);
+
+        let key = DataKey::Attestation(attestation_id);
+        let mut attestation: Attestation = e
+            .storage()
+            .instance()
+            .get(&key)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::AttestationNotFound));
+
+        if attestation.verifier != attester {
+            panic_with_error!(e, BondError::NotOriginalAttester);
+        }
+        if attestation.revoked {
+            panic_with_error!(e, BondError::AttestationAlreadyRevoked);
+        }
+
+        attestation.revoked = true;
+        e.storage().instance().set(&key, &attestation);
+
+        let dedup_key = types::AttestationDedupKey {
+            verifier: attestation.verifier.clone(),
+            identity: attestation.identity.clone(),
+            attestation_data: attestation.attestation_data.clone(),
+        };
+        e.storage().instance().remove(&dedup_key);
+
+        let count_key = DataKey::SubjectAttestationCount(attestation.identity.clone());
+        let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&count_key, &count.saturating_sub(1));
+
+        events::publish(
+            &e,
+            (
+                Symbol::new(&e, "attestation_revoked"),
+                attestation.identity.clone(),
+            ),
+            (attestation_id, attester),
+        );
+    }
+
+    /// Get an attestation by ID.
+    pub fn get_attestation(e: Env, attestation_id: u64) -> Attestation {
+        e.storage()
+            .instance()
+            .get(&DataKey::Attestation(attestation_id))
+            .unwrap_or_else(|| panic_with_error!(e, BondError::AttestationNotFound))
+    }
+
+    /// Get all attestation IDs for a subject.
+    pub fn get_subject_attestations(e: Env, subject: Address) -> Vec<u64> {
+        e.storage()
+            .instance()
+            .get(&DataKey::SubjectAttestations(subject))
+            .unwrap_or(Vec::new(&e))
+    }
+
+    /// Get attestation count for a subject (identity). O(1).
+    pub fn get_subject_attestation_count(e: Env, subject: Address) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::SubjectAttestationCount(subject))
+            .unwrap_or(0)
+    }
+
+    /// Get current nonce for an identity (for replay prevention). Use this value in the next state-changing call.
+    pub fn get_nonce(e: Env, identity: Address) -> u64 {
+        nonce::get_nonce(&e, &identity)
+    }
+
+    /// Set attester stake (admin only). Used for weighted attestation; weight is derived from this.
+    pub fn set_attester_stake(e: Env, admin: Address, attester: Address, amount: i128) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        weighted_attestation::set_attester_stake(&e, &attester, amount);
+    }
+
+    /// Set weight config: multiplier_bps (e.g. 100 = 1%), max_attestation_weight. Admin only.
+    pub fn set_weight_config(e: Env, admin: Address, multiplier_bps: u32, max_weight: u32) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        weighted_attestation::set_weight_config(&e, multiplier_bps, max_weight);
+    }
+
+    /// Get weight config (multiplier_bps, max_weight).
+    pub fn get_weight_config(e: Env) -> (u32, u32) {
+        weighted_attestation::get_weight_config(&e)
+    }
+
+    /// Withdraw from bond. Checks that the bond has sufficient balance after accounting for slashed amount.
+    /// Fixed bonds must have reached `bond_start + bond_duration` (otherwise fails with
+    /// `BondError::LockupNotEnded`; use `withdraw_early`). Rolling bonds must have requested
+    /// withdrawal and waited out the notice period. Fails with `BondError::InvalidAmount` unless
+    /// `amount` is positive.
+    /// Returns the updated bond with reduced bonded_amount.
+    pub fn withdraw(e: Env, identity: Address, amount: i128) -> IdentityBond {
+        identity.require_auth();
+        Self::withdraw_impl(e, identity, amount, None)
+    }
+
+    /// Withdraw before lock-up end; applies early exit penalty and transfers penalty to treasury
+    /// (or burns it in burn mode). Net amount to user = amount - penalty. Use when lock-up has
+    /// not yet ended. Fails with `TreasuryError::InvalidTreasury` if the treasury is this
+    /// contract or the identity itself, and with `BondError::InvalidAmount` unless `amount` is
+    /// positive.
+    pub fn withdraw_early(e: Env, identity: Address, amount: i128) -> IdentityBond {
+        identity.require_auth();
+        Self::withdraw_early_impl(e, identity, amount, None)
+    }
+
+    fn withdraw_impl(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        destination: Option<Address>,
+    ) -> IdentityBond {
+        pause::require_not_paused(&e);
+        if amount <= 0 {
+            panic_with_error!(e, BondError::InvalidAmount);
+        }
+        validation::validate_amount_conforms(&e, amount);
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        let now = e.ledger().timestamp();
+        rolling_bond::settle_fixed_conversion(&e, &mut bond, now);
+        if bond.is_rolling {
+            rolling_bond::check_notice_period_elapsed(&e, &bond, now);
+        } else if now < bond.bond_start.saturating_add(bond.bond_duration) {
+            // Before maturity the early exit path (with penalty) must be used
+            panic_with_error!(e, BondError::LockupNotEnded);
+        }
+
+        // Calculate available balance (bonded - slashed - pending - pledged - collateral floor)
+        let available = bond
+            .bonded_amount
+            .checked_sub(bond.slashed_amount)
+            .and_then(|a| a.checked_sub(slashing::pending_amount(&e, &identity)))
+            .and_then(|a| a.checked_sub(pledges::encumbered(&e, &identity, now)))
+            .and_then(|a| a.checked_sub(collateral::locked(&e, &bond)))
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+
+        // Verify sufficient available balance for withdrawal
+        if amount > available {
+            panic_with_error!(e, BondError::InsufficientBalance);
+        }
+
+        // Perform withdrawal with overflow protection
+        bond.bonded_amount = bond
+            .bonded_amount
+            .checked_sub(amount)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+
+        // Verify invariant: slashed amount should not exceed bonded amount after withdrawal
+        if bond.slashed_amount > bond.bonded_amount {
+            panic_with_error!(e, BondError::InsufficientBalance);
+        }
+        if bond.bonded_amount == 0 {
+            bond.active = false;
+        }
+        tiered_bond::update_tier(&e, &bond.identity, before.bonded_amount, bond.bonded_amount);
+
+        bond_store::save(&e, Some(&before), &bond, "withdraw");
+        let destination = destination.unwrap_or_else(|| bond.identity.clone());
+        token_transfer::transfer_out(&e, &destination, amount);
+        if bond.is_rolling {
+            events::publish(
+                &e,
+                (Symbol::new(&e, "rolling_bond_exited"),),
+                (bond.identity.clone(), amount, bond.withdrawal_requested_at),
+            );
+        }
+        let breakdown = receipts::no_charge(&e);
+        receipts::issue(
+            &e,
+            &bond.identity,
+            amount,
+            &breakdown,
+            "withdraw",
+            &destination,
+        );
+        bond
+    }
+
+    fn withdraw_early_impl(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        destination: Option<Address>,
+    ) -> IdentityBond {
+        pause::require_not_paused(&e);
+        if amount <= 0 {
+            panic_with_error!(e, BondError::InvalidAmount);
+        }
+        validation::validate_amount_conforms(&e, amount);
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        let now = e.ledger().timestamp();
+        Self::require_early_exit_available(&e, &bond, amount, now);
+
+        let end = bond.bond_start.saturating_add(bond.bond_duration);
+        if now >= end {
+            panic_with_error!(e, BondError::LockupEnded);
+        }
+
+        let (treasury, surge, bracket, waived, breakdown) =
+            Self::early_exit_charge(&e, &bond, amount, now);
+        let penalty = breakdown.final_amount;
+        early_exit_penalty::record_early_outflow(&e, now, amount);
+        let burned = treasury == e.current_contract_address();
+        let accounting = early_exit_penalty::record_penalty_collected(&e, penalty, burned);
+        // Penalty 0 with `waived` set records an exit excused by a waiver
+        events::publish(
+            &e,
+            (Symbol::new(&e, "early_exit_penalty"),),
+            (
+                bond.identity.clone(),
+                amount,
+                penalty,
+                treasury.clone(),
+                surge,
+                bracket,
+                waived,
+                accounting.total_penalties_collected,
+            ),
+        );
+        bond.bonded_amount = bond
+            .bonded_amount
+            .checked_sub(amount)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+        if bond.slashed_amount > bond.bonded_amount {
+            panic_with_error!(e, BondError::InsufficientBalance);
+        }
+        if bond.bonded_amount == 0 {
+            bond.active = false;
+        }
+        tiered_bond::update_tier(&e, &bond.identity, before.bonded_amount, bond.bonded_amount);
+
+        bond_store::save(&e, Some(&before), &bond, "withdraw_early");
+        let destination = destination.unwrap_or_else(|| bond.identity.clone());
+        let net = amount
+            .checked_sub(penalty)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+        token_transfer::transfer_out(&e, &destination, net);
+        if burned {
+            token_transfer::burn(&e, penalty);
+        } else {
+            token_transfer::transfer_out(&e, &treasury, penalty);
+        }
+        receipts::issue(
+            &e,
+            &bond.identity,
+            amount,
+            &breakdown,
+            "withdraw_early",
+            &destination,
+        );
+        bond
+    }
+
+    /// Reject an early exit of more than the bond's free balance (unslashed, less pending
+    /// slashes, live pledges and the collateral floor).
+    fn require_early_exit_available(e: &Env, bond: &IdentityBond, amount: i128, now: u64) {
+        let available = bond
+            .bonded_amount
+            .checked_sub(bond.slashed_amount)
+            .and_then(|a| a.checked_sub(slashing::pending_amount(e, &bond.identity)))
+            .and_then(|a| a.checked_sub(pledges::encumbered(e, &bond.identity, now)))
+            .and_then(|a| a.checked_sub(collateral::locked(e, bond)))
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+        if amount > available {
+            panic_with_error!(e, BondError::InsufficientBalance);
+        }
+    }
+
+    /// Charge for withdrawing `amount` early from `bond` at `now`, before its lock-up ends:
+    /// the bracket or pro-rated penalty with any surge applied, clamped to the absolute cap and
+    /// waived entirely for an identity with a live waiver. Returns (penalty recipient: the
+    /// treasury, or this contract in burn mode; surge multiplier bps, selected bracket, waived,
+    /// breakdown). Reads state only.
+    fn early_exit_charge(
+        e: &Env,
+        bond: &IdentityBond,
+        amount: i128,
+        now: u64,
+    ) -> (Address, u32, Option<(u32, u32)>, bool, ChargeBreakdown) {
+        let (treasury, penalty_bps) = early_exit_penalty::get_config(e);
+        let treasury = early_exit_penalty::penalty_recipient(e, treasury, &bond.identity);
+        let remaining = bond
+            .bond_start
+            .saturating_add(bond.bond_duration)
+            .saturating_sub(now);
+        let (base_penalty, rate_bps, bracket) =
+            early_exit_penalty::penalty_for(e, amount, remaining, bond.bond_duration, penalty_bps);
+        let total_bonded = bond_store::get_total_bonded(e);
+        let surge = early_exit_penalty::get_surge_multiplier(e, now, total_bonded);
+        let surged = early_exit_penalty::apply_surge(e, base_penalty, amount, surge);
+        let mut surcharges = Vec::new(e);
+        if surged > base_penalty {
+            surcharges.push_back((Symbol::new(e, "surge"), surged - base_penalty));
+        }
+        let mut discounts = Vec::new(e);
+        let cap = early_exit_penalty::get_max_penalty_amount(e);
+        let waived = early_exit_penalty::has_waiver(e, &bond.identity, now);
+        if waived {
+            discounts.push_back((Symbol::new(e, "waiver"), surged));
+        } else if cap > 0 && surged > cap {
+            discounts.push_back((Symbol::new(e, "penalty_cap"), surged - cap));
+        }
+        let breakdown = receipts::finalize_charge(
+            e,
+            amount,
+            base_penalty,
+            rate_bps,
+            "floor",
+            discounts,
+            surcharges,
+        );
+        (treasury, surge, bracket, waived, breakdown)
+    }
+
+    /// Preview what `withdraw_early(identity, amount)` would charge in the current ledger,
+    /// computed by the same code. Read-only: no state change, no events. Once the lock-up has
+    /// ended the quote is penalty-free with `lockup_ended` set (use `withdraw` then).
+    ///
+    /// # Panics
+    /// Same as `withdraw_early` for an invalid amount, an inactive bond, an amount above the
+    /// free balance, a missing early exit config or an invalid treasury. A non-positive amount
+    /// fails with `BondError::InvalidAmount`.
+    pub fn quote_early_exit(e: Env, identity: Address, amount: i128) -> EarlyExitQuote {
+        if amount <= 0 {
+            panic_with_error!(e, BondError::InvalidAmount);
+        }
+        validation::validate_amount_conforms(&e, amount);
+        let bond = bond_store::get(&e, &identity);
+        bond_store::require_bond_active(&e, &bond);
+        let now = e.ledger().timestamp();
+        Self::require_early_exit_available(&e, &bond, amount, now);
+
+        let end = bond.bond_start.saturating_add(bond.bond_duration);
+        if now >= end {
+            return EarlyExitQuote {
+                penalty: 0,
+                net: amount,
+                remaining_secs: 0,
+                effective_bps: 0,
+                surge_multiplier_bps: early_exit_penalty::BASE_MULTIPLIER_BPS,
+                lockup_ended: true,
+            };
+        }
+        let (_, surge, _, _, breakdown) = Self::early_exit_charge(&e, &bond, amount, now);
+        let penalty = breakdown.final_amount;
+        EarlyExitQuote {
+            penalty,
+            net: amount - penalty,
+            remaining_secs: end - now,
+            effective_bps: (penalty * 10_000 / amount) as u32,
+            surge_multiplier_bps: surge,
+            lockup_ended: false,
+        }
+    }
+
+    /// Execute a withdrawal intent signed by the identity (relayer submission).
+    /// Checks the deadline and single-use nonce, then runs the normal withdrawal logic:
+    /// `withdraw_early` (with penalty) before lock-up end, `withdraw` afterwards.
+    /// The net amount is credited to `intent.destination`.
+    pub fn execute_withdrawal_intent(e: Env, intent: WithdrawalIntent) -> IdentityBond {
+        intent
+            .identity
+            .require_auth_for_args((intent.clone(),).into_val(&e));
+
+        if e.ledger().timestamp() > intent.deadline {
+            panic_with_error!(e, BondError::IntentExpired);
+        }
+        nonce::consume_intent_nonce(&e, &intent.identity, intent.nonce, intent.deadline);
+
+        let bond = bond_store::get(&e, &intent.identity);
+        let end = bond.bond_start.saturating_add(bond.bond_duration);
+        let destination = Some(intent.destination.clone());
+        let bond = if e.ledger().timestamp() < end {
+            Self::withdraw_early_impl(
+                e.clone(),
+                intent.identity.clone(),
+                intent.amount,
+                destination,
+            )
+        } else {
+            Self::withdraw_impl(
+                e.clone(),
+                intent.identity.clone(),
+                intent.amount,
+                destination,
+            )
+        };
+        events::publish(
+            &e,
+            (
+                Symbol::new(&e, "withdrawal_intent_executed"),
+                intent.identity.clone(),
+            ),
+            (intent.destination, intent.amount, intent.nonce),
+        );
+        bond
+    }
+
+    /// Returns true if a withdrawal intent nonce has already been consumed for the identity.
+    pub fn is_intent_nonce_used(e: Env, identity: Address, nonce: u64) -> bool {
+        nonce::is_intent_nonce_used(&e, &identity, nonce)
+    }
+
+    /// Get a withdrawal receipt by id.
+    pub fn get_withdrawal_receipt(e: Env, id: u64) -> WithdrawalReceipt {
+        receipts::get_receipt(&e, id)
+    }
+
+    /// Explain how the charge on a withdrawal receipt was derived.
+    pub fn explain_charge(e: Env, receipt_id: u64) -> ChargeBreakdown {
+        receipts::get_breakdown(&e, receipt_id)
+    }
+
+    /// Get up to `limit` of an identity's retained withdrawal receipt ids, in issue order,
+    /// starting at index `start`. Only the latest `get_receipt_retention` ids are kept.
+    pub fn get_identity_receipts(e: Env, identity: Address, start: u32, limit: u32) -> Vec<u64> {
+        receipts::get_identity_receipts(&e, &identity, start, limit)
+    }
+
+    /// Set how many receipt ids each identity's index keeps (oldest dropped first; the receipts
+    /// stay retrievable by id). Admin only. Fails with `ReceiptError::InvalidRetention` if
+    /// `retention` is zero.
+    pub fn set_receipt_retention(e: Env, admin: Address, retention: u32) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        receipts::set_retention(&e, retention);
+    }
+
+    /// Get how many receipt ids each identity's index keeps.
+    pub fn get_receipt_retention(e: Env) -> u32 {
+        receipts::get_retention(&e)
+    }
+
+    /// Get the number of withdrawal receipts issued (also the latest receipt id).
+    pub fn get_receipt_count(e: Env) -> u64 {
+        receipts::get_receipt_count(&e)
+    }
+
+    /// Request withdrawal (rolling bonds). Withdrawal allowed after notice period.
+    pub fn request_withdrawal(e: Env, identity: Address) -> IdentityBond {
+        identity.require_auth();
+        Self::request_withdrawal_impl(e, identity)
+    }
+
+    pub fn request_withdrawal_as(e: Env, caller: Address, identity: Address) -> IdentityBond {
+        contract_identity::require_identity_or_controller(&e, &caller, &identity);
+        Self::request_withdrawal_impl(e, identity)
+    }
+
+    fn request_withdrawal_impl(e: Env, identity: Address) -> IdentityBond {
+        pause::require_not_paused(&e);
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        if !bond.is_rolling {
+            panic_with_error!(e, BondError::NotRollingBond);
+        }
+        if bond.withdrawal_requested_at != 0 {
+            panic_with_error!(e, BondError::WithdrawalAlreadyRequested);
+        }
+        bond.withdrawal_requested_at = e.ledger().timestamp();
+        bond_store::save(&e, Some(&before), &bond, "request_withdrawal");
+        events::publish(
+            &e,
+            (Symbol::new(&e, "withdrawal_requested"),),
+            (bond.identity.clone(), bond.withdrawal_requested_at),
+        );
+        bond
+    }
+
+    /// Cancel a pending withdrawal request on a rolling bond. The bond keeps rolling; a later
+    /// request starts a fresh notice period. Emits `withdrawal_cancelled`.
+    pub fn cancel_withdrawal_request(e: Env, identity: Address) -> IdentityBond {
+        identity.require_auth();
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        if !bond.is_rolling {
+            panic_with_error!(e, BondError::NotRollingBond);
+        }
+        if bond.withdrawal_requested_at == 0 {
+            panic_with_error!(e, BondError::NoPendingWithdrawal);
+        }
+        bond.withdrawal_requested_at = 0;
+        bond_store::save(&e, Some(&before), &bond, "cancel_withdrawal");
+        events::publish(
+            &e,
+            (Symbol::new(&e, "withdrawal_cancelled"),),
+            (bond.identity.clone(), e.ledger().timestamp()),
+        );
+        bond
+    }
+
+    /// Convert an active fixed bond into a rolling bond with the given notice period. The bond
+    /// keeps its `bond_start` and duration, so the current lock-up is not shortened.
+    /// Emits `converted_to_rolling` with (identity, notice_period_duration).
+    ///
+    /// # Panics
+    /// - `BondError::AlreadyRollingBond` if the bond is already rolling
+    /// - `BondError::InvalidNoticePeriod` if the notice period is 0 or longer than the bond duration
+    pub fn convert_to_rolling(
+        e: Env,
+        identity: Address,
+        notice_period_duration: u64,
+    ) -> IdentityBond {
+        identity.require_auth();
+        pause::require_not_paused(&e);
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        if bond.is_rolling {
+            panic_with_error!(e, BondError::AlreadyRollingBond);
+        }
+        rolling_bond::validate_notice_period(&e, notice_period_duration, bond.bond_duration);
+        bond.is_rolling = true;
+        bond.notice_period_duration = notice_period_duration;
+        bond.withdrawal_requested_at = 0;
+        bond_store::save(&e, Some(&before), &bond, "convert_to_rolling");
+        events::publish(
+            &e,
+            (Symbol::new(&e, "converted_to_rolling"),),
+            (bond.identity.clone(), notice_period_duration),
+        );
+        bond
+    }
+
+    /// Schedule a rolling bond to become fixed at the end of its current period, so the
+    /// rolling commitment is not broken mid-cycle. The conversion is applied by the first
+    /// `renew_if_rolling` or `withdraw` at or after the period end, which emits
+    /// `converted_to_fixed`. Emits `fixed_conversion_scheduled` with (identity, effective_at).
+    /// Returns the bond, which stays rolling until then.
+    ///
+    /// # Panics
+    /// - `BondError::NotRollingBond` if the bond is not rolling
+    /// - `BondError::WithdrawalAlreadyRequested` if the bond has a pending withdrawal request
+    /// - `BondError::FixedConversionPending` if a conversion is already scheduled
+    pub fn convert_to_fixed(e: Env, identity: Address) -> IdentityBond {
+        identity.require_auth();
+        pause::require_not_paused(&e);
+        let bond = bond_store::get(&e, &identity);
+        bond_store::require_bond_active(&e, &bond);
+        if !bond.is_rolling {
+            panic_with_error!(e, BondError::NotRollingBond);
+        }
+        if bond.withdrawal_requested_at != 0 {
+            panic_with_error!(e, BondError::WithdrawalAlreadyRequested);
+        }
+        if rolling_bond::is_fixed_conversion_pending(&e, &identity) {
+            panic_with_error!(e, BondError::FixedConversionPending);
+        }
+        rolling_bond::schedule_fixed_conversion(&e, &identity);
+        let effective_at = bond.bond_start.saturating_add(bond.bond_duration);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "fixed_conversion_scheduled"),),
+            (identity, effective_at),
+        );
+        bond
+    }
+
+    /// Set what `renew_if_rolling` does with a rolling bond whose withdrawal matured but was not
+    /// claimed within `reclaim_window` seconds of maturity (admin only). `Hold` (the default)
+    /// leaves it waiting; `Reabsorb` cancels the request and renews the bond, emitting
+    /// `withdrawal_reabsorbed` with (identity, amount, withdrawal_requested_at); `Claimable`
+    /// moves the withdrawable amount to `get_unclaimed_withdrawal`, emitting
+    /// `withdrawal_unclaimed` with (identity, amount).
+    pub fn set_unclaimed_policy(
+        e: Env,
+        admin: Address,
+        policy: UnclaimedPolicy,
+        reclaim_window: u64,
+    ) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        rolling_bond::set_unclaimed_policy(&e, policy, reclaim_window);
+    }
+
+    /// Returns the unclaimed withdrawal policy and reclaim window (`Hold`, 0 if never set).
+    pub fn get_unclaimed_policy(e: Env) -> (UnclaimedPolicy, u64) {
+        rolling_bond::get_unclaimed_policy(&e)
+    }
+
+    /// Returns the identity's unclaimed withdrawal moved out of its bond (0 if none).
+    pub fn get_unclaimed_withdrawal(e: Env, identity: Address) -> i128 {
+        rolling_bond::get_unclaimed(&e, &identity)
+    }
+
+    /// Pay out the identity's unclaimed withdrawal and issue its withdrawal receipt. Emits
+    /// `unclaimed_withdrawal_claimed` with (identity, amount).
+    ///
+    /// # Panics
+    /// - `BondError::NothingToClaim` if there is none
+    pub fn claim_unclaimed_withdrawal(e: Env, identity: Address) -> i128 {
+        identity.require_auth();
+        let amount = rolling_bond::take_unclaimed(&e, &identity);
+        token_transfer::transfer_out(&e, &identity, amount);
+        receipts::issue(
+            &e,
+            &identity,
+            amount,
+            &receipts::no_charge(&e),
+            "claim_unclaimed",
+            &identity,
+        );
+        events::publish(
+            &e,
+            (Symbol::new(&e, "unclaimed_withdrawal_claimed"),),
+            (identity, amount),
+        );
+        amount
+    }
+
+    /// Move what the bond could withdraw (unslashed, less pending slashes, pledges and
+    /// collateral floor) into the identity's unclaimed balance. As with `withdraw`, the bond
+    /// closes once it is empty.
+    fn move_unclaimed_withdrawal(e: &Env, mut bond: IdentityBond) -> IdentityBond {
+        let before = bond.clone();
+        let now = e.ledger().timestamp();
+        let amount = bond
+            .bonded_amount
+            .saturating_sub(bond.slashed_amount)
+            .saturating_sub(slashing::pending_amount(e, &bond.identity))
+            .saturating_sub(pledges::encumbered(e, &bond.identity, now))
+            .saturating_sub(collateral::locked(e, &bond));
+        if amount <= 0 {
+            return bond;
+        }
+        bond.bonded_amount -= amount;
+        if bond.bonded_amount == 0 {
+            bond.active = false;
+        }
+        tiered_bond::update_tier(e, &bond.identity, before.bonded_amount, bond.bonded_amount);
+        bond_store::save(e, Some(&before), &bond, "unclaimed_withdrawal");
+        rolling_bond::add_unclaimed(e, &bond.identity, amount);
+        events::publish(
+            e,
+            (Symbol::new(e, "withdrawal_unclaimed"),),
+            (bond.identity.clone(), amount),
+        );
+        bond
+    }
+
+    /// Whether the identity's rolling bond is scheduled to become fixed.
+    pub fn is_fixed_conversion_pending(e: Env, identity: Address) -> bool {
+        rolling_bond::is_fixed_conversion_pending(&e, &identity)
+    }
+
+    /// If bond is rolling and period has ended, renew (new period start = now). Emits renewal event.
+    /// Inactive, fully slashed or exiting (withdrawal requested) bonds are left untouched and no
+    /// event is emitted. A bond with a scheduled fixed conversion is converted instead of renewed.
+    /// A matured withdrawal left unclaimed past the reclaim window is first handled by the
+    /// unclaimed policy (see `set_unclaimed_policy`).
+    pub fn renew_if_rolling(e: Env, identity: Address) -> IdentityBond {
+        pause::require_not_paused(&e);
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        let now = e.ledger().timestamp();
+        if bond.active && rolling_bond::settle_fixed_conversion(&e, &mut bond, now) {
+            bond_store::save(&e, Some(&before), &bond, "convert_to_fixed");
+            return bond;
+        }
+        let (policy, reclaim_window) = rolling_bond::get_unclaimed_policy(&e);
+        if policy != UnclaimedPolicy::Hold
+            && rolling_bond::is_reclaim_window_lapsed(&bond, now, reclaim_window)
+        {
+            if policy == UnclaimedPolicy::Claimable {
+                return Self::move_unclaimed_withdrawal(&e, bond);
+            }
+            events::publish(
+                &e,
+                (Symbol::new(&e, "withdrawal_reabsorbed"),),
+                (
+                    identity.clone(),
+                    bond.bonded_amount.saturating_sub(bond.slashed_amount),
+                    bond.withdrawal_requested_at,
+                ),
+            );
+            bond.withdrawal_requested_at = 0;
+            if !rolling_bond::is_renewable(&bond, now) {
+                bond_store::save(&e, Some(&before), &bond, "reabsorb_withdrawal");
+                return bond;
+            }
+        } else if !rolling_bond::is_renewable(&bond, now) {
+            return bond;
+        }
+        rolling_bond::apply_renewal(&mut bond, now);
+        bond_store::save(&e, Some(&before), &bond, "renew_if_rolling");
+        events::publish(
+            &e,
+            (Symbol::new(&e, "bond_renewed"),),
+            (bond.identity.clone(), bond.bond_start, bond.bond_duration),
+        );
+        bond
+    }
+
+    /// Renew the identity's rolling bond at its period end with new terms (owner auth). Starts a
+    /// new period as `renew_if_rolling` does, but with `new_duration` and `new_notice_period`.
+    /// Emits `bond_renewed_with_new_terms` with (identity, bond_start, old_duration,
+    /// old_notice_period, new_duration, new_notice_period).
+    ///
+    /// # Panics
+    /// - `BondError::NotRollingBond` if the bond is not rolling
+    /// - `BondError::BondNotActive` if the bond is no longer active
+    /// - `BondError::WithdrawalAlreadyRequested` if the bond is exiting
+    /// - `BondError::FixedConversionPending` if the bond is scheduled to become fixed
+    /// - `BondError::LockupNotEnded` if the current period has not ended
+    /// - `BondError::InvalidNoticePeriod` if the notice period is 0 or longer than the duration
+    pub fn renew_with_new_terms(
+        e: Env,
+        identity: Address,
+        new_duration: u64,
+        new_notice_period: u64,
+    ) -> IdentityBond {
+        identity.require_auth();
+        pause::require_not_paused(&e);
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        if !bond.is_rolling {
+            panic_with_error!(e, BondError::NotRollingBond);
+        }
+        bond_store::require_bond_active(&e, &bond);
+        if bond.withdrawal_requested_at != 0 {
+            panic_with_error!(e, BondError::WithdrawalAlreadyRequested);
+        }
+        if rolling_bond::is_fixed_conversion_pending(&e, &identity) {
+            panic_with_error!(e, BondError::FixedConversionPending);
+        }
+        let now = e.ledger().timestamp();
+        if !rolling_bond::is_period_ended(now, bond.bond_start, bond.bond_duration) {
+            panic_with_error!(e, BondError::LockupNotEnded);
+        }
+        rolling_bond::validate_notice_period(&e, new_notice_period, new_duration);
+        now.checked_add(new_duration)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+
+        rolling_bond::apply_renewal(&mut bond, now);
+        bond.bond_duration = new_duration;
+        bond.notice_period_duration = new_notice_period;
+        bond_store::save(&e, Some(&before), &bond, "renew_with_new_terms");
+        events::publish(
+            &e,
+            (Symbol::new(&e, "bond_renewed_with_new_terms"),),
+            (
+                identity,
+                bond.bond_start,
+                before.bond_duration,
+                before.notice_period_duration,
+                new_duration,
+                new_notice_period,
+            ),
+        );
+        bond
+    }
+
+    /// Set the tier thresholds. Admin only. Fails with `BondError::InvalidConfig` unless the
+    /// minimums are non-negative and strictly ascending. Tiers are computed on read, so existing
+    /// bonds pick up new thresholds immediately.
+    pub fn set_tier_config(e: Env, admin: Address, config: TierConfig) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        tiered_bond::set_tier_config(&e, &config);
+    }
+
+    /// Dry run of `set_tier_config`: runs the same checks and returns the thresholds that would
+    /// be in force, without writing storage or emitting `tier_thresholds_updated`.
+    pub fn preview_tier_config(e: Env, admin: Address, config: TierConfig) -> TierConfig {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        tiered_bond::validate_tier_config(&e, &config);
+        config
+    }
+
+    /// Returns the tier thresholds in force (defaults if never configured).
+    pub fn get_tier_config(e: Env) -> TierConfig {
+        tiered_bond::get_tier_config(&e)
+    }
+
+    /// Set the downgrade hysteresis margin in bps (admin only, max 5000). A recorded tier is
+    /// kept until the bonded amount falls below `threshold * (1 - margin)`; upgrades still use
+    /// the nominal thresholds. Fails with `BondError::InvalidConfig` above the maximum.
+    pub fn set_tier_hysteresis(e: Env, admin: Address, margin_bps: u32) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        tiered_bond::set_hysteresis_bps(&e, margin_bps);
+    }
+
+    /// Get the downgrade hysteresis margin in bps (0 = none).
+    pub fn get_tier_hysteresis(e: Env) -> u32 {
+        tiered_bond::get_hysteresis_bps(&e)
+    }
+
+    /// Set the minimum time (seconds) a bond must continuously meet a tier's minimum before
+    /// `get_effective_tier` grants it (admin only, 0 = none, the default).
+    pub fn set_tier_qualification_period(e: Env, admin: Address, period: u64) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        tiered_bond::set_qualification_period(&e, period);
+    }
+
+    /// Get the tier qualification period in seconds (0 = none).
+    pub fn get_tier_qualification_period(e: Env) -> u64 {
+        tiered_bond::get_qualification_period(&e)
+    }
+
+    /// Returns the highest tier whose minimum the identity's unslashed bond has met for at
+    /// least the qualification period, capped at `get_tier`. Equal to `get_tier` when no
+    /// period is set. Verification should rely on this rather than the instantaneous tier.
+    pub fn get_effective_tier(e: Env, identity: Address) -> BondTier {
+        let bond = bond_store::get(&e, &identity);
+        tiered_bond::get_effective_tier(&e, &bond)
+    }
+
+    /// Set the Silver, Gold and Platinum minimums, keeping the configured Bronze and Diamond
+    /// minimums. Admin only; same validation and event as `set_tier_config`.
+    pub fn set_tier_thresholds(
+        e: Env,
+        admin: Address,
+        silver_min: i128,
+        gold_min: i128,
+        platinum_min: i128,
+    ) {
+        let current = tiered_bond::get_tier_config(&e);
+        let config = TierConfig {
+            bronze_min: current.bronze_min,
+            silver_min,
+            gold_min,
+            platinum_min,
+            diamond_min: current.diamond_min,
+        };
+        Self::set_tier_config(e, admin, config);
+    }
+
+    /// Dry run of `set_tier_thresholds`: returns the full merged `TierConfig` (with the current
+    /// Bronze and Diamond minimums) that the call would store, without writing storage.
+    pub fn preview_tier_thresholds(
+        e: Env,
+        admin: Address,
+        silver_min: i128,
+        gold_min: i128,
+        platinum_min: i128,
+    ) -> TierConfig {
+        let current = tiered_bond::get_tier_config(&e);
+        let config = TierConfig {
+            bronze_min: current.bronze_min,
+            silver_min,
+            gold_min,
+            platinum_min,
+            diamond_min: current.diamond_min,
+        };
+        Self::preview_tier_config(e, admin, config)
+    }
+
+    /// Returns the (silver_min, gold_min, platinum_min) thresholds in force.
+    pub fn get_tier_thresholds(e: Env) -> (i128, i128, i128) {
+        let config = tiered_bond::get_tier_config(&e);
+        (config.silver_min, config.gold_min, config.platinum_min)
+    }
+
+    /// Get current tier for the bond's bonded amount.
+    pub fn get_tier(e: Env, identity: Address) -> BondTier {
+        let bond = bond_store::get(&e, &identity);
+        tiered_bond::get_identity_tier(&e, &identity, bond.bonded_amount)
+    }
+
+    /// Returns up to `limit` (max 50) of the identity's recorded tier changes, oldest first,
+    /// starting at sequence number `start`. Only the latest 50 changes are kept.
+    pub fn get_tier_history(
+        e: Env,
+        identity: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<TierChangeRecord> {
+        tiered_bond::get_tier_history(&e, &identity, start, limit)
+    }
+
+    /// Returns when the identity attained its current tier: the latest tier change, or the
+    /// bond start if the tier has not changed since.
+    pub fn get_tier_since(e: Env, identity: Address) -> u64 {
+        let bond = bond_store::get(&e, &identity);
+        tiered_bond::latest_tier_change(&e, &identity).map_or(bond.bond_start, |c| c.timestamp)
+    }
+
+    /// Returns the tier a bond of `amount` would have under the thresholds in force.
+    pub fn preview_tier(e: Env, amount: i128) -> BondTier {
+        tiered_bond::get_tier(&e, amount)
+    }
+
+    /// Returns the additional bonded amount the identity needs to move up one tier
+    /// (0 if already Diamond).
+    pub fn amount_to_next_tier(e: Env, identity: Address) -> i128 {
+        let bond = bond_store::get(&e, &identity);
+        let tier = tiered_bond::get_identity_tier(&e, &identity, bond.bonded_amount);
+        tiered_bond::next_tier_min(tier, &tiered_bond::get_tier_config(&e))
+            .map_or(0, |min| min.saturating_sub(bond.bonded_amount))
+    }
+
+    /// Returns true if the identity holds a bond at or above `min_tier`. Always public, even
+    /// in privacy mode, and reveals nothing beyond the yes/no answer.
+    pub fn attest_min_tier(e: Env, identity: Address, min_tier: BondTier) -> bool {
+        match bond_store::load(&e, &identity) {
+            Some(bond) => {
+                let tier = tiered_bond::get_identity_tier(&e, &identity, bond.bonded_amount);
+                tiered_bond::tier_rank(&tier) >= tiered_bond::tier_rank(&min_tier)
+            }
+            None => false,
+        }
+    }
+
+    /// Slash a portion of the bond (slash admin only). Reduces the bond's value as a penalty.
+    /// Increases slashed_amount up to the bonded_amount (over-slash prevention) and writes an
+    /// immutable `SlashRecord` with the requested and applied amounts and the reason code.
+    /// With a dispute window configured the slash is pending instead: the amount is locked in
+    /// the bond and moves into slashed_amount once the slash is final.
+    ///
+    /// # Arguments
+    /// * `admin` - Address claiming the slash admin role
+    /// * `identity` - Identity whose bond is slashed
+    /// * `amount` - Amount to slash (i128). Will be capped at bonded_amount.
+    /// * `reason` - Reason code stored in the record
+    /// * `incident_at` - Time of the incident (0 if unknown), stored in the record. Lets a bond
+    ///   in its warm-up window be slashed for an incident after the bond was created.
+    ///
+    /// # Returns
+    /// Updated IdentityBond with increased slashed_amount
+    ///
+    /// # Panics
+    /// - `BondError::NotAdmin` if caller is not the slash admin
+    /// - `BondError::NoBond` if no bond exists
+    /// - `BondError::BondInWarmup` if the bond is in its warm-up window and `incident_at` is not
+    ///   after its creation
+    /// - If the admin has not authorized the call
+    ///
+    /// # Events
+    /// Emits `bond_slashed` with (identity, slash_amount, total_slashed_amount) (for a pending
+    /// slash, when it becomes final) and `slash_recorded` with (record_id, reason)
+    pub fn slash(
+        e: Env,
+        admin: Address,
+        identity: Address,
+        amount: i128,
+        reason: Symbol,
+        incident_at: u64,
+    ) -> IdentityBond {
+        admin.require_auth();
+        slashing::slash_bond_with_reason(&e, &admin, &identity, amount, &reason, incident_at)
+    }
+
+    /// Pledge part of the bond to a service agreement (owner auth). Until `ends_at` the amount
+    /// is encumbered (not withdrawable) and `counterparty` may slash it via `slash_pledge`;
+    /// afterwards it is released automatically. Emits `bond_pledged` with
+    /// (identity, agreement_id, counterparty, amount, ends_at).
+    ///
+    /// # Panics
+    /// - `BondError::InvalidAmount` if `amount` is not positive or `ends_at` is not in the future
+    /// - `BondError::InsufficientBalance` if the bond's unslashed, unpledged balance is too small
+    /// - `PledgeError::AlreadyExists` if the agreement id is already used
+    /// - `PledgeError::TooMany` if the identity has 10 live pledges
+    pub fn pledge(
+        e: Env,
+        identity: Address,
+        agreement_id: BytesN<32>,
+        counterparty: Address,
+        amount: i128,
+        ends_at: u64,
+    ) -> Pledge {
+        identity.require_auth();
+        pause::require_not_paused(&e);
+        let now = e.ledger().timestamp();
+        if amount <= 0 || ends_at <= now {
+            panic_with_error!(e, BondError::InvalidAmount);
+        }
+        let bond = bond_store::get(&e, &identity);
+        bond_store::require_bond_active(&e, &bond);
+        let free = bond
+            .bonded_amount
+            .saturating_sub(bond.slashed_amount)
+            .saturating_sub(slashing::pending_amount(&e, &identity))
+            .saturating_sub(pledges::encumbered(&e, &identity, now));
+        if amount > free {
+            panic_with_error!(e, BondError::InsufficientBalance);
+        }
+        let pledge = Pledge {
+            agreement_id: agreement_id.clone(),
+            identity: identity.clone(),
+            counterparty: counterparty.clone(),
+            amount,
+            slashed: 0,
+            ends_at,
+        };
+        pledges::add(&e, &pledge, now);
+        events::publish_or_compact(
+            &e,
+            (Symbol::new(&e, "bond_pledged"),),
+            (
+                identity,
+                agreement_id.clone(),
+                counterparty,
+                amount,
+                ends_at,
+            ),
+            (agreement_id, amount),
+        );
+        pledge
+    }
+
+    /// Slash a pledged amount (counterparty auth), before the agreement ends and up to the
+    /// pledge's unslashed remainder. Writes an audit record with reason `pledge` and the
+    /// counterparty as approver. Emits `bond_slashed` and `pledge_slashed` with
+    /// (agreement_id, counterparty, amount, evidence_hash).
+    ///
+    /// # Panics
+    /// - `PledgeError::NotFound` / `PledgeError::NotCounterparty`
+    /// - `PledgeError::Ended` at or after `ends_at`
+    /// - `BondError::InvalidAmount` if `amount` is not positive
+    /// - `PledgeError::ExceedsPledge` if `amount` exceeds what remains of the pledge
+    /// - `BondError::SlashExceedsBond` if the bond no longer holds that much unslashed
+    pub fn slash_pledge(
+        e: Env,
+        counterparty: Address,
+        agreement_id: BytesN<32>,
+        amount: i128,
+        evidence_hash: BytesN<32>,
+    ) -> IdentityBond {
+        counterparty.require_auth();
+        let mut pledge = pledges::get(&e, &agreement_id);
+        if pledge.counterparty != counterparty {
+            panic_with_error!(e, PledgeError::NotCounterparty);
+        }
+        if !pledges::is_live(&pledge, e.ledger().timestamp()) {
+            panic_with_error!(e, PledgeError::Ended);
+        }
+        if amount <= 0 {
+            panic_with_error!(e, BondError::InvalidAmount);
+        }
+        if amount > pledge.amount - pledge.slashed {
+            panic_with_error!(e, PledgeError::ExceedsPledge);
+        }
+
+        let mut bond = bond_store::get(&e, &pledge.identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        let new_slashed = bond
+            .slashed_amount
+            .checked_add(amount)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+        if new_slashed > bond.bonded_amount - slashing::pending_amount(&e, &pledge.identity) {
+            panic_with_error!(e, BondError::SlashExceedsBond);
+        }
+        bond.slashed_amount = new_slashed;
+        pledge.slashed += amount;
+        pledges::save(&e, &pledge);
+        bond_store::save(&e, Some(&before), &bond, "slash_pledge");
+        slashing::record_slash(
+            &e,
+            &counterparty,
+            &bond,
+            amount,
+            amount,
+            &Symbol::new(&e, "pledge"),
+            0,
+        );
+
+        slashing::emit_slashing_event(&e, &bond.identity, amount, bond.slashed_amount);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "pledge_slashed"),),
+            (agreement_id, counterparty, amount, evidence_hash),
+        );
+        bond
+    }
+
+    /// Get the pledge for an agreement id. Fails with `PledgeError::NotFound`.
+    pub fn get_pledge(e: Env, agreement_id: BytesN<32>) -> Pledge {
+        pledges::get(&e, &agreement_id)
+    }
+
+    /// Amount of the identity's bond currently encumbered by live pledges.
+    pub fn get_encumbered_amount(e: Env, identity: Address) -> i128 {
+        pledges::encumbered(&e, &identity, e.ledger().timestamp())
+    }
+
+    /// Reverse all or part of a slash (admin only), e.g. after a mistaken slash or a dispute
+    /// resolved in the bondholder's favour. Reduces `slashed_amount` by `amount`, making it
+    /// withdrawable again.
+    ///
+    /// # Panics
+    /// - `BondError::NotAdmin` if caller is not the contract admin
+    /// - `BondError::InvalidAmount` if `amount` is not positive
+    /// - `BondError::RecoveryExceedsSlashed` if `amount` exceeds the bond's slashed amount
+    ///
+    /// # Events
+    /// Emits `slash_recovered` with (identity, amount, new_slashed_amount)
+    pub fn recover_slash(e: Env, admin: Address, identity: Address, amount: i128) -> IdentityBond {
+        admin.require_auth();
+        slashing::unslash_bond(&e, &admin, &identity, amount)
+    }
+
+    /// Amount of the identity's bond that `recover_slash` could still restore (its slashed amount).
+    pub fn get_recoverable_slash_amount(e: Env, identity: Address) -> i128 {
+        slashing::get_slashed_amount(&e, &identity)
+    }
+
+    /// Slash several bonds in one atomic call (slash admin only). Each `(identity, amount)` pair is
+    /// slashed exactly as by `slash`, capped at the bonded amount and emitting `bond_slashed`.
+    /// Identities without a bond are skipped with a `slash_skipped` event carrying the identity.
+    /// Any other failure (e.g. an inactive bond) reverts the whole batch. An identity listed
+    /// twice fails the batch with `BondError::DuplicateTarget` before anything is slashed.
+    /// Each slash writes an audit record with reason `batch`.
+    ///
+    /// # Returns
+    /// The updated bonds, in input order, excluding skipped identities
+    pub fn batch_slash(e: Env, admin: Address, slashes: Vec<(Address, i128)>) -> Vec<IdentityBond> {
+        admin.require_auth();
+        roles::validate_slash_admin(&e, &admin);
+        let mut seen = Map::new(&e);
+        for (identity, _) in slashes.iter() {
+            if seen.contains_key(identity.clone()) {
+                panic_with_error!(e, BondError::DuplicateTarget);
+            }
+            seen.set(identity, ());
+        }
+        let mut updated = Vec::new(&e);
+        for (identity, amount) in slashes.iter() {
+            if bond_store::load(&e, &identity).is_none() {
+                events::publish(&e, (Symbol::new(&e, "slash_skipped"),), identity);
+                continue;
+            }
+            updated.push_back(slashing::slash_bond_with_reason(
+                &e,
+                &admin,
+                &identity,
+                amount,
+                &Symbol::new(&e, "batch"),
+                0,
+            ));
+        }
+        updated
+    }
+
+    /// Same as `slash` with no incident time. Kept for existing integrations.
+    ///
+    /// # Panics
+    /// Same as `slash`
+    ///
+    /// # Events
+    /// Emits `bond_slashed` and `slash_recorded` with (record_id, reason)
+    pub fn slash_with_reason(
+        e: Env,
+        admin: Address,
+        identity: Address,
+        amount: i128,
+        reason: Symbol,
+    ) -> IdentityBond {
+        admin.require_auth();
+        slashing::slash_bond_with_reason(&e, &admin, &identity, amount, &reason, 0)
+    }
+
+    /// Get a slash audit record by id.
+    ///
+    /// # Panics
+    /// - `BondError::SlashRecordNotFound` if no record has that id
+    pub fn get_slash_record(e: Env, id: u64) -> SlashRecord {
+        slashing::get_slash_record(&e, id)
+    }
+
+    /// Set the slashing config (admin only). A non-zero `slash_dispute_window_secs` makes new
+    /// `slash_with_reason` records pending for that long so the identity can dispute them.
+    pub fn set_slash_config(e: Env, admin: Address, config: SlashConfig) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        slashing::set_slash_config(&e, &config);
+    }
+
+    /// Get the slashing config (zero dispute window if never set).
+    pub fn get_slash_config(e: Env) -> SlashConfig {
+        slashing::get_slash_config(&e)
+    }
+
+    /// Set the slash warm-up window in seconds (admin only; 0 disables it). Admin slashes of a
+    /// bond created less than `seconds` ago fail with `BondError::BondInWarmup` unless they
+    /// give an incident time after the bond's creation. Pledge slashes are not affected.
+    pub fn set_slash_warmup(e: Env, admin: Address, seconds: u64) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        slashing::set_warmup(&e, seconds);
+    }
+
+    /// Get the slash warm-up window in seconds (0 if never set).
+    pub fn get_slash_warmup(e: Env) -> u64 {
+        slashing::get_warmup(&e)
+    }
+
+    /// Dispute a pending slash. Only the slashed identity may call, before the dispute window
+    /// ends. A disputed slash stays pending until `adjudicate_slash`.
+    ///
+    /// # Panics
+    /// - `BondError::SlashRecordNotFound` if no record has that id
+    /// - `SlashDisputeError::NotPending` if the slash is already final
+    /// - `SlashDisputeError::AlreadyDisputed` if it is already disputed
+    /// - `SlashDisputeError::WindowClosed` at or after `dispute_ends_at`
+    ///
+    /// # Events
+    /// Emits `slash_disputed` with (slash_id, identity)
+    pub fn dispute_slash(e: Env, slash_id: u64) -> SlashRecord {
+        slashing::dispute_slash(&e, slash_id)
+    }
+
+    /// Decide a disputed slash (admin only). An upheld slash becomes final; an overturned one
+    /// is finalised and its amount restored to the bond as by `recover_slash`. If the bond has
+    /// been closed since the slash, the amount is paid out to the identity instead.
+    ///
+    /// # Panics
+    /// - `BondError::SlashRecordNotFound` if no record has that id
+    /// - `SlashDisputeError::NotPending` if the slash is already final
+    /// - `SlashDisputeError::NotDisputed` if it was never disputed
+    ///
+    /// # Events
+    /// Emits `slash_adjudicated` with (slash_id, upheld), plus `slash_recovered` if overturned
+    pub fn adjudicate_slash(e: Env, admin: Address, slash_id: u64, upheld: bool) -> SlashRecord {
+        admin.require_auth();
+        slashing::adjudicate_slash(&e, &admin, slash_id, upheld)
+    }
+
+    /// Finalise an undisputed slash once its dispute window has ended. Anyone may call.
+    ///
+    /// # Panics
+    /// - `BondError::SlashRecordNotFound` if no record has that id
+    /// - `SlashDisputeError::NotPending` if the slash is already final
+    /// - `SlashDisputeError::UnderDispute` if it is disputed (use `adjudicate_slash`)
+    /// - `SlashDisputeError::WindowOpen` before `dispute_ends_at`
+    ///
+    /// # Events
+    /// Emits `slash_finalized` with slash_id
+    pub fn finalize_slash(e: Env, slash_id: u64) -> SlashRecord {
+        slashing::finalize_slash(&e, slash_id)
+    }
+
+    /// Get the id of the most recent slash audit record (0 if none; ids start at 1).
+    pub fn get_latest_slash_record_id(e: Env) -> u64 {
+        slashing::get_latest_slash_record_id(&e)
+    }
+
+    /// List up to `limit` of the identity's slash audit records starting at index `start`,
+    /// oldest first.
+    pub fn get_slash_records_for_identity(
+        e: Env,
+        identity: Address,
+        start: u32,
+        limit: u32,
+    ) -> Vec<SlashRecord> {
+        slashing::get_records_for_identity(&e, &identity, start, limit)
+    }
+
+    /// Summarise the identity's slashes with `from_ts <= timestamp <= to_ts`: how many stand,
+    /// their total and audit record ids, and how many were overturned on dispute. Covers every
+    /// slash path.
+    pub fn get_slash_summary(e: Env, identity: Address, from_ts: u64, to_ts: u64) -> SlashSummary {
+        slashing::slash_summary(&e, &identity, from_ts, to_ts)
+    }
+
+    /// True if no slash of the identity's bond stands within `from_ts..=to_ts`. Overturned
+    /// slashes do not count.
+    pub fn attest_clean_record(e: Env, identity: Address, from_ts: u64, to_ts: u64) -> bool {
+        slashing::slash_summary(&e, &identity, from_ts, to_ts).count == 0
+    }
+
+    /// Nominate `delegate` to call `top_up_as` and `extend_duration_as` for the identity
+    /// (identity auth), replacing any previous delegate. The delegate cannot withdraw or
+    /// otherwise act for the identity. Emits `delegate_set` with (identity, delegate).
+    ///
+    /// # Panics
+    /// - `DelegateError::SelfDelegation` if `delegate` is the identity
+    pub fn set_delegate(e: Env, identity: Address, delegate: Address) {
+        identity.require_auth();
+        bond_delegation::set_delegate(&e, &identity, &delegate);
+        events::publish(&e, (Symbol::new(&e, "delegate_set"),), (identity, delegate));
+    }
+
+    /// Remove the identity's delegate (identity auth). Emits `delegate_revoked` with
+    /// (identity, delegate) if there was one.
+    pub fn revoke_delegate(e: Env, identity: Address) {
+        identity.require_auth();
+        if let Some(delegate) = bond_delegation::revoke_delegate(&e, &identity) {
+            events::publish(
+                &e,
+                (Symbol::new(&e, "delegate_revoked"),),
+                (identity, delegate),
+            );
+        }
+    }
+
+    /// Returns the identity's delegate, if any.
+    pub fn get_delegate(e: Env, identity: Address) -> Option<Address> {
+        bond_delegation::get_delegate(&e, &identity)
+    }
+
+    pub fn is_contract_identity(e: Env, identity: Address) -> bool {
+        contract_identity::is_contract_identity(&e, &identity)
+    }
+
+    pub fn set_controller(e: Env, identity: Address, controller: Address) {
+        identity.require_auth();
+        contract_identity::set_controller(&e, &identity, &controller);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "controller_set"),),
+            (identity, controller),
+        );
+    }
+
+    pub fn remove_controller(e: Env, identity: Address) {
+        identity.require_auth();
+        if let Some(controller) = contract_identity::remove_controller(&e, &identity) {
+            events::publish(
+                &e,
+                (Symbol::new(&e, "controller_removed"),),
+                (identity, controller),
+            );
+        }
+    }
+
+    pub fn get_controller(e: Env, identity: Address) -> Option<Address> {
+        contract_identity::get_controller(&e, &identity)
+    }
+
+    /// Top up the bond with additional amount (checks for overflow).
+    /// The deposit premium (if configured) is routed to the insurance pool; the net is bonded.
+    /// Emits `bond_topped_up` with (identity, net_amount, new_bonded_amount).
+    ///
+    /// Amounts above the large deposit threshold are transferred in but held for review
+    /// (`deposit_held` event) and the bond is returned unchanged; see `release_deposit`.
+    pub fn top_up(e: Env, identity: Address, amount: i128) -> IdentityBond {
+        identity.require_auth();
+        Self::top_up_impl(e, identity.clone(), identity, amount)
+    }
+
+    /// `top_up` called by the identity, its delegate or its controller (`caller` auth). The
+    /// caller pays the amount; a held deposit that is rejected is claimable by the identity.
+    ///
+    /// # Panics
+    /// - `DelegateError::NotDelegate` if `caller` is not the identity, its delegate or its
+    ///   controller
+    /// - Otherwise as `top_up`
+    pub fn top_up_as(e: Env, caller: Address, identity: Address, amount: i128) -> IdentityBond {
+        bond_delegation::require_identity_or_delegate(&e, &caller, &identity);
+        Self::top_up_impl(e, caller, identity, amount)
+    }
+
+    fn top_up_impl(e: Env, payer: Address, identity: Address, amount: i128) -> IdentityBond {
+        pause::require_not_paused(&e);
+        validation::validate_amount_conforms(&e, amount);
+        // Validate the net top-up amount meets minimum requirements
+        let (premium, net) = insurance::split_deposit(&e, amount);
+        if net < validation::MIN_BOND_AMOUNT {
+            panic_with_error!(e, BondError::InvalidAmount);
+        }
 
-        e.events().publish(
-            (
-                Symbol::new(&e, "attestation_revoked"),
-                attestation.identity.clone(),
-            ),
-            (attestation_id, attester),
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        if deposit_hold::requires_review(&e, amount) {
+            token_transfer::transfer_in(&e, &payer, amount);
+            deposit_hold::hold(&e, &identity, amount, premium, net);
+            return bond;
+        }
+        Self::apply_top_up(&e, &mut bond, net);
+
+        token_transfer::transfer_in(&e, &payer, amount);
+        bond_store::save(&e, Some(&before), &bond, "top_up");
+        events::publish(
+            &e,
+            (Symbol::new(&e, "bond_topped_up"),),
+            (bond.identity.clone(), net, bond.bonded_amount),
         );
+        insurance::record_deposit(&e, &bond.identity, amount, premium, net);
+        bond
     }
 
-    /// Get an attestation by ID.
-    pub fn get_attestation(e: Env, attestation_id: u64) -> Attestation {
-        e.storage()
-            .instance()
-            .get(&DataKey::Attestation(attestation_id))
-            .unwrap_or_else(|| panic!("attestation not found"))
+    /// Set the gross top-up size above which deposits are held for review (admin only).
+    /// 0 disables review. Fails with `BondError::InvalidConfig` if negative.
+    pub fn set_large_deposit_threshold(e: Env, admin: Address, amount: i128) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        deposit_hold::set_threshold(&e, amount);
     }
 
-    /// Get all attestation IDs for a subject.
-    pub fn get_subject_attestations(e: Env, subject: Address) -> Vec<u64> {
-        e.storage()
-            .instance()
-            .get(&DataKey::SubjectAttestations(subject))
-            .unwrap_or(Vec::new(&e))
+    /// Get the large deposit threshold (0 = review disabled).
+    pub fn get_large_deposit_threshold(e: Env) -> i128 {
+        deposit_hold::get_threshold(&e)
     }
 
-    /// Get attestation count for a subject (identity). O(1).
-    pub fn get_subject_attestation_count(e: Env, subject: Address) -> u32 {
-        e.storage()
-            .instance()
-            .get(&DataKey::SubjectAttestationCount(subject))
-            .unwrap_or(0)
+    /// Set how long a held deposit waits for review before anyone may release it (admin only).
+    /// Fails with `BondError::InvalidConfig` if 0. Applies to deposits held afterwards.
+    pub fn set_deposit_review_window(e: Env, admin: Address, seconds: u64) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        deposit_hold::set_review_window(&e, seconds);
     }
 
-    /// Get current nonce for an identity (for replay prevention). Use this value in the next state-changing call.
-    pub fn get_nonce(e: Env, identity: Address) -> u64 {
-        nonce::get_nonce(&e, &identity)
+    /// Get the deposit review window in seconds (default 7 days).
+    pub fn get_deposit_review_window(e: Env) -> u64 {
+        deposit_hold::get_review_window(&e)
     }
 
-    /// Set attester stake (admin only). Used for weighted attestation; weight is derived from this.
-    pub fn set_attester_stake(e: Env, admin: Address, attester: Address, amount: i128) {
-        let stored_admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
-        admin.require_auth();
-        if admin != stored_admin {
-            panic!("not admin");
-        }
-        weighted_attestation::set_attester_stake(&e, &attester, amount);
+    /// Get a held deposit. Fails with `BondError::DepositNotFound` once released or rejected.
+    pub fn get_pending_deposit(e: Env, deposit_id: u64) -> PendingDeposit {
+        deposit_hold::get(&e, deposit_id)
     }
 
-    /// Set weight config: multiplier_bps (e.g. 100 = 1%), max_attestation_weight. Admin only.
-    pub fn set_weight_config(e: Env, admin: Address, multiplier_bps: u32, max_weight: u32) {
-        let stored_admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("not initialized"));
+    /// Release a held deposit into the identity's bond (admin only). Charges the premium
+    /// recorded when the deposit was held and emits `deposit_released`, `bond_topped_up` and
+    /// `tier_changed` if the tier changed.
+    ///
+    /// # Panics
+    /// - `BondError::DepositNotFound` if the deposit is not pending for this identity
+    /// - `BondError::BondNotActive` if the bond was closed or fully withdrawn meanwhile
+    pub fn release_deposit(
+        e: Env,
+        admin: Address,
+        identity: Address,
+        deposit_id: u64,
+    ) -> IdentityBond {
         admin.require_auth();
-        if admin != stored_admin {
-            panic!("not admin");
-        }
-        weighted_attestation::set_weight_config(&e, multiplier_bps, max_weight);
+        slashing::validate_admin(&e, &admin);
+        Self::release_held_deposit(&e, &identity, deposit_id)
     }
 
-    /// Get weight config (multiplier_bps, max_weight).
-    pub fn get_weight_config(e: Env) -> (u32, u32) {
-        weighted_attestation::get_weight_config(&e)
+    /// Release a held deposit the admin has not acted on once its review deadline has passed.
+    /// Callable by anyone. Fails with `BondError::DepositReviewPending` before `release_at`.
+    pub fn release_expired_deposit(e: Env, identity: Address, deposit_id: u64) -> IdentityBond {
+        let deposit = deposit_hold::get(&e, deposit_id);
+        if e.ledger().timestamp() < deposit.release_at {
+            panic_with_error!(e, BondError::DepositReviewPending);
+        }
+        Self::release_held_deposit(&e, &identity, deposit_id)
     }
 
-    /// Withdraw from bond. Checks that the bond has sufficient balance after accounting for slashed amount.
-    /// Returns the updated bond with reduced bonded_amount.
-    pub fn withdraw(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
+    /// Reject a held deposit (admin only). The full gross amount becomes claimable by the
+    /// depositor via `claim_rejected_deposits`; no premium is charged. Emits `deposit_rejected`
+    /// with (identity, deposit_id, amount).
+    pub fn reject_deposit(e: Env, admin: Address, identity: Address, deposit_id: u64) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        let deposit = deposit_hold::take(&e, &identity, deposit_id);
+        deposit_hold::add_claimable(&e, &identity, deposit.amount);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "deposit_rejected"),),
+            (identity, deposit_id, deposit.amount),
+        );
+    }
 
-        // Calculate available balance (bonded - slashed)
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
+    /// Amount of rejected deposits the identity can claim back.
+    pub fn get_claimable_deposits(e: Env, identity: Address) -> i128 {
+        deposit_hold::get_claimable(&e, &identity)
+    }
 
-        // Verify sufficient available balance for withdrawal
-        if amount > available {
-            panic!("insufficient balance for withdrawal");
-        }
+    /// Pay out the identity's rejected deposits. Fails with `BondError::NothingToClaim` if none.
+    /// Emits `rejected_deposits_claimed` with (identity, amount).
+    pub fn claim_rejected_deposits(e: Env, identity: Address) -> i128 {
+        identity.require_auth();
+        let amount = deposit_hold::take_claimable(&e, &identity);
+        token_transfer::transfer_out(&e, &identity, amount);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "rejected_deposits_claimed"),),
+            (identity, amount),
+        );
+        amount
+    }
 
-        // Perform withdrawal with overflow protection
-        bond.bonded_amount = bond
-            .bonded_amount
-            .checked_sub(amount)
-            .expect("withdrawal caused underflow");
+    /// Set the collateral floor (admin only, 0 = disabled, the default). That much of every open
+    /// bond's unslashed balance cannot be withdrawn until `close_bond`. Fails with
+    /// `BondError::InvalidConfig` if negative.
+    pub fn set_collateral_floor(e: Env, admin: Address, amount: i128) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        collateral::set_floor(&e, amount);
+    }
 
-        // Verify invariant: slashed amount should not exceed bonded amount after withdrawal
-        if bond.slashed_amount > bond.bonded_amount {
-            panic!("slashed amount exceeds bonded amount");
-        }
+    /// Get the collateral floor.
+    pub fn get_collateral_floor(e: Env) -> i128 {
+        collateral::get_floor(&e)
+    }
 
-        e.storage().instance().set(&key, &bond);
-        bond
+    /// Set how long collateral released at closure cools off before it can be claimed (admin
+    /// only, default 7 days).
+    pub fn set_collateral_cooling_period(e: Env, admin: Address, seconds: u64) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        collateral::set_cooling_period(&e, seconds);
     }
 
-    /// Withdraw before lock-up end; applies early exit penalty and transfers penalty to treasury.
-    /// Net amount to user = amount - penalty. Use when lock-up has not yet ended.
-    pub fn withdraw_early(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
+    /// Get the collateral cooling-off period in seconds.
+    pub fn get_collateral_cooling_period(e: Env) -> u64 {
+        collateral::get_cooling_period(&e)
+    }
 
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
-        if amount > available {
-            panic!("insufficient balance for withdrawal");
+    /// Parts of the identity's bond that cannot currently be withdrawn: live pledges and the
+    /// collateral floor.
+    pub fn get_encumbrances(e: Env, identity: Address) -> Encumbrances {
+        let bond = bond_store::get(&e, &identity);
+        Encumbrances {
+            pledged: pledges::encumbered(&e, &identity, e.ledger().timestamp()),
+            collateral_floor: collateral::locked(&e, &bond),
+            pending_slash: slashing::pending_amount(&e, &identity),
         }
+    }
 
-        let now = e.ledger().timestamp();
-        let end = bond.bond_start.saturating_add(bond.bond_duration);
-        if now >= end {
-            panic!("use withdraw for post lock-up");
-        }
+    /// Collateral released from the identity's closed bonds and when it can be claimed.
+    pub fn get_claimable_collateral(e: Env, identity: Address) -> ClaimableCollateral {
+        collateral::get_claimable(&e, &identity)
+    }
 
-        let (treasury, penalty_bps) = early_exit_penalty::get_config(&e);
-        let remaining = end.saturating_sub(now);
-        let penalty = early_exit_penalty::calculate_penalty(
-            amount,
-            remaining,
-            bond.bond_duration,
-            penalty_bps,
+    /// Pay out collateral released at closure once cooled off. Emits `collateral_claimed` with
+    /// (identity, amount).
+    ///
+    /// # Panics
+    /// - `BondError::NothingToClaim` if there is none
+    /// - `CollateralError::CoolingOff` before `release_at`
+    pub fn claim_collateral(e: Env, identity: Address) -> i128 {
+        identity.require_auth();
+        let amount = collateral::take_claimable(&e, &identity);
+        token_transfer::transfer_out(&e, &identity, amount);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "collateral_claimed"),),
+            (identity, amount),
         );
-        early_exit_penalty::emit_penalty_event(&e, &bond.identity, amount, penalty, &treasury);
-        // In a full implementation: transfer (amount - penalty) to user, penalty to treasury.
+        amount
+    }
 
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        bond.bonded_amount = bond
-            .bonded_amount
-            .checked_sub(amount)
-            .expect("withdrawal caused underflow");
-        if bond.slashed_amount > bond.bonded_amount {
-            panic!("slashed amount exceeds bonded amount");
-        }
-        let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+    /// Set the referral reward (bps of the referred bond's net amount, max 1000) and the
+    /// minimum bond age before it vests. Admin only. A reward of 0 records referrals without
+    /// accruing anything.
+    pub fn set_referral_config(e: Env, admin: Address, reward_bps: u32, min_age: u64) {
+        admin.require_auth();
+        slashing::validate_admin(&e, &admin);
+        referral::set_config(&e, reward_bps, min_age);
+    }
 
-        e.storage().instance().set(&key, &bond);
-        bond
+    /// Returns the referral (reward_bps, min_age).
+    pub fn get_referral_config(e: Env) -> (u32, u64) {
+        referral::get_config(&e)
     }
 
-    /// Request withdrawal (rolling bonds). Withdrawal allowed after notice period.
-    pub fn request_withdrawal(e: Env) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
-        if !bond.is_rolling {
-            panic!("not a rolling bond");
-        }
-        if bond.withdrawal_requested_at != 0 {
-            panic!("withdrawal already requested");
-        }
-        bond.withdrawal_requested_at = e.ledger().timestamp();
-        e.storage().instance().set(&key, &bond);
-        e.events().publish(
-            (Symbol::new(&e, "withdrawal_requested"),),
-            (bond.identity.clone(), bond.withdrawal_requested_at),
+    /// Returns the referral recorded for the identity's bond, if any.
+    pub fn get_referral(e: Env, identity: Address) -> Option<Referral> {
+        referral::get_referral(&e, &identity)
+    }
+
+    /// Returns the referrer's referred count and rewards; rewards whose bond has reached the
+    /// minimum age count as claimable.
+    pub fn get_referral_stats(e: Env, referrer: Address) -> ReferralStats {
+        referral::get_stats(&e, &referrer)
+    }
+
+    /// Pay out the referrer's vested rewards. Emits `referral_rewards_claimed` with
+    /// (referrer, amount).
+    ///
+    /// # Panics
+    /// - `BondError::NothingToClaim` if no reward has vested
+    pub fn claim_referral_rewards(e: Env, referrer: Address) -> i128 {
+        referrer.require_auth();
+        let amount = referral::take_claimable(&e, &referrer);
+        token_transfer::transfer_out(&e, &referrer, amount);
+        events::publish(
+            &e,
+            (Symbol::new(&e, "referral_rewards_claimed"),),
+            (referrer, amount),
         );
-        bond
+        amount
     }
 
-    /// If bond is rolling and period has ended, renew (new period start = now). Emits renewal event.
-    pub fn renew_if_rolling(e: Env) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
-        if !bond.is_rolling {
-            return bond;
-        }
-        let now = e.ledger().timestamp();
-        if !rolling_bond::is_period_ended(now, bond.bond_start, bond.bond_duration) {
-            return bond;
-        }
-        rolling_bond::apply_renewal(&mut bond, now);
-        e.storage().instance().set(&key, &bond);
-        e.events().publish(
-            (Symbol::new(&e, "bond_renewed"),),
-            (bond.identity.clone(), bond.bond_start, bond.bond_duration),
+    /// Move a held deposit into the bond.
+    fn release_held_deposit(e: &Env, identity: &Address, deposit_id: u64) -> IdentityBond {
+        let deposit = deposit_hold::take(e, identity, deposit_id);
+        let mut bond = bond_store::get(e, identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(e, &bond);
+        Self::apply_top_up(e, &mut bond, deposit.net);
+        bond_store::save(e, Some(&before), &bond, "release_deposit");
+        events::publish(
+            e,
+            (Symbol::new(e, "deposit_released"),),
+            (identity.clone(), deposit_id, deposit.net),
+        );
+        events::publish(
+            e,
+            (Symbol::new(e, "bond_topped_up"),),
+            (identity.clone(), deposit.net, bond.bonded_amount),
         );
+        insurance::record_deposit(e, identity, deposit.amount, deposit.premium, deposit.net);
         bond
     }
 
-    /// Get current tier for the bond's bonded amount.
-    pub fn get_tier(e: Env) -> BondTier {
-        let bond = Self::get_identity_state(e);
-        tiered_bond::get_tier_for_amount(bond.bonded_amount)
+    /// Extend bond duration (checks for u64 overflow on timestamps).
+    /// Emits `bond_extended` with (identity, additional_duration, new_duration).
+    pub fn extend_duration(e: Env, identity: Address, additional_duration: u64) -> IdentityBond {
+        identity.require_auth();
+        Self::extend_duration_impl(e, identity, additional_duration)
     }
 
-    /// Slash a portion of the bond (admin only). Reduces the bond's value as a penalty.
-    /// Increases slashed_amount up to the bonded_amount (over-slash prevention).
-    ///
-    /// # Arguments
-    /// * `admin` - Address claiming admin authority (must be contract admin)
-    /// * `amount` - Amount to slash (i128). Will be capped at bonded_amount.
-    ///
-    /// # Returns
-    /// Updated IdentityBond with increased slashed_amount
+    /// `extend_duration` called by the identity, its delegate or its controller (`caller` auth).
     ///
     /// # Panics
-    /// - "not admin" if caller is not the contract admin
-    /// - "no bond" if no bond exists
-    ///
-    /// # Events
-    /// Emits `bond_slashed` event with (identity, slash_amount, total_slashed_amount)
-    pub fn slash(e: Env, admin: Address, amount: i128) -> IdentityBond {
-        slashing::slash_bond(&e, &admin, amount)
+    /// - `DelegateError::NotDelegate` if `caller` is not the identity, its delegate or its
+    ///   controller
+    pub fn extend_duration_as(
+        e: Env,
+        caller: Address,
+        identity: Address,
+        additional_duration: u64,
+    ) -> IdentityBond {
+        bond_delegation::require_identity_or_delegate(&e, &caller, &identity);
+        Self::extend_duration_impl(e, identity, additional_duration)
     }
 
-    /// Top up the bond with additional amount (checks for overflow)
-    pub fn top_up(e: Env, amount: i128) -> IdentityBond {
-        // Validate the top-up amount meets minimum requirements
-        if amount < validation::MIN_BOND_AMOUNT {
-            panic!(
-                "top-up amount below minimum required: {} (minimum: {})",
-                amount,
-                validation::MIN_BOND_AMOUNT
-            );
+    fn extend_duration_impl(e: Env, identity: Address, additional_duration: u64) -> IdentityBond {
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        Self::apply_extension(&e, &mut bond, additional_duration);
+        bond_store::save(&e, Some(&before), &bond, "extend_duration");
+        events::publish(
+            &e,
+            (Symbol::new(&e, "bond_extended"),),
+            (
+                bond.identity.clone(),
+                additional_duration,
+                bond.bond_duration,
+            ),
+        );
+        bond
+    }
+
+    /// Top up the bond and extend its duration in one operation, so the bond never sits at
+    /// the new amount with the old expiry (or vice versa). Applies the same checks as
+    /// `top_up` and `extend_duration`; if either fails, neither is applied.
+    ///
+    /// # Events
+    /// Emits `bond_topped_up_and_extended` with
+    /// (identity, old_amount, new_amount, old_duration, new_duration, new_tier), plus
+    /// `tier_changed` if the tier changed.
+    pub fn top_up_and_extend(
+        e: Env,
+        identity: Address,
+        amount: i128,
+        additional_duration: u64,
+    ) -> IdentityBond {
+        identity.require_auth();
+        pause::require_not_paused(&e);
+        validation::validate_amount_conforms(&e, amount);
+        if deposit_hold::requires_review(&e, amount) {
+            panic_with_error!(e, BondError::DepositRequiresReview);
         }
-        
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
+        let (premium, net) = insurance::split_deposit(&e, amount);
+        if net < validation::MIN_BOND_AMOUNT {
+            panic_with_error!(e, BondError::InvalidAmount);
+        }
+
+        let mut bond = bond_store::get(&e, &identity);
+        let before = bond.clone();
+        bond_store::require_bond_active(&e, &bond);
+        Self::apply_top_up(&e, &mut bond, net);
+        Self::apply_extension(&e, &mut bond, additional_duration);
+
+        token_transfer::transfer_in(&e, &bond.identity, amount);
+        bond_store::save(&e, Some(&before), &bond, "top_up_and_extend");
+        insurance::record_deposit(&e, &bond.identity, amount, premium, net);
+        events::publish_or_compact(
+            &e,
+            (Symbol::new(&e, "bond_topped_up_and_extended"),),
+            (
+                bond.identity.clone(),
+                before.bonded_amount,
+                bond.bonded_amount,
+                before.bond_duration,
+                bond.bond_duration,
+                tiered_bond::get_identity_tier(&e, &bond.identity, bond.bonded_amount),
+            ),
+            (
+                bond.identity.clone(),
+                before.bonded_amount,
+                bond.bonded_amount,
+            ),
+        );
+        bond
+    }
 
-        // Calculate the new bonded amount after top-up
+    /// Add `net` to the bonded amount (overflow and bounds checked) and emit any tier change.
+    fn apply_top_up(e: &Env, bond: &mut IdentityBond, net: i128) {
+        // Calculate the new bonded amount after top-up with overflow protection
         let new_bonded_amount = bond
-        // Perform top-up with overflow protection
-        bond.bonded_amount = bond
             .bonded_amount
-            .checked_add(amount)
-            .expect("top-up caused overflow");
-            
+            .checked_add(net)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+
         // Validate the new total bonded amount is within limits
-        validation::validate_bond_amount(new_bonded_amount);
+        validation::validate_bond_amount(e, new_bonded_amount);
 
-        // Perform top-up with overflow protection
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+        tiered_bond::update_tier(e, &bond.identity, bond.bonded_amount, new_bonded_amount);
         bond.bonded_amount = new_bonded_amount;
-
-        e.storage().instance().set(&key, &bond);
-        bond
     }
 
-    /// Extend bond duration (checks for u64 overflow on timestamps)
-    pub fn extend_duration(e: Env, additional_duration: u64) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
-
+    /// Add `additional_duration` to the bond duration (checks for u64 overflow on timestamps).
+    fn apply_extension(e: &Env, bond: &mut IdentityBond, additional_duration: u64) {
         // Perform duration extension with overflow protection
         bond.bond_duration = bond
             .bond_duration
             .checked_add(additional_duration)
-            .expect("duration extension caused overflow");
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
 
         // Also verify the end timestamp wouldn't overflow
         let _end_timestamp = bond
             .bond_start
             .checked_add(bond.bond_duration)
-            .expect("bond end timestamp would overflow");
-
-        e.storage().instance().set(&key, &bond);
-        bond
+            .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
     }
 
     /// Deposit fees into the contract's fee pool.
@@ -588,49 +3199,60 @@ impl CredenceBond {
         e.storage().instance().set(&key, &(current + amount));
     }
 
-    /// Withdraw the full bonded amount back to the identity.
+    /// Withdraw the full bonded amount back to the identity, less any collateral floor, which
+    /// stays in the (still active) bond until `close_bond`. As for `withdraw`, fixed bonds must
+    /// have reached `bond_start + bond_duration` (otherwise `BondError::LockupNotEnded`; use
+    /// `withdraw_early`) and rolling bonds must have requested withdrawal and waited out the
+    /// notice period.
     /// Uses a reentrancy guard to prevent re-entrance during external calls.
     pub fn withdraw_bond(e: Env, identity: Address) -> i128 {
         identity.require_auth();
+        pause::require_not_paused(&e);
         Self::acquire_lock(&e);
 
-        let bond_key = DataKey::Bond;
-        let bond: IdentityBond = e
-            .storage()
-            .instance()
-            .get(&bond_key)
-            .unwrap_or_else(|| panic!("no bond"));
+        let stored = bond_store::get(&e, &identity);
+        let mut bond = stored.clone();
 
-        if bond.identity != identity {
+        if !bond.active {
             Self::release_lock(&e);
-            panic!("not bond owner");
+            panic_with_error!(e, BondError::BondNotActive);
         }
-        if !bond.active {
+        let now = e.ledger().timestamp();
+        rolling_bond::settle_fixed_conversion(&e, &mut bond, now);
+        if bond.is_rolling {
+            // Rolling bonds exit only through request_withdrawal and the notice period
+            rolling_bond::check_notice_period_elapsed(&e, &bond, now);
+        } else if now < bond.bond_start.saturating_add(bond.bond_duration) {
+            // Before maturity the early exit path (with penalty) must be used
             Self::release_lock(&e);
-            panic!("bond not active");
+            panic_with_error!(e, BondError::LockupNotEnded);
+        }
+        if pledges::encumbered(&e, &identity, now) > 0 {
+            Self::release_lock(&e);
+            panic_with_error!(e, BondError::InsufficientBalance);
         }
 
-        let withdraw_amount = bond.bonded_amount - bond.slashed_amount;
-
-        // State update BEFORE external interaction (checks-effects-interactions)
-        let updated = IdentityBond {
-            identity: identity.clone(),
-            bonded_amount: 0,
-            bond_start: bond.bond_start,
-            bond_duration: bond.bond_duration,
-            slashed_amount: bond.slashed_amount,
-            is_rolling: bond.is_rolling,
-            notice_period: bond.notice_period,
-            withdrawal_requested_at: bond.withdrawal_requested_at,
-            active: false,
-            is_rolling: bond.is_rolling,
-            withdrawal_requested_at: bond.withdrawal_requested_at,
-            notice_period: bond.notice_period,
-        };
-        e.storage().instance().set(&bond_key, &updated);
+        // The collateral floor and pending slashes stay locked in the bond
+        let held = collateral::locked(&e, &bond) + slashing::pending_amount(&e, &identity);
+        let withdraw_amount = bond.bonded_amount - bond.slashed_amount - held;
+
+        // State update BEFORE external interaction (checks-effects-interactions).
+        // Slashed funds stay on the bond, as for close_bond, so slashed never exceeds bonded.
+        bond.bonded_amount = bond.slashed_amount + held;
+        bond.active = held > 0;
+        tiered_bond::update_tier(&e, &identity, stored.bonded_amount, bond.bonded_amount);
+        bond_store::save(&e, Some(&stored), &bond, "withdraw_bond");
+        token_transfer::transfer_out(&e, &identity, withdraw_amount);
+        receipts::issue(
+            &e,
+            &identity,
+            withdraw_amount,
+            &receipts::no_charge(&e),
+            "withdraw_bond",
+            &identity,
+        );
 
-        // External call: invoke callback if a callback contract is registered.
-        // In production this would be a token transfer; here we use a hook for testing.
+        // External call: invoke callback if a callback contract is registered
         let cb_key = Symbol::new(&e, "callback");
         if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
             let fn_name = Symbol::new(&e, "on_withdraw");
@@ -642,38 +3264,45 @@ impl CredenceBond {
         withdraw_amount
     }
 
-    /// Slash a portion of a bond. Only callable by admin.
+    /// Slash a portion of a bond. Only callable by the slash admin.
     /// Uses a reentrancy guard to prevent re-entrance during external calls.
-    pub fn slash_bond(e: Env, admin: Address, slash_amount: i128) -> i128 {
+    /// Writes an audit record with reason `slash_bond`.
+    pub fn slash_bond(e: Env, admin: Address, identity: Address, slash_amount: i128) -> i128 {
         admin.require_auth();
         Self::acquire_lock(&e);
 
-        let stored_admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("no admin"));
-        if stored_admin != admin {
+        if roles::get_slash_admin(&e) != admin {
             Self::release_lock(&e);
-            panic!("not admin");
+            panic_with_error!(e, BondError::NotAdmin);
+        }
+        if slash_amount <= 0 {
+            Self::release_lock(&e);
+            panic_with_error!(e, BondError::InvalidAmount);
         }
 
-        let bond_key = DataKey::Bond;
-        let bond: IdentityBond = e
-            .storage()
-            .instance()
-            .get(&bond_key)
-            .unwrap_or_else(|| panic!("no bond"));
+        validation::validate_amount_conforms(&e, slash_amount);
+
+        let bond = bond_store::get(&e, &identity);
 
         if !bond.active {
             Self::release_lock(&e);
-            panic!("bond not active");
+            panic_with_error!(e, BondError::BondNotActive);
+        }
+        if slashing::in_warmup(&e, &bond, 0) {
+            Self::release_lock(&e);
+            panic_with_error!(e, BondError::BondInWarmup);
         }
 
-        let new_slashed = bond.slashed_amount + slash_amount;
-        if new_slashed > bond.bonded_amount {
+        let new_slashed = bond
+            .slashed_amount
+            .checked_add(slash_amount)
+            .unwrap_or_else(|| {
+                Self::release_lock(&e);
+                panic_with_error!(e, BondError::Overflow)
+            });
+        if new_slashed > bond.bonded_amount - slashing::pending_amount(&e, &identity) {
             Self::release_lock(&e);
-            panic!("slash exceeds bond");
+            panic_with_error!(e, BondError::SlashExceedsBond);
         }
 
         // State update BEFORE external interaction
@@ -683,15 +3312,21 @@ impl CredenceBond {
             bond_start: bond.bond_start,
             bond_duration: bond.bond_duration,
             slashed_amount: new_slashed,
-            is_rolling: bond.is_rolling,
-            notice_period: bond.notice_period,
-            withdrawal_requested_at: bond.withdrawal_requested_at,
             active: bond.active,
             is_rolling: bond.is_rolling,
             withdrawal_requested_at: bond.withdrawal_requested_at,
-            notice_period: bond.notice_period,
+            notice_period_duration: bond.notice_period_duration,
         };
-        e.storage().instance().set(&bond_key, &updated);
+        bond_store::save(&e, Some(&bond), &updated, "slash_bond");
+        slashing::record_slash(
+            &e,
+            &admin,
+            &updated,
+            slash_amount,
+            slash_amount,
+            &Symbol::new(&e, "slash_bond"),
+            0,
+        );
 
         // External call: invoke callback if registered
         let cb_key = Symbol::new(&e, "callback");
@@ -709,18 +3344,9 @@ impl CredenceBond {
     /// Uses a reentrancy guard to prevent re-entrance during external calls.
     pub fn collect_fees(e: Env, admin: Address) -> i128 {
         admin.require_auth();
+        slashing::validate_admin(&e, &admin);
         Self::acquire_lock(&e);
 
-        let stored_admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("no admin"));
-        if stored_admin != admin {
-            Self::release_lock(&e);
-            panic!("not admin");
-        }
-
         let fee_key = Symbol::new(&e, "fees");
         let fees: i128 = e.storage().instance().get(&fee_key).unwrap_or(0);
 
@@ -757,7 +3383,7 @@ impl CredenceBond {
         let key = Symbol::new(e, "locked");
         let locked: bool = e.storage().instance().get(&key).unwrap_or(false);
         if locked {
-            panic!("reentrancy detected");
+            panic_with_error!(e, BondError::Reentrancy);
         }
         e.storage().instance().set(&key, &true);
     }
@@ -776,18 +3402,69 @@ impl CredenceBond {
 #[cfg(test)]
 mod test;
 
+#[cfg(test)]
+mod test_accounting;
 #[cfg(test)]
 mod test_attestation;
+#[cfg(test)]
+mod test_bench;
 
+mod test_attestation_types;
 #[cfg(test)]
 mod test_validation;
-mod test_attestation_types;
 
 #[cfg(test)]
 mod test_weighted_attestation;
 
+#[cfg(test)]
+mod test_pause;
+#[cfg(test)]
+mod test_pledges;
+#[cfg(test)]
+mod test_privacy_mode;
+#[cfg(test)]
+mod test_referral;
 #[cfg(test)]
 mod test_replay_prevention;
 
 #[cfg(test)]
 mod security;
+
+#[cfg(test)]
+mod test_bond_delegation;
+#[cfg(test)]
+mod test_bond_storage;
+#[cfg(test)]
+mod test_changelog;
+#[cfg(test)]
+mod test_collateral;
+#[cfg(test)]
+mod test_config_preview;
+#[cfg(test)]
+mod test_contract_identity;
+#[cfg(test)]
+mod test_deposit_hold;
+#[cfg(test)]
+mod test_deposit_premium;
+#[cfg(test)]
+mod test_early_exit_penalty;
+#[cfg(test)]
+mod test_event_mode;
+#[cfg(test)]
+mod test_roles;
+#[cfg(test)]
+mod test_rolling_bond;
+#[cfg(test)]
+mod test_slashing;
+#[cfg(test)]
+mod test_storage_budget;
+#[cfg(test)]
+mod test_tiered_bond;
+#[cfg(test)]
+mod test_token_transfer;
+#[cfg(test)]
+mod test_top_up_and_extend;
+#[cfg(test)]
+mod test_withdrawal_intent;
+#[cfg(test)]
+mod test_withdrawal_receipts;
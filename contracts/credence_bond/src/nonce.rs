@@ -2,11 +2,17 @@
 //!
 //! Each identity has a nonce that must be included in state-changing calls.
 //! The contract rejects replayed transactions by requiring nonce to match
-//! the stored value, then incrementing it. Handles nonce overflow by wrapping.
+//! the stored value, then incrementing it. Nonce overflow fails with `BondError::Overflow`.
+//! Consumed withdrawal-intent nonces live in persistent storage with the intent's deadline and
+//! a TTL that lasts until then; once the deadline has passed the deadline check rejects the
+//! intent on its own, so the entry may expire.
 
-use soroban_sdk::Env;
+use soroban_sdk::{panic_with_error, Env};
 
-use crate::DataKey;
+use crate::{BondError, DataKey, NonceError};
+
+/// Approximate ledger close time, used to turn an intent deadline into a TTL.
+const LEDGER_SECONDS: u64 = 5;
 
 /// Returns the current nonce for an identity. Caller must use this value in the next state-changing call.
 ///
@@ -24,14 +30,46 @@ pub fn get_nonce(e: &Env, identity: &soroban_sdk::Address) -> u64 {
 /// Call this at the start of state-changing functions.
 ///
 /// # Errors
-/// Panics if `expected_nonce` does not match the stored nonce (replay or out-of-order).
+/// Panics with `NonceError::InvalidNonce` if `expected_nonce` does not match the stored nonce
+/// (replay or out-of-order).
 pub fn consume_nonce(e: &Env, identity: &soroban_sdk::Address, expected_nonce: u64) {
     let current = get_nonce(e, identity);
     if current != expected_nonce {
-        panic!("invalid nonce: replay or out-of-order");
+        panic_with_error!(e, NonceError::InvalidNonce);
     }
-    let next = current.checked_add(1).expect("nonce overflow");
+    let next = current
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
     e.storage()
         .instance()
         .set(&DataKey::Nonce(identity.clone()), &next);
 }
+
+/// Returns true if the intent nonce has already been consumed for the identity.
+/// Also honours nonces consumed by earlier deployments, which kept them in instance storage.
+#[must_use]
+pub fn is_intent_nonce_used(e: &Env, identity: &soroban_sdk::Address, nonce: u64) -> bool {
+    let key = DataKey::IntentNonce(identity.clone(), nonce);
+    e.storage().persistent().has(&key) || e.storage().instance().has(&key)
+}
+
+/// Marks an intent nonce as consumed until `deadline`. Unlike the sequential nonce, intent
+/// nonces form a set so offline-signed intents can be submitted in any order, but each at most
+/// once. The entry stores the deadline and is kept alive at least until it passes (capped at the
+/// network's maximum TTL).
+///
+/// # Errors
+/// Panics with `NonceError::IntentNonceUsed` if the nonce was already consumed (replay).
+pub fn consume_intent_nonce(e: &Env, identity: &soroban_sdk::Address, nonce: u64, deadline: u64) {
+    if is_intent_nonce_used(e, identity, nonce) {
+        panic_with_error!(e, NonceError::IntentNonceUsed);
+    }
+    let key = DataKey::IntentNonce(identity.clone(), nonce);
+    e.storage().persistent().set(&key, &deadline);
+    let remaining = deadline.saturating_sub(e.ledger().timestamp());
+    let ledgers = remaining
+        .div_ceil(LEDGER_SECONDS)
+        .saturating_add(1)
+        .min(e.storage().max_ttl() as u64) as u32;
+    e.storage().persistent().extend_ttl(&key, ledgers, ledgers);
+}
@@ -0,0 +1,38 @@
+//! Contract Pause Switch
+//!
+//! Circuit breaker for user-initiated operations. While paused, bond creation, top-ups,
+//! withdrawals (including relayed intents), withdrawal requests and renewals are refused;
+//! admin actions such as slashing keep working so governance can still respond to incidents.
+
+use soroban_sdk::{panic_with_error, Env, Symbol};
+
+use crate::BondError;
+
+/// Storage key for the pause flag.
+const KEY_PAUSED: &str = "paused";
+
+/// Returns true if the contract is paused (default false).
+#[must_use]
+pub fn is_paused(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_PAUSED))
+        .unwrap_or(false)
+}
+
+/// Set the pause flag. Only admin should call (enforced by caller).
+pub fn set_paused(e: &Env, paused: bool) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_PAUSED), &paused);
+}
+
+/// Guard for pausable entry points.
+///
+/// # Panics
+/// * `BondError::ContractPaused` if the contract is paused
+pub fn require_not_paused(e: &Env) {
+    if is_paused(e) {
+        panic_with_error!(e, BondError::ContractPaused);
+    }
+}
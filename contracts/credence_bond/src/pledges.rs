@@ -0,0 +1,103 @@
+//! Service Agreement Pledges
+//!
+//! An identity can pledge part of its bond to a service agreement with a counterparty. Until
+//! the agreement ends, the pledged amount is encumbered: it cannot be withdrawn, and the
+//! counterparty can slash it (and only it) by citing evidence. Pledges release automatically
+//! at `ends_at`: the encumbrance is computed on read, so nothing has to be called.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Vec};
+
+use crate::{Pledge, PledgeError};
+
+/// Maximum live (not yet ended) pledges per identity.
+pub const MAX_PLEDGES_PER_IDENTITY: u32 = 10;
+
+/// Persistent storage keys for pledges.
+#[contracttype]
+pub enum PledgeKey {
+    /// Pledge by agreement id.
+    Pledge(BytesN<32>),
+    /// Agreement ids of the identity's live pledges.
+    Agreements(Address),
+}
+
+/// Returns true if the pledge still encumbers the bond at `now`.
+#[must_use]
+pub fn is_live(pledge: &Pledge, now: u64) -> bool {
+    now < pledge.ends_at
+}
+
+/// Returns the pledge for an agreement id, if any.
+#[must_use]
+pub fn load(e: &Env, agreement_id: &BytesN<32>) -> Option<Pledge> {
+    e.storage()
+        .persistent()
+        .get(&PledgeKey::Pledge(agreement_id.clone()))
+}
+
+/// Returns the pledge for an agreement id.
+///
+/// # Panics
+/// * `PledgeError::NotFound` if no pledge uses that agreement id
+#[must_use]
+pub fn get(e: &Env, agreement_id: &BytesN<32>) -> Pledge {
+    load(e, agreement_id).unwrap_or_else(|| panic_with_error!(e, PledgeError::NotFound))
+}
+
+/// Persist a pledge (new or updated).
+pub fn save(e: &Env, pledge: &Pledge) {
+    e.storage()
+        .persistent()
+        .set(&PledgeKey::Pledge(pledge.agreement_id.clone()), pledge);
+}
+
+/// Returns the identity's live pledges at `now`.
+#[must_use]
+pub fn live_pledges(e: &Env, identity: &Address, now: u64) -> Vec<Pledge> {
+    let ids: Vec<BytesN<32>> = e
+        .storage()
+        .persistent()
+        .get(&PledgeKey::Agreements(identity.clone()))
+        .unwrap_or_else(|| Vec::new(e));
+    let mut live = Vec::new(e);
+    for id in ids.iter() {
+        if let Some(pledge) = load(e, &id) {
+            if is_live(&pledge, now) {
+                live.push_back(pledge);
+            }
+        }
+    }
+    live
+}
+
+/// Returns the amount of the identity's bond encumbered by live pledges at `now` (pledged
+/// amounts less what counterparties have already slashed).
+#[must_use]
+pub fn encumbered(e: &Env, identity: &Address, now: u64) -> i128 {
+    live_pledges(e, identity, now)
+        .iter()
+        .fold(0i128, |sum, p| sum.saturating_add(p.amount - p.slashed))
+}
+
+/// Record a new pledge against the identity's live pledge list, dropping ended ones.
+///
+/// # Panics
+/// * `PledgeError::AlreadyExists` if the agreement id is already used
+/// * `PledgeError::TooMany` if the identity already has `MAX_PLEDGES_PER_IDENTITY` live pledges
+pub fn add(e: &Env, pledge: &Pledge, now: u64) {
+    if load(e, &pledge.agreement_id).is_some() {
+        panic_with_error!(e, PledgeError::AlreadyExists);
+    }
+    let mut ids = Vec::new(e);
+    for live in live_pledges(e, &pledge.identity, now).iter() {
+        ids.push_back(live.agreement_id);
+    }
+    if ids.len() >= MAX_PLEDGES_PER_IDENTITY {
+        panic_with_error!(e, PledgeError::TooMany);
+    }
+    ids.push_back(pledge.agreement_id.clone());
+    e.storage()
+        .persistent()
+        .set(&PledgeKey::Agreements(pledge.identity.clone()), &ids);
+    save(e, pledge);
+}
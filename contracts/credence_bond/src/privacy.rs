@@ -0,0 +1,46 @@
+//! Bond Privacy Mode
+//!
+//! Optional restriction on who can read an identity's full bond details. When enabled,
+//! detail views are limited to the identity itself and the admin; minimal views such as
+//! `get_tier` and `attest_min_tier` stay public so integrations keep working.
+
+use soroban_sdk::{panic_with_error, Address, Env, Symbol};
+
+use crate::{BondError, DataKey};
+
+/// Storage key for the privacy mode flag.
+const KEY_PRIVACY_MODE: &str = "privacy_mode";
+
+/// Returns true if privacy mode is enabled (default false).
+#[must_use]
+pub fn is_enabled(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_PRIVACY_MODE))
+        .unwrap_or(false)
+}
+
+/// Enable or disable privacy mode. Only admin should call (enforced by caller).
+pub fn set_enabled(e: &Env, enabled: bool) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_PRIVACY_MODE), &enabled);
+}
+
+/// Authorize a detail read of `identity`'s bond by `reader`. No-op when privacy mode is off.
+///
+/// # Panics
+/// * `BondError::ReadRestricted` if privacy mode is on and `reader` is neither the identity nor the admin
+pub fn require_reader(e: &Env, reader: &Address, identity: &Address) {
+    if !is_enabled(e) {
+        return;
+    }
+    reader.require_auth();
+    if reader == identity {
+        return;
+    }
+    let admin: Option<Address> = e.storage().instance().get(&DataKey::Admin);
+    if admin.as_ref() != Some(reader) {
+        panic_with_error!(e, BondError::ReadRestricted);
+    }
+}
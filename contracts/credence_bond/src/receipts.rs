@@ -0,0 +1,221 @@
+//! Withdrawal Receipts
+//!
+//! Every successful withdrawal (`withdraw`, `withdraw_early`, `withdraw_bond`) is assigned a
+//! sequential receipt id and a compact `WithdrawalReceipt` is stored in persistent storage,
+//! so users disputing a payout have a stable reference. Receipts are also indexed per identity.
+//! Each receipt carries a `ChargeBreakdown` assembled by the withdrawal path at charge time,
+//! retrievable via `explain_charge`. Every charging path builds its breakdown with
+//! `finalize_charge`, which clamps the charge to `[0, gross]`.
+//! Receipt entries get the same TTL bumps as bonds on every write and read. The per-identity
+//! index keeps the latest `get_retention` ids; older receipts stay retrievable by id.
+
+use soroban_sdk::{panic_with_error, Address, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::bond_store::{BUMP_TARGET, BUMP_THRESHOLD};
+use crate::{events, BondError, ChargeBreakdown, DataKey, ReceiptError, WithdrawalReceipt};
+
+/// Storage key for the last assigned receipt id.
+const KEY_RECEIPT_COUNTER: &str = "receipt_counter";
+/// Storage key for the per-identity receipt index cap.
+const KEY_RETENTION: &str = "receipt_retention";
+
+/// Default number of receipt ids kept in each identity's index.
+pub const DEFAULT_RECEIPT_RETENTION: u32 = 100;
+
+fn store<V: IntoVal<Env, Val>>(e: &Env, key: &DataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, BUMP_THRESHOLD, BUMP_TARGET);
+}
+
+fn bump(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, BUMP_THRESHOLD, BUMP_TARGET);
+}
+
+/// Returns the number of receipts issued so far (the last assigned id; ids start at 1).
+#[must_use]
+pub fn get_receipt_count(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_RECEIPT_COUNTER))
+        .unwrap_or(0)
+}
+
+/// Returns the receipt with the given id. Panics with `ReceiptError::NotFound` if it does not
+/// exist.
+#[must_use]
+pub fn get_receipt(e: &Env, id: u64) -> WithdrawalReceipt {
+    let key = DataKey::WithdrawalReceipt(id);
+    let receipt = e
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(e, ReceiptError::NotFound));
+    bump(e, &key);
+    receipt
+}
+
+fn load_index(e: &Env, identity: &Address) -> Vec<u64> {
+    let key = DataKey::IdentityReceipts(identity.clone());
+    match e.storage().persistent().get(&key) {
+        Some(ids) => {
+            bump(e, &key);
+            ids
+        }
+        None => Vec::new(e),
+    }
+}
+
+/// Returns up to `limit` of the identity's retained receipt ids, in issue order, starting at
+/// index `start`.
+#[must_use]
+pub fn get_identity_receipts(e: &Env, identity: &Address, start: u32, limit: u32) -> Vec<u64> {
+    let ids = load_index(e, identity);
+    let end = start.saturating_add(limit).min(ids.len());
+    if start >= end {
+        return Vec::new(e);
+    }
+    ids.slice(start..end)
+}
+
+/// Returns how many receipt ids each identity's index keeps.
+#[must_use]
+pub fn get_retention(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_RETENTION))
+        .unwrap_or(DEFAULT_RECEIPT_RETENTION)
+}
+
+/// Set the per-identity index cap. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `ReceiptError::InvalidRetention` if `retention` is zero
+pub fn set_retention(e: &Env, retention: u32) {
+    if retention == 0 {
+        panic_with_error!(e, ReceiptError::InvalidRetention);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_RETENTION), &retention);
+}
+
+/// Breakdown for a withdrawal path that charges nothing.
+#[must_use]
+pub fn no_charge(e: &Env) -> ChargeBreakdown {
+    ChargeBreakdown {
+        base_amount: 0,
+        rate_bps: 0,
+        rounding: Symbol::new(e, "none"),
+        discounts: Vec::new(e),
+        surcharges: Vec::new(e),
+        final_amount: 0,
+    }
+}
+
+/// Assemble the breakdown for a charge on a `gross` withdrawal and clamp it to `[0, gross]`.
+///
+/// `final_amount = base_amount + sum(surcharges) - sum(discounts)`, floored at zero and capped
+/// at `gross`, so stacked discounts can never inflate the payout and stacked surcharges can
+/// never exceed it. A clamp is recorded as its own adjustment (`floor_zero` surcharge or
+/// `cap_gross` discount) so the identity above still holds for the returned breakdown.
+#[must_use]
+pub fn finalize_charge(
+    e: &Env,
+    gross: i128,
+    base_amount: i128,
+    rate_bps: u32,
+    rounding: &str,
+    mut discounts: Vec<(Symbol, i128)>,
+    mut surcharges: Vec<(Symbol, i128)>,
+) -> ChargeBreakdown {
+    let mut raw = base_amount;
+    for (_, amount) in surcharges.iter() {
+        raw = raw.saturating_add(amount);
+    }
+    for (_, amount) in discounts.iter() {
+        raw = raw.saturating_sub(amount);
+    }
+
+    let final_amount = raw.clamp(0, gross.max(0));
+    if final_amount > raw {
+        surcharges.push_back((Symbol::new(e, "floor_zero"), final_amount - raw));
+    } else if final_amount < raw {
+        discounts.push_back((Symbol::new(e, "cap_gross"), raw - final_amount));
+    }
+
+    ChargeBreakdown {
+        base_amount,
+        rate_bps,
+        rounding: Symbol::new(e, rounding),
+        discounts,
+        surcharges,
+        final_amount,
+    }
+}
+
+/// Returns the charge breakdown attached to a receipt. Panics with `ReceiptError::NotFound` if
+/// the receipt does not exist.
+#[must_use]
+pub fn get_breakdown(e: &Env, id: u64) -> ChargeBreakdown {
+    let key = DataKey::ChargeBreakdown(id);
+    let breakdown = e
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(e, ReceiptError::NotFound));
+    bump(e, &key);
+    breakdown
+}
+
+/// Issue a receipt for a completed withdrawal and emit `withdrawal_receipt`.
+/// The penalty is the breakdown's `final_amount`; `net = gross - penalty`.
+/// Returns the new receipt id.
+pub fn issue(
+    e: &Env,
+    identity: &Address,
+    gross: i128,
+    breakdown: &ChargeBreakdown,
+    path: &str,
+    destination: &Address,
+) -> u64 {
+    let penalty = breakdown.final_amount;
+    let id = get_receipt_count(e)
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_RECEIPT_COUNTER), &id);
+
+    let receipt = WithdrawalReceipt {
+        id,
+        identity: identity.clone(),
+        gross,
+        penalty,
+        net: gross
+            .checked_sub(penalty)
+            .unwrap_or_else(|| panic_with_error!(e, BondError::InvalidAmount)),
+        path: Symbol::new(e, path),
+        destination: destination.clone(),
+        timestamp: e.ledger().timestamp(),
+    };
+    store(e, &DataKey::WithdrawalReceipt(id), &receipt);
+    store(e, &DataKey::ChargeBreakdown(id), breakdown);
+
+    let mut ids = load_index(e, identity);
+    ids.push_back(id);
+    while ids.len() > get_retention(e) {
+        ids.pop_front();
+    }
+    store(e, &DataKey::IdentityReceipts(identity.clone()), &ids);
+
+    events::publish(
+        e,
+        (Symbol::new(e, "withdrawal_receipt"), identity.clone()),
+        (id, receipt.net),
+    );
+    id
+}
@@ -0,0 +1,288 @@
+//! Referral Attribution
+//!
+//! A bond created with `create_bond_with_referrer` records its referrer for the life of the
+//! bond. A reward of `reward_bps` of the bonded amount is reserved for the referrer out of the
+//! insurance pool (capped at the pool balance) and vests once the referred bond has stayed open
+//! for the minimum age. A bond closed before then forfeits the reward back to the pool, so
+//! bonding and exiting straight away earns nothing.
+//!
+//! Vested rewards are paid out by `claim_referral_rewards`. Self-referrals and referrals that
+//! would close a cycle in the referrer chain are rejected.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+use crate::{events, insurance, BondError, IdentityBond, Referral, ReferralError, ReferralStats};
+
+/// Storage keys for referral records.
+#[contracttype]
+pub enum ReferralKey {
+    /// Referral of the identity's current bond (persistent storage).
+    Referral(Address),
+    /// Identities whose referral reward has not vested yet, per referrer (persistent storage).
+    Pending(Address),
+    /// Running totals per referrer (persistent storage).
+    Stats(Address),
+}
+
+/// Storage key for the referral reward rate in basis points.
+const KEY_REWARD_BPS: &str = "referral_reward_bps";
+/// Storage key for the minimum age before a reward vests.
+const KEY_MIN_AGE: &str = "referral_min_age";
+
+/// Maximum referral reward (10%).
+pub const MAX_REFERRAL_REWARD_BPS: u32 = 1_000;
+/// Default minimum bond age before a reward vests: 30 days.
+pub const DEFAULT_MIN_AGE: u64 = 30 * 24 * 60 * 60;
+/// How far up the referrer chain cycles are looked for.
+pub const MAX_CHAIN_DEPTH: u32 = 16;
+
+/// Returns (reward_bps, min_age). The reward defaults to 0, which accrues nothing.
+#[must_use]
+pub fn get_config(e: &Env) -> (u32, u64) {
+    let reward_bps = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_REWARD_BPS))
+        .unwrap_or(0);
+    let min_age = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_MIN_AGE))
+        .unwrap_or(DEFAULT_MIN_AGE);
+    (reward_bps, min_age)
+}
+
+/// Set the reward rate and minimum age. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if `reward_bps` exceeds `MAX_REFERRAL_REWARD_BPS`
+pub fn set_config(e: &Env, reward_bps: u32, min_age: u64) {
+    if reward_bps > MAX_REFERRAL_REWARD_BPS {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_REWARD_BPS), &reward_bps);
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_MIN_AGE), &min_age);
+}
+
+/// Returns the referral recorded for the identity's bond, if any.
+#[must_use]
+pub fn get_referral(e: &Env, identity: &Address) -> Option<Referral> {
+    e.storage()
+        .persistent()
+        .get(&ReferralKey::Referral(identity.clone()))
+}
+
+fn get_pending(e: &Env, referrer: &Address) -> Vec<Address> {
+    e.storage()
+        .persistent()
+        .get(&ReferralKey::Pending(referrer.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+fn set_pending(e: &Env, referrer: &Address, pending: &Vec<Address>) {
+    let key = ReferralKey::Pending(referrer.clone());
+    if pending.is_empty() {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, pending);
+    }
+}
+
+fn get_stored_stats(e: &Env, referrer: &Address) -> ReferralStats {
+    e.storage()
+        .persistent()
+        .get(&ReferralKey::Stats(referrer.clone()))
+        .unwrap_or(ReferralStats {
+            referred_count: 0,
+            pending: 0,
+            claimable: 0,
+            claimed: 0,
+            forfeited: 0,
+        })
+}
+
+fn set_stats(e: &Env, referrer: &Address, stats: &ReferralStats) {
+    e.storage()
+        .persistent()
+        .set(&ReferralKey::Stats(referrer.clone()), stats);
+}
+
+/// Reject a self-referral or a referrer whose own referrer chain leads back to `identity`.
+///
+/// # Panics
+/// * `ReferralError::SelfReferral` if `referrer == identity`
+/// * `ReferralError::CircularReferral` if `identity` appears in the referrer's chain
+pub fn validate_referrer(e: &Env, identity: &Address, referrer: &Address) {
+    if referrer == identity {
+        panic_with_error!(e, ReferralError::SelfReferral);
+    }
+    let mut current = referrer.clone();
+    for _ in 0..MAX_CHAIN_DEPTH {
+        match get_referral(e, &current) {
+            Some(referral) if &referral.referrer == identity => {
+                panic_with_error!(e, ReferralError::CircularReferral)
+            }
+            Some(referral) => current = referral.referrer,
+            None => return,
+        }
+    }
+}
+
+/// Record the referrer of a newly created bond and reserve its reward from the insurance pool.
+/// Emits `referral_recorded` with (identity, referrer, reward).
+pub fn attribute(e: &Env, bond: &IdentityBond, referrer: &Address) {
+    let (reward_bps, _) = get_config(e);
+    let reward = bond
+        .bonded_amount
+        .checked_mul(reward_bps as i128)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow))
+        / 10_000;
+    let reward = reward.min(insurance::get_pool_balance(e)).max(0);
+    insurance::reserve_for_referral(e, reward);
+
+    let referral = Referral {
+        referrer: referrer.clone(),
+        reward,
+        bond_start: bond.bond_start,
+        settled: reward == 0,
+    };
+    e.storage()
+        .persistent()
+        .set(&ReferralKey::Referral(bond.identity.clone()), &referral);
+
+    let mut stats = get_stored_stats(e, referrer);
+    stats.referred_count = stats.referred_count.saturating_add(1);
+    if reward > 0 {
+        stats.pending = stats.pending.saturating_add(reward);
+        let mut pending = get_pending(e, referrer);
+        pending.push_back(bond.identity.clone());
+        set_pending(e, referrer, &pending);
+    }
+    set_stats(e, referrer, &stats);
+
+    events::publish(
+        e,
+        (Symbol::new(e, "referral_recorded"),),
+        (bond.identity.clone(), referrer.clone(), reward),
+    );
+}
+
+/// Settle an unvested referral when its bond closes: the reward vests if the bond reached the
+/// minimum age, otherwise it is forfeited back to the insurance pool.
+pub fn settle_on_close(e: &Env, before: Option<&IdentityBond>, bond: &IdentityBond) {
+    if !before.is_some_and(|b| b.active) || bond.active {
+        return;
+    }
+    let Some(mut referral) = get_referral(e, &bond.identity) else {
+        return;
+    };
+    if referral.settled {
+        return;
+    }
+    let (_, min_age) = get_config(e);
+    let mut stats = get_stored_stats(e, &referral.referrer);
+    stats.pending = stats.pending.saturating_sub(referral.reward);
+    if is_vested(e, &referral, min_age) {
+        stats.claimable = stats.claimable.saturating_add(referral.reward);
+    } else {
+        stats.forfeited = stats.forfeited.saturating_add(referral.reward);
+        insurance::release_from_referral(e, referral.reward);
+        events::publish(
+            e,
+            (Symbol::new(e, "referral_forfeited"),),
+            (
+                bond.identity.clone(),
+                referral.referrer.clone(),
+                referral.reward,
+            ),
+        );
+    }
+    set_stats(e, &referral.referrer, &stats);
+
+    let mut pending = get_pending(e, &referral.referrer);
+    if let Some(index) = pending.first_index_of(&bond.identity) {
+        pending.remove(index);
+    }
+    set_pending(e, &referral.referrer, &pending);
+
+    referral.settled = true;
+    e.storage()
+        .persistent()
+        .set(&ReferralKey::Referral(bond.identity.clone()), &referral);
+}
+
+/// Drop a settled referral left from the identity's previous bond, so a bond created without
+/// a referrer has none.
+pub fn clear_settled(e: &Env, identity: &Address) {
+    if get_referral(e, identity).is_some_and(|r| r.settled) {
+        e.storage()
+            .persistent()
+            .remove(&ReferralKey::Referral(identity.clone()));
+    }
+}
+
+fn is_vested(e: &Env, referral: &Referral, min_age: u64) -> bool {
+    e.ledger().timestamp() >= referral.bond_start.saturating_add(min_age)
+}
+
+/// Split the referrer's pending rewards into (still pending identities, vested referrals).
+fn partition_pending(e: &Env, referrer: &Address) -> (Vec<Address>, Vec<(Address, Referral)>) {
+    let (_, min_age) = get_config(e);
+    let mut still_pending = Vec::new(e);
+    let mut vested = Vec::new(e);
+    for identity in get_pending(e, referrer).iter() {
+        match get_referral(e, &identity) {
+            Some(referral) if is_vested(e, &referral, min_age) => {
+                vested.push_back((identity, referral));
+            }
+            Some(_) => still_pending.push_back(identity),
+            None => {}
+        }
+    }
+    (still_pending, vested)
+}
+
+/// Returns the referrer's totals, counting rewards that have reached the minimum age as
+/// claimable. Read-only.
+#[must_use]
+pub fn get_stats(e: &Env, referrer: &Address) -> ReferralStats {
+    let mut stats = get_stored_stats(e, referrer);
+    let (_, vested) = partition_pending(e, referrer);
+    for (_, referral) in vested.iter() {
+        stats.pending = stats.pending.saturating_sub(referral.reward);
+        stats.claimable = stats.claimable.saturating_add(referral.reward);
+    }
+    stats
+}
+
+/// Vest every reward that has reached the minimum age, then clear and return the referrer's
+/// claimable balance.
+///
+/// # Panics
+/// * `BondError::NothingToClaim` if nothing has vested
+pub fn take_claimable(e: &Env, referrer: &Address) -> i128 {
+    let mut stats = get_stored_stats(e, referrer);
+    let (still_pending, vested) = partition_pending(e, referrer);
+    for (identity, mut referral) in vested.iter() {
+        stats.pending = stats.pending.saturating_sub(referral.reward);
+        stats.claimable = stats.claimable.saturating_add(referral.reward);
+        referral.settled = true;
+        e.storage()
+            .persistent()
+            .set(&ReferralKey::Referral(identity), &referral);
+    }
+    set_pending(e, referrer, &still_pending);
+
+    let amount = stats.claimable;
+    if amount <= 0 {
+        panic_with_error!(e, BondError::NothingToClaim);
+    }
+    stats.claimable = 0;
+    stats.claimed = stats.claimed.saturating_add(amount);
+    set_stats(e, referrer, &stats);
+    amount
+}
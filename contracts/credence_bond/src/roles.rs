@@ -0,0 +1,88 @@
+//! Admin Roles
+//!
+//! The admin set at `initialize` is the super-admin. Two narrower roles sit beside it: the
+//! slash admin runs `slash`, `slash_with_reason` and `batch_slash`, and the treasury admin
+//! sets the early exit and deposit fee configuration. `initialize` gives both roles to the
+//! admin; the admin can then hand each to a different key.
+//!
+//! Contracts initialized before the roles existed have no role entries; each role then
+//! resolves to the admin. When the admin changes hands through `accept_admin`, any role still
+//! held by the old admin moves to the new one.
+
+use soroban_sdk::{panic_with_error, Address, Env};
+
+use crate::{BondError, DataKey};
+
+fn get_admin(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized))
+}
+
+/// Returns the slash admin (the admin if never set).
+///
+/// # Panics
+/// * `BondError::NotInitialized` before `initialize`
+pub fn get_slash_admin(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&DataKey::SlashAdmin)
+        .unwrap_or_else(|| get_admin(e))
+}
+
+/// Returns the treasury admin (the admin if never set).
+///
+/// # Panics
+/// * `BondError::NotInitialized` before `initialize`
+pub fn get_treasury_admin(e: &Env) -> Address {
+    e.storage()
+        .instance()
+        .get(&DataKey::TreasuryAdmin)
+        .unwrap_or_else(|| get_admin(e))
+}
+
+/// Set the slash admin. Only the admin should call (enforced by caller).
+pub fn set_slash_admin(e: &Env, slash_admin: &Address) {
+    e.storage()
+        .instance()
+        .set(&DataKey::SlashAdmin, slash_admin);
+}
+
+/// Set the treasury admin. Only the admin should call (enforced by caller).
+pub fn set_treasury_admin(e: &Env, treasury_admin: &Address) {
+    e.storage()
+        .instance()
+        .set(&DataKey::TreasuryAdmin, treasury_admin);
+}
+
+/// Move every role still held by `old_admin` to `new_admin`. Roles handed to other keys are
+/// left alone.
+pub fn migrate_admin(e: &Env, old_admin: &Address, new_admin: &Address) {
+    if &get_slash_admin(e) == old_admin {
+        set_slash_admin(e, new_admin);
+    }
+    if &get_treasury_admin(e) == old_admin {
+        set_treasury_admin(e, new_admin);
+    }
+}
+
+/// Validates that the caller holds the slash admin role.
+///
+/// # Panics
+/// * `BondError::NotAdmin` if `caller` is not the slash admin
+pub fn validate_slash_admin(e: &Env, caller: &Address) {
+    if caller != &get_slash_admin(e) {
+        panic_with_error!(e, BondError::NotAdmin);
+    }
+}
+
+/// Validates that the caller holds the treasury admin role.
+///
+/// # Panics
+/// * `BondError::NotAdmin` if `caller` is not the treasury admin
+pub fn validate_treasury_admin(e: &Env, caller: &Address) {
+    if caller != &get_treasury_admin(e) {
+        panic_with_error!(e, BondError::NotAdmin);
+    }
+}
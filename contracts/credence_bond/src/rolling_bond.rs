@@ -2,8 +2,39 @@
 //!
 //! Auto-renews at period end unless withdrawal was requested with notice.
 //! Tracks withdrawal request and notice period for scoring.
+//!
+//! ## Conversion
+//! A fixed bond can be converted to rolling at any time; its current lock-up is kept. A rolling
+//! bond converts back to fixed only at the end of its current period, so the conversion is
+//! scheduled in persistent storage (kept alive with the bond) and applied once the period has
+//! ended.
+//!
+//! ## Unclaimed withdrawals
+//! A withdrawal whose notice period has elapsed but that is never withdrawn blocks renewal.
+//! Once a reclaim window past maturity has lapsed, `renew_if_rolling` applies the admin-set
+//! `UnclaimedPolicy`: `Hold` (the default) leaves the bond waiting, `Reabsorb` cancels the
+//! request so the bond keeps rolling, and `Claimable` moves the withdrawable amount out of the
+//! bond into a balance the identity can claim at any time.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol};
+
+use crate::bond_store::{BUMP_TARGET, BUMP_THRESHOLD};
+use crate::{events, BondError, IdentityBond, UnclaimedPolicy};
 
-use crate::IdentityBond;
+/// Storage keys for rolling bond state kept outside the bond record.
+#[contracttype]
+pub enum RollingDataKey {
+    /// Rolling bond scheduled to become fixed at the end of its current period (persistent
+    /// storage).
+    FixedConversion(Address),
+    /// Unclaimed withdrawal moved out of the identity's bond (persistent storage).
+    Unclaimed(Address),
+}
+
+/// Storage key for the unclaimed withdrawal policy.
+const KEY_UNCLAIMED_POLICY: &str = "unclaimed_policy";
+/// Storage key for the reclaim window in seconds.
+const KEY_RECLAIM_WINDOW: &str = "reclaim_window";
 
 /// Returns true if the bond has passed its period end (bond_start + bond_duration).
 #[must_use]
@@ -14,7 +45,6 @@ pub fn is_period_ended(now: u64, bond_start: u64, bond_duration: u64) -> bool {
 
 /// Returns true if a withdrawal was requested and the notice period has elapsed.
 #[must_use]
-#[allow(dead_code)] // Public API for off-chain / frontends
 pub fn can_withdraw_after_notice(
     now: u64,
     withdrawal_requested_at: u64,
@@ -27,9 +57,214 @@ pub fn can_withdraw_after_notice(
     now >= notice_end
 }
 
+/// Enforce the notice period before a rolling bond is withdrawn. Non-rolling bonds pass.
+///
+/// # Panics
+/// * `BondError::WithdrawalNotRequested` if the bond is rolling and no withdrawal was requested
+/// * `BondError::NoticePeriodNotElapsed` if the notice period has not yet passed at `now`
+pub fn check_notice_period_elapsed(e: &Env, bond: &IdentityBond, now: u64) {
+    if !bond.is_rolling {
+        return;
+    }
+    if bond.withdrawal_requested_at == 0 {
+        panic_with_error!(e, BondError::WithdrawalNotRequested);
+    }
+    if !can_withdraw_after_notice(
+        now,
+        bond.withdrawal_requested_at,
+        bond.notice_period_duration,
+    ) {
+        panic_with_error!(e, BondError::NoticePeriodNotElapsed);
+    }
+}
+
+/// Returns true if the bond can be withdrawn without penalty at `now`: a fixed bond once its
+/// lock-up has ended, a rolling bond once a requested withdrawal's notice period has elapsed.
+#[must_use]
+pub fn is_ready_for_withdrawal(bond: &IdentityBond, now: u64) -> bool {
+    if bond.is_rolling {
+        can_withdraw_after_notice(
+            now,
+            bond.withdrawal_requested_at,
+            bond.notice_period_duration,
+        )
+    } else {
+        is_period_ended(now, bond.bond_start, bond.bond_duration)
+    }
+}
+
+/// Seconds until `is_ready_for_withdrawal` holds (0 once ready). A rolling bond with no
+/// withdrawal request never becomes ready on its own and returns `u64::MAX`.
+#[must_use]
+pub fn time_until_ready(bond: &IdentityBond, now: u64) -> u64 {
+    let ready_at = if bond.is_rolling {
+        if bond.withdrawal_requested_at == 0 {
+            return u64::MAX;
+        }
+        bond.withdrawal_requested_at
+            .saturating_add(bond.notice_period_duration)
+    } else {
+        bond.bond_start.saturating_add(bond.bond_duration)
+    };
+    ready_at.saturating_sub(now)
+}
+
+/// Returns true if the bond qualifies for renewal at `now`: it is rolling, active, still has
+/// available (unslashed) balance, its period has ended and no withdrawal has been requested.
+/// A dead or exiting bond is never renewed; a pending request (matured or not) blocks renewal
+/// until it is withdrawn or cancelled.
+#[must_use]
+pub fn is_renewable(bond: &IdentityBond, now: u64) -> bool {
+    if !bond.is_rolling || !bond.active {
+        return false;
+    }
+    if bond.bonded_amount.saturating_sub(bond.slashed_amount) <= 0 {
+        return false;
+    }
+    if bond.withdrawal_requested_at != 0 {
+        return false;
+    }
+    is_period_ended(now, bond.bond_start, bond.bond_duration)
+}
+
 /// Advance bond to a new period (set bond_start to now, keep duration and rolling flag).
 /// Call when period has ended and bond is rolling.
 pub fn apply_renewal(bond: &mut IdentityBond, new_start: u64) {
     bond.bond_start = new_start;
     bond.withdrawal_requested_at = 0; // reset withdrawal request on renewal
 }
+
+/// Validate a notice period for a rolling bond: it must be non-zero and fit within one period.
+///
+/// # Panics
+/// * `BondError::InvalidNoticePeriod` if `notice_period_duration` is 0 or exceeds `bond_duration`
+pub fn validate_notice_period(e: &Env, notice_period_duration: u64, bond_duration: u64) {
+    if notice_period_duration == 0 || notice_period_duration > bond_duration {
+        panic_with_error!(e, BondError::InvalidNoticePeriod);
+    }
+}
+
+/// Returns true if the identity's rolling bond is scheduled to become fixed.
+#[must_use]
+pub fn is_fixed_conversion_pending(e: &Env, identity: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .has(&RollingDataKey::FixedConversion(identity.clone()))
+}
+
+/// Schedule the identity's rolling bond to become fixed at the end of its current period.
+pub fn schedule_fixed_conversion(e: &Env, identity: &Address) {
+    let key = RollingDataKey::FixedConversion(identity.clone());
+    e.storage().persistent().set(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+}
+
+/// Extend the TTL of a scheduled fixed conversion alongside the identity's bond.
+pub fn bump_ttl(e: &Env, identity: &Address) {
+    crate::bond_store::bump_if_present(e, &RollingDataKey::FixedConversion(identity.clone()));
+}
+
+/// Drop any scheduled fixed conversion for the identity.
+pub fn clear_fixed_conversion(e: &Env, identity: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&RollingDataKey::FixedConversion(identity.clone()));
+}
+
+/// Apply a scheduled fixed conversion once the bond's period has ended. The bond keeps its
+/// start and duration, so it is matured as a fixed bond. Emits `converted_to_fixed` with
+/// (identity, now). Returns true if the bond was converted; the caller must save it.
+pub fn settle_fixed_conversion(e: &Env, bond: &mut IdentityBond, now: u64) -> bool {
+    if !bond.is_rolling || !is_fixed_conversion_pending(e, &bond.identity) {
+        return false;
+    }
+    if !is_period_ended(now, bond.bond_start, bond.bond_duration) {
+        return false;
+    }
+    bond.is_rolling = false;
+    bond.notice_period_duration = 0;
+    bond.withdrawal_requested_at = 0;
+    clear_fixed_conversion(e, &bond.identity);
+    events::publish(
+        e,
+        (Symbol::new(e, "converted_to_fixed"),),
+        (bond.identity.clone(), now),
+    );
+    true
+}
+
+/// Returns the unclaimed withdrawal policy and its reclaim window in seconds (`Hold`, 0 if
+/// never set).
+#[must_use]
+pub fn get_unclaimed_policy(e: &Env) -> (UnclaimedPolicy, u64) {
+    let policy = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_UNCLAIMED_POLICY))
+        .unwrap_or(UnclaimedPolicy::Hold);
+    let window = e
+        .storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_RECLAIM_WINDOW))
+        .unwrap_or(0);
+    (policy, window)
+}
+
+/// Set the unclaimed withdrawal policy. Only admin should call (enforced by caller).
+pub fn set_unclaimed_policy(e: &Env, policy: UnclaimedPolicy, reclaim_window: u64) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_UNCLAIMED_POLICY), &policy);
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_RECLAIM_WINDOW), &reclaim_window);
+}
+
+/// Returns true if the bond's withdrawal matured and then went unclaimed for the whole reclaim
+/// window at `now`.
+#[must_use]
+pub fn is_reclaim_window_lapsed(bond: &IdentityBond, now: u64, reclaim_window: u64) -> bool {
+    if !bond.is_rolling || !bond.active || bond.withdrawal_requested_at == 0 {
+        return false;
+    }
+    let matured_at = bond
+        .withdrawal_requested_at
+        .saturating_add(bond.notice_period_duration);
+    now >= matured_at.saturating_add(reclaim_window)
+}
+
+/// Returns the identity's unclaimed withdrawal balance (0 if none).
+#[must_use]
+pub fn get_unclaimed(e: &Env, identity: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&RollingDataKey::Unclaimed(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Add `amount` to the identity's unclaimed withdrawal balance.
+pub fn add_unclaimed(e: &Env, identity: &Address, amount: i128) {
+    let total = get_unclaimed(e, identity)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
+        .persistent()
+        .set(&RollingDataKey::Unclaimed(identity.clone()), &total);
+}
+
+/// Clear and return the identity's unclaimed withdrawal balance.
+///
+/// # Panics
+/// * `BondError::NothingToClaim` if there is none
+pub fn take_unclaimed(e: &Env, identity: &Address) -> i128 {
+    let amount = get_unclaimed(e, identity);
+    if amount <= 0 {
+        panic_with_error!(e, BondError::NothingToClaim);
+    }
+    e.storage()
+        .persistent()
+        .remove(&RollingDataKey::Unclaimed(identity.clone()));
+    amount
+}
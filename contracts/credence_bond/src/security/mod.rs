@@ -1 +1,4 @@
+pub mod test_admin_transfer;
 pub mod test_arithmetic;
+pub mod test_authorization;
+pub mod test_initialization;
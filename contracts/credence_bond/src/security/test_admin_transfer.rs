@@ -0,0 +1,192 @@
+//! Admin Transfer Security Tests
+//!
+//! Verifies the two-step admin transfer: only the current admin can propose, only the
+//! proposed address can accept, and privileges move only once the transfer is accepted.
+
+#![cfg(test)]
+
+use crate::*;
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::{symbol_short, Env, IntoVal};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let new_admin = Address::generate(e);
+    (client, admin, new_admin)
+}
+
+#[test]
+fn test_no_pending_admin_initially() {
+    let e = Env::default();
+    let (client, _admin, _new_admin) = setup(&e);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_propose_requires_current_admin() {
+    let e = Env::default();
+    let (client, _admin, new_admin) = setup(&e);
+    client.propose_admin(&new_admin, &new_admin);
+}
+
+#[test]
+fn test_current_admin_keeps_privileges_while_pending() {
+    let e = Env::default();
+    let (client, admin, new_admin) = setup(&e);
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    assert_eq!(client.get_admin(), admin);
+
+    client.set_privacy_mode(&admin, &true);
+    assert_eq!(
+        client.try_set_privacy_mode(&new_admin, &false).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_accept_transfers_admin() {
+    let e = Env::default();
+    let (client, admin, new_admin) = setup(&e);
+    client.propose_admin(&admin, &new_admin);
+    client.accept_admin(&new_admin);
+
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "admin_transfer_accepted"),).into_val(&e)
+    );
+    let data: (Address, Address, u64) = data.into_val(&e);
+    assert_eq!(data, (admin.clone(), new_admin.clone(), 0));
+
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_old_admin_loses_privileges_after_accept() {
+    let e = Env::default();
+    let (client, admin, new_admin) = setup(&e);
+    client.propose_admin(&admin, &new_admin);
+    client.accept_admin(&new_admin);
+
+    assert_eq!(
+        client.try_set_privacy_mode(&admin, &true).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client.try_propose_admin(&admin, &admin).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    client.set_privacy_mode(&new_admin, &true);
+}
+
+#[test]
+fn test_roles_move_with_admin_transfer() {
+    let e = Env::default();
+    let (client, admin, new_admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
+    client.propose_admin(&admin, &new_admin);
+    client.accept_admin(&new_admin);
+
+    assert_eq!(client.get_slash_admin(), new_admin);
+    assert_eq!(client.get_treasury_admin(), new_admin);
+    assert_eq!(
+        client
+            .try_slash(&admin, &identity, &1_000_000, &symbol_short!("test"), &0)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client
+            .try_set_early_exit_config(&admin, &Address::generate(&e), &500)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    let bond = client.slash(
+        &new_admin,
+        &identity,
+        &1_000_000,
+        &symbol_short!("test"),
+        &0,
+    );
+    assert_eq!(bond.slashed_amount, 1_000_000);
+}
+
+#[test]
+fn test_delegated_role_kept_on_admin_transfer() {
+    let e = Env::default();
+    let (client, admin, new_admin) = setup(&e);
+    let slasher = Address::generate(&e);
+    client.set_slash_admin(&admin, &slasher);
+    client.propose_admin(&admin, &new_admin);
+    client.accept_admin(&new_admin);
+
+    assert_eq!(client.get_slash_admin(), slasher);
+    assert_eq!(client.get_treasury_admin(), new_admin);
+}
+
+#[test]
+fn test_accept_by_wrong_address_rejected() {
+    let e = Env::default();
+    let (client, admin, new_admin) = setup(&e);
+    client.propose_admin(&admin, &new_admin);
+
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client.try_accept_admin(&stranger).err(),
+        Some(Ok(BondError::NotPendingAdmin.into()))
+    );
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_accept_without_proposed_admin_signature_rejected() {
+    let e = Env::default();
+    let (client, admin, new_admin) = setup(&e);
+    client.propose_admin(&admin, &new_admin);
+
+    // Only the current admin signs: accepting on the new admin's behalf must fail
+    e.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "accept_admin",
+            args: (new_admin.clone(),).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(client.try_accept_admin(&new_admin).is_err());
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+fn test_accept_without_proposal_rejected() {
+    let e = Env::default();
+    let (client, _admin, new_admin) = setup(&e);
+    client.accept_admin(&new_admin);
+}
+
+#[test]
+fn test_new_proposal_replaces_pending() {
+    let e = Env::default();
+    let (client, admin, first) = setup(&e);
+    let second = Address::generate(&e);
+    client.propose_admin(&admin, &first);
+    client.propose_admin(&admin, &second);
+
+    assert_eq!(
+        client.try_accept_admin(&first).err(),
+        Some(Ok(BondError::NotPendingAdmin.into()))
+    );
+    client.accept_admin(&second);
+    assert_eq!(client.get_admin(), second);
+}
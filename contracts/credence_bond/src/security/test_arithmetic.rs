@@ -13,9 +13,10 @@
 
 #![cfg(test)]
 
+use crate::validation::{MAX_BOND_AMOUNT, MIN_BOND_AMOUNT};
 use crate::*;
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::Env;
+use soroban_sdk::{symbol_short, Env};
 
 // ============================================================================
 // i128 OVERFLOW TESTS
@@ -25,45 +26,51 @@ use soroban_sdk::Env;
 fn test_i128_bond_amount_at_max() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    // Test creating bond with maximum i128 value
-    let bond = client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
+    // Test creating bond with the maximum allowed amount
+    let bond = client.create_bond(&identity, &MAX_BOND_AMOUNT, &86400_u64, &false, &0_u64);
 
-    assert_eq!(bond.bonded_amount, i128::MAX);
+    assert_eq!(bond.bonded_amount, MAX_BOND_AMOUNT);
     assert!(bond.active);
 }
 
 #[test]
-#[should_panic(expected = "top-up caused overflow")]
-fn test_i128_overflow_on_top_up() {
+#[should_panic(expected = "Error(Contract, #702)")]
+fn test_i128_top_up_beyond_max_bond_amount() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    // Create bond with max - 1000
-    client.create_bond(&identity, &(i128::MAX - 1000), &86400_u64, &false, &0_u64);
-
-    // Attempt to top up by 2000, which should overflow
-    client.top_up(&2000);
+    // Create bond just below the maximum allowed amount
+    client.create_bond(
+        &identity,
+        &(MAX_BOND_AMOUNT - MIN_BOND_AMOUNT / 2),
+        &86400_u64,
+        &false,
+        &0_u64,
+    );
+
+    // Attempt to top up past the maximum, which must be rejected
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
 }
 
 #[test]
-#[should_panic(expected = "top-up caused overflow")]
-fn test_i128_overflow_on_max_top_up() {
+#[should_panic(expected = "Error(Contract, #702)")]
+fn test_i128_top_up_at_max_bond_amount() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -71,18 +78,18 @@ fn test_i128_overflow_on_max_top_up() {
 
     let identity = Address::generate(&e);
     // Create bond with max value
-    client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &MAX_BOND_AMOUNT, &86400_u64, &false, &0_u64);
 
-    // Attempt to top up by 1, which should overflow
-    client.top_up(&1);
+    // Attempt to top up by the minimum, which must be rejected
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
 }
 
 #[test]
-#[should_panic(expected = "slashing caused overflow")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_i128_overflow_on_massive_slashing() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -90,43 +97,50 @@ fn test_i128_overflow_on_massive_slashing() {
 
     let identity = Address::generate(&e);
     // Create bond with large amount
-    client.create_bond(&identity, &(i128::MAX / 2), &86400_u64, &false, &0_u64);
-
-    // Slash near-maximum amount first
-    client.slash(&admin, &(i128::MAX / 2));
-
-    // Current slashed_amount is now i128::MAX / 2
-    // Attempt to slash more than i128::MAX / 2, which will cause overflow in checked_add
-    client.slash(&admin, &(i128::MAX / 2 + 2));
+    client.create_bond(&identity, &MAX_BOND_AMOUNT, &86400_u64, &false, &0_u64);
+
+    // Slash the full bond first
+    client.slash(
+        &admin,
+        &identity,
+        &MAX_BOND_AMOUNT,
+        &symbol_short!("test"),
+        &0,
+    );
+
+    // Current slashed_amount is now MAX_BOND_AMOUNT
+    // Attempt to slash i128::MAX more, which will cause overflow in checked_add
+    client.slash(&admin, &identity, &i128::MAX, &symbol_short!("test"), &0);
 }
 
 #[test]
 fn test_i128_large_bond_operations() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    let large_amount = i128::MAX / 2;
+    let large_amount = MAX_BOND_AMOUNT / 2;
 
     // Create bond with large amount
     let bond = client.create_bond(&identity, &large_amount, &86400_u64, &false, &0_u64);
     assert_eq!(bond.bonded_amount, large_amount);
 
-    // Top up with another large amount (should succeed as sum < i128::MAX)
-    let bond = client.top_up(&(large_amount / 2));
+    // Top up with another large amount (should succeed as sum < MAX_BOND_AMOUNT)
+    let bond = client.top_up(&identity, &(large_amount / 2));
     assert_eq!(bond.bonded_amount, large_amount + (large_amount / 2));
 }
 
 #[test]
+#[should_panic(expected = "Error(Contract, #5)")]
 fn test_negative_bond_amount_handling() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -134,10 +148,8 @@ fn test_negative_bond_amount_handling() {
 
     let identity = Address::generate(&e);
 
-    // Test with negative amount (technically allowed by i128, but may be business logic violation)
-    // This documents current behavior
-    let bond = client.create_bond(&identity, &(-1000), &86400_u64, &false, &0_u64);
-    assert_eq!(bond.bonded_amount, -1000);
+    // Negative amounts are rejected by bond amount validation
+    client.create_bond(&identity, &(-1000), &86400_u64, &false, &0_u64);
 }
 
 // ============================================================================
@@ -148,7 +160,7 @@ fn test_negative_bond_amount_handling() {
 fn test_u64_max_duration() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -156,17 +168,17 @@ fn test_u64_max_duration() {
 
     let identity = Address::generate(&e);
     // Test creating bond with maximum u64 duration
-    let bond = client.create_bond(&identity, &1000, &u64::MAX, &false, &0_u64);
+    let bond = client.create_bond(&identity, &1_000_000_000, &u64::MAX, &false, &0_u64);
 
     assert_eq!(bond.bond_duration, u64::MAX);
 }
 
 #[test]
-#[should_panic(expected = "duration extension caused overflow")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_u64_overflow_on_duration_extension() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -174,14 +186,20 @@ fn test_u64_overflow_on_duration_extension() {
 
     let identity = Address::generate(&e);
     // Create bond with max - 1000 duration
-    client.create_bond(&identity, &1000, &(u64::MAX - 1000), &false, &0_u64);
+    client.create_bond(
+        &identity,
+        &1_000_000_000,
+        &(u64::MAX - 1000),
+        &false,
+        &0_u64,
+    );
 
     // Attempt to extend by 2000, which should overflow
-    client.extend_duration(&2000);
+    client.extend_duration(&identity, &2000);
 }
 
 #[test]
-#[should_panic(expected = "bond end timestamp would overflow")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_u64_overflow_on_end_timestamp() {
     let e = Env::default();
     e.mock_all_auths();
@@ -190,7 +208,7 @@ fn test_u64_overflow_on_end_timestamp() {
         li.timestamp = u64::MAX - 1000;
     });
 
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -199,14 +217,14 @@ fn test_u64_overflow_on_end_timestamp() {
     let identity = Address::generate(&e);
     // Create bond with duration that would cause end timestamp to overflow
     // bond_start will be u64::MAX - 1000, adding 2000 duration will overflow
-    client.create_bond(&identity, &1000, &2000, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &2000, &false, &0_u64);
 }
 
 #[test]
 fn test_u64_large_duration_extension() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -216,11 +234,11 @@ fn test_u64_large_duration_extension() {
     let duration = u64::MAX / 2;
 
     // Create bond with large duration
-    let bond = client.create_bond(&identity, &1000, &duration, &false, &0_u64);
+    let bond = client.create_bond(&identity, &1_000_000_000, &duration, &false, &0_u64);
     assert_eq!(bond.bond_duration, duration);
 
     // Extend with another large duration (should succeed as sum < u64::MAX)
-    let bond = client.extend_duration(&(duration / 2));
+    let bond = client.extend_duration(&identity, &(duration / 2));
     assert_eq!(bond.bond_duration, duration + (duration / 2));
 }
 
@@ -233,7 +251,7 @@ fn test_timestamp_boundary_conditions() {
         li.timestamp = u64::MAX - 10000;
     });
 
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -241,7 +259,7 @@ fn test_timestamp_boundary_conditions() {
 
     let identity = Address::generate(&e);
     // Create bond with safe duration
-    let bond = client.create_bond(&identity, &1000, &5000, &false, &0_u64);
+    let bond = client.create_bond(&identity, &1_000_000_000, &5000, &false, &0_u64);
 
     assert_eq!(bond.bond_duration, 5000);
     assert!(bond.bond_start >= u64::MAX - 10000);
@@ -252,59 +270,62 @@ fn test_timestamp_boundary_conditions() {
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_withdrawal_exceeds_available_balance() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Attempt to withdraw more than available
-    client.withdraw(&1001);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &1_001_000_000);
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_withdrawal_after_slashing() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Slash 400
-    client.slash(&admin, &400);
+    client.slash(&admin, &identity, &400_000_000, &symbol_short!("test"), &0);
 
     // Available balance is now 600, attempt to withdraw 601
-    client.withdraw(&601);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &601_000_000);
 }
 
 #[test]
 fn test_withdrawal_exact_available_balance() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Withdraw exact available amount
-    let bond = client.withdraw(&1000);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    let bond = client.withdraw(&identity, &1_000_000_000);
     assert_eq!(bond.bonded_amount, 0);
 }
 
@@ -312,78 +333,94 @@ fn test_withdrawal_exact_available_balance() {
 fn test_withdrawal_zero_amount() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
-
-    // Withdraw zero amount (should succeed)
-    let bond = client.withdraw(&0);
-    assert_eq!(bond.bonded_amount, 1000);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
+
+    // Withdrawing zero is rejected and leaves the bond unchanged
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    assert_eq!(
+        client.try_withdraw(&identity, &0).err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        1_000_000_000
+    );
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_multiple_withdrawals_causing_underflow() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Multiple withdrawals
-    client.withdraw(&400);
-    client.withdraw(&400);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &400_000_000);
+    client.withdraw(&identity, &400_000_000);
     // Available balance is now 200, this should fail
-    client.withdraw(&300);
+    client.withdraw(&identity, &300_000_000);
 }
 
 #[test]
 fn test_withdrawal_with_max_i128_bond() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &MAX_BOND_AMOUNT, &86400_u64, &false, &0_u64);
 
     // Withdraw large amount
-    let bond = client.withdraw(&(i128::MAX / 2));
-    assert_eq!(bond.bonded_amount, i128::MAX - (i128::MAX / 2));
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    let bond = client.withdraw(&identity, &(MAX_BOND_AMOUNT / 2));
+    assert_eq!(bond.bonded_amount, MAX_BOND_AMOUNT - (MAX_BOND_AMOUNT / 2));
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_withdrawal_when_fully_slashed() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Slash entire amount
-    client.slash(&admin, &1000);
+    client.slash(
+        &admin,
+        &identity,
+        &1_000_000_000,
+        &symbol_short!("test"),
+        &0,
+    );
 
     // Attempt to withdraw when fully slashed (available = 0)
-    client.withdraw(&1);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &1_000_000);
 }
 
 // ============================================================================
@@ -394,120 +431,138 @@ fn test_withdrawal_when_fully_slashed() {
 fn test_slashing_normal_amount() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Slash normal amount
-    let bond = client.slash(&admin, &300);
-    assert_eq!(bond.slashed_amount, 300);
-    assert_eq!(bond.bonded_amount, 1000);
+    let bond = client.slash(&admin, &identity, &300_000_000, &symbol_short!("test"), &0);
+    assert_eq!(bond.slashed_amount, 300_000_000);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 #[test]
 fn test_slashing_exceeds_bonded_amount() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Slash more than bonded amount (should cap at bonded amount)
-    let bond = client.slash(&admin, &2000);
-    assert_eq!(bond.slashed_amount, 1000); // Capped at bonded_amount
-    assert_eq!(bond.bonded_amount, 1000);
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &2_000_000_000,
+        &symbol_short!("test"),
+        &0,
+    );
+    assert_eq!(bond.slashed_amount, 1_000_000_000); // Capped at bonded_amount
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
 }
 
 #[test]
 fn test_multiple_slashing_operations() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Multiple slashing operations
-    let bond = client.slash(&admin, &200);
-    assert_eq!(bond.slashed_amount, 200);
+    let bond = client.slash(&admin, &identity, &200_000_000, &symbol_short!("test"), &0);
+    assert_eq!(bond.slashed_amount, 200_000_000);
 
-    let bond = client.slash(&admin, &300);
-    assert_eq!(bond.slashed_amount, 500);
+    let bond = client.slash(&admin, &identity, &300_000_000, &symbol_short!("test"), &0);
+    assert_eq!(bond.slashed_amount, 500_000_000);
 
-    let bond = client.slash(&admin, &100);
-    assert_eq!(bond.slashed_amount, 600);
+    let bond = client.slash(&admin, &identity, &100_000_000, &symbol_short!("test"), &0);
+    assert_eq!(bond.slashed_amount, 600_000_000);
 }
 
 #[test]
 fn test_slashing_zero_amount() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Slash zero amount
-    let bond = client.slash(&admin, &0);
-    assert_eq!(bond.slashed_amount, 0);
+    assert_eq!(
+        client
+            .try_slash(&admin, &identity, &0, &symbol_short!("test"), &0)
+            .err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
 }
 
 #[test]
 fn test_slashing_after_withdrawal() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Withdraw first
-    client.withdraw(&300);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &300_000_000);
 
     // Then slash (should still reference original bonded amount)
-    let bond = client.slash(&admin, &400);
-    assert_eq!(bond.slashed_amount, 400);
-    assert_eq!(bond.bonded_amount, 700); // After withdrawal
+    let bond = client.slash(&admin, &identity, &400_000_000, &symbol_short!("test"), &0);
+    assert_eq!(bond.slashed_amount, 400_000_000);
+    assert_eq!(bond.bonded_amount, 700_000_000); // After withdrawal
 }
 
 #[test]
 fn test_slashing_with_max_values() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &i128::MAX, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &MAX_BOND_AMOUNT, &86400_u64, &false, &0_u64);
 
     // Slash large amount
-    let bond = client.slash(&admin, &(i128::MAX / 2));
-    assert_eq!(bond.slashed_amount, i128::MAX / 2);
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(MAX_BOND_AMOUNT / 2),
+        &symbol_short!("test"),
+        &0,
+    );
+    assert_eq!(bond.slashed_amount, MAX_BOND_AMOUNT / 2);
 }
 
 // ============================================================================
@@ -518,7 +573,7 @@ fn test_slashing_with_max_values() {
 fn test_complex_arithmetic_scenario() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -526,66 +581,85 @@ fn test_complex_arithmetic_scenario() {
 
     let identity = Address::generate(&e);
     // Initial bond
-    client.create_bond(&identity, &10000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &10_000_000_000, &86400_u64, &false, &0_u64);
 
     // Top up
-    let bond = client.top_up(&5000);
-    assert_eq!(bond.bonded_amount, 15000);
+    let bond = client.top_up(&identity, &5_000_000_000);
+    assert_eq!(bond.bonded_amount, 15_000_000_000);
 
     // Slash some
-    let bond = client.slash(&admin, &3000);
-    assert_eq!(bond.slashed_amount, 3000);
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &3_000_000_000,
+        &symbol_short!("test"),
+        &0,
+    );
+    assert_eq!(bond.slashed_amount, 3_000_000_000);
 
     // Withdraw available (15000 - 3000 = 12000 available)
-    let bond = client.withdraw(&8000);
-    assert_eq!(bond.bonded_amount, 7000);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    let bond = client.withdraw(&identity, &8_000_000_000);
+    assert_eq!(bond.bonded_amount, 7_000_000_000);
 
     // Verify final state
-    assert_eq!(bond.slashed_amount, 3000);
-    assert_eq!(bond.bonded_amount, 7000);
+    assert_eq!(bond.slashed_amount, 3_000_000_000);
+    assert_eq!(bond.bonded_amount, 7_000_000_000);
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_withdrawal_leaves_insufficient_for_slashed() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000, &86400_u64, &false, &0_u64);
 
     // Slash 500
-    client.slash(&admin, &500);
+    client.slash(&admin, &identity, &500_000_000, &symbol_short!("test"), &0);
 
     // Try to withdraw 600 (but only 500 is available after slashing)
     // This should panic with "insufficient balance for withdrawal"
-    client.withdraw(&600);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &600_000_000);
 }
 
 #[test]
 fn test_boundary_arithmetic_with_zero_values() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    // Create bond with zero amount
-    let bond = client.create_bond(&identity, &0, &86400_u64, &false, &0_u64);
-    assert_eq!(bond.bonded_amount, 0);
-
-    // Try operations on zero bond
-    let bond = client.slash(&admin, &0);
-    assert_eq!(bond.slashed_amount, 0);
-
-    let bond = client.withdraw(&0);
-    assert_eq!(bond.bonded_amount, 0);
+    // Create bond with the minimum amount
+    let bond = client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, MIN_BOND_AMOUNT);
+
+    // Zero-valued operations are rejected and leave the bond unchanged
+    assert_eq!(
+        client
+            .try_slash(&admin, &identity, &0, &symbol_short!("test"), &0)
+            .err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    assert_eq!(
+        client.try_withdraw(&identity, &0).err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        MIN_BOND_AMOUNT
+    );
 }
@@ -0,0 +1,284 @@
+//! Authorization Security Tests
+//!
+//! Verifies that every user-facing function that mutates a bond requires the bond
+//! identity's authorization: a call authorized only by a stranger must fail and leave
+//! the bond untouched, while the same call authorized by the identity succeeds. Admin
+//! entry points likewise need the admin's signature, not just the admin's address.
+
+#![cfg(test)]
+
+use crate::*;
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::{symbol_short, Env, IntoVal, Val};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+const AMOUNT: i128 = 1_000_000;
+
+fn setup(e: &Env, is_rolling: bool) -> (CredenceBondClient<'_>, Address, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    client.set_early_exit_config(&admin, &Address::generate(e), &100);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &is_rolling, &0_u64);
+    let stranger = Address::generate(e);
+    (client, identity, stranger)
+}
+
+/// Authorize exactly one call to `fn_name` with `args`, signed by `signer`.
+fn authorize(
+    e: &Env,
+    client: &CredenceBondClient<'_>,
+    signer: &Address,
+    fn_name: &str,
+    args: soroban_sdk::Vec<Val>,
+) {
+    e.mock_auths(&[MockAuth {
+        address: signer,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name,
+            args,
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+fn bonded(client: &CredenceBondClient<'_>, identity: &Address) -> i128 {
+    client.get_identity_state(identity).bonded_amount
+}
+
+// ============================================================================
+// UNAUTHORIZED CALLS
+// ============================================================================
+
+#[test]
+fn test_create_bond_for_other_identity_rejected() {
+    let e = Env::default();
+    let (client, _identity, stranger) = setup(&e, false);
+    let victim = Address::generate(&e);
+    let args = (victim.clone(), BOND_AMOUNT, 86400_u64, false, 0_u64).into_val(&e);
+    authorize(&e, &client, &stranger, "create_bond", args);
+    let result = client.try_create_bond(&victim, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_withdraw_by_stranger_rejected() {
+    let e = Env::default();
+    let (client, identity, stranger) = setup(&e, false);
+    e.ledger().with_mut(|li| li.timestamp = 100_000);
+    let args = (identity.clone(), AMOUNT).into_val(&e);
+    authorize(&e, &client, &stranger, "withdraw", args);
+    let result = client.try_withdraw(&identity, &AMOUNT);
+    assert!(result.is_err());
+    assert_eq!(bonded(&client, &identity), BOND_AMOUNT);
+}
+
+#[test]
+fn test_withdraw_early_by_stranger_rejected() {
+    let e = Env::default();
+    let (client, identity, stranger) = setup(&e, false);
+    let args = (identity.clone(), AMOUNT).into_val(&e);
+    authorize(&e, &client, &stranger, "withdraw_early", args);
+    let result = client.try_withdraw_early(&identity, &AMOUNT);
+    assert!(result.is_err());
+    assert_eq!(bonded(&client, &identity), BOND_AMOUNT);
+}
+
+#[test]
+fn test_top_up_by_stranger_rejected() {
+    let e = Env::default();
+    let (client, identity, stranger) = setup(&e, false);
+    let args = (identity.clone(), AMOUNT).into_val(&e);
+    authorize(&e, &client, &stranger, "top_up", args);
+    let result = client.try_top_up(&identity, &AMOUNT);
+    assert!(result.is_err());
+    assert_eq!(bonded(&client, &identity), BOND_AMOUNT);
+}
+
+#[test]
+fn test_extend_duration_by_stranger_rejected() {
+    let e = Env::default();
+    let (client, identity, stranger) = setup(&e, false);
+    let args = (identity.clone(), 10_u64).into_val(&e);
+    authorize(&e, &client, &stranger, "extend_duration", args);
+    let result = client.try_extend_duration(&identity, &10_u64);
+    assert!(result.is_err());
+    assert_eq!(client.get_identity_state(&identity).bond_duration, 86400);
+}
+
+#[test]
+fn test_request_withdrawal_by_stranger_rejected() {
+    let e = Env::default();
+    let (client, identity, stranger) = setup(&e, true);
+    let args = (identity.clone(),).into_val(&e);
+    authorize(&e, &client, &stranger, "request_withdrawal", args);
+    let result = client.try_request_withdrawal(&identity);
+    assert!(result.is_err());
+    assert_eq!(
+        client.get_identity_state(&identity).withdrawal_requested_at,
+        0
+    );
+}
+
+#[test]
+fn test_cancel_withdrawal_request_by_stranger_rejected() {
+    let e = Env::default();
+    let (client, identity, stranger) = setup(&e, true);
+    client.request_withdrawal(&identity);
+    let args = (identity.clone(),).into_val(&e);
+    authorize(&e, &client, &stranger, "cancel_withdrawal_request", args);
+    let result = client.try_cancel_withdrawal_request(&identity);
+    assert!(result.is_err());
+    assert_eq!(
+        client.get_identity_state(&identity).withdrawal_requested_at,
+        1000
+    );
+}
+
+#[test]
+fn test_withdraw_bond_by_stranger_rejected() {
+    let e = Env::default();
+    let (client, identity, stranger) = setup(&e, false);
+    let args = (identity.clone(),).into_val(&e);
+    authorize(&e, &client, &stranger, "withdraw_bond", args);
+    let result = client.try_withdraw_bond(&identity);
+    assert!(result.is_err());
+    assert_eq!(bonded(&client, &identity), BOND_AMOUNT);
+}
+
+// ============================================================================
+// AUTHORIZED CALLS
+// ============================================================================
+
+#[test]
+fn test_create_bond_authorized_by_identity() {
+    let e = Env::default();
+    let (client, _identity, _stranger) = setup(&e, false);
+    let identity = Address::generate(&e);
+    let args = (identity.clone(), BOND_AMOUNT, 86400_u64, false, 0_u64).into_val(&e);
+    authorize(&e, &client, &identity, "create_bond", args);
+    let bond = client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT);
+}
+
+#[test]
+fn test_withdraw_authorized_by_identity() {
+    let e = Env::default();
+    let (client, identity, _stranger) = setup(&e, false);
+    e.ledger().with_mut(|li| li.timestamp = 100_000);
+    let args = (identity.clone(), AMOUNT).into_val(&e);
+    authorize(&e, &client, &identity, "withdraw", args);
+    let bond = client.withdraw(&identity, &AMOUNT);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT - AMOUNT);
+}
+
+#[test]
+fn test_withdraw_early_authorized_by_identity() {
+    let e = Env::default();
+    let (client, identity, _stranger) = setup(&e, false);
+    let args = (identity.clone(), AMOUNT).into_val(&e);
+    authorize(&e, &client, &identity, "withdraw_early", args);
+    let bond = client.withdraw_early(&identity, &AMOUNT);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT - AMOUNT);
+}
+
+#[test]
+fn test_top_up_authorized_by_identity() {
+    let e = Env::default();
+    let (client, identity, _stranger) = setup(&e, false);
+    let args = (identity.clone(), AMOUNT).into_val(&e);
+    authorize(&e, &client, &identity, "top_up", args);
+    let bond = client.top_up(&identity, &AMOUNT);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT + AMOUNT);
+}
+
+#[test]
+fn test_extend_duration_authorized_by_identity() {
+    let e = Env::default();
+    let (client, identity, _stranger) = setup(&e, false);
+    let args = (identity.clone(), 10_u64).into_val(&e);
+    authorize(&e, &client, &identity, "extend_duration", args);
+    let bond = client.extend_duration(&identity, &10_u64);
+    assert_eq!(bond.bond_duration, 86410);
+}
+
+#[test]
+fn test_request_and_cancel_withdrawal_authorized_by_identity() {
+    let e = Env::default();
+    let (client, identity, _stranger) = setup(&e, true);
+    let args: soroban_sdk::Vec<Val> = (identity.clone(),).into_val(&e);
+    authorize(&e, &client, &identity, "request_withdrawal", args.clone());
+    let bond = client.request_withdrawal(&identity);
+    assert_eq!(bond.withdrawal_requested_at, 1000);
+    authorize(&e, &client, &identity, "cancel_withdrawal_request", args);
+    let bond = client.cancel_withdrawal_request(&identity);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+}
+
+// ============================================================================
+// ADMIN CALLS
+// ============================================================================
+
+#[test]
+fn test_slash_without_admin_signature_rejected() {
+    let e = Env::default();
+    let (client, identity, stranger) = setup(&e, false);
+    let admin = client.get_admin();
+    // Passing the admin's address while only the stranger signs must fail
+    let args = (
+        admin.clone(),
+        identity.clone(),
+        AMOUNT,
+        symbol_short!("test"),
+        0_u64,
+    )
+        .into_val(&e);
+    authorize(&e, &client, &stranger, "slash", args);
+    let result = client.try_slash(&admin, &identity, &AMOUNT, &symbol_short!("test"), &0);
+    assert!(result.is_err());
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
+}
+
+#[test]
+fn test_slash_authorized_by_admin() {
+    let e = Env::default();
+    let (client, identity, _stranger) = setup(&e, false);
+    let admin = client.get_admin();
+    let args = (
+        admin.clone(),
+        identity.clone(),
+        AMOUNT,
+        symbol_short!("test"),
+        0_u64,
+    )
+        .into_val(&e);
+    authorize(&e, &client, &admin, "slash", args);
+    let bond = client.slash(&admin, &identity, &AMOUNT, &symbol_short!("test"), &0);
+    assert_eq!(bond.slashed_amount, AMOUNT);
+}
+
+#[test]
+fn test_set_early_exit_config_without_admin_signature_rejected() {
+    let e = Env::default();
+    let (client, _identity, stranger) = setup(&e, false);
+    let admin = client.get_admin();
+    let args = (admin.clone(), stranger.clone(), 5000_u32).into_val(&e);
+    authorize(&e, &client, &stranger, "set_early_exit_config", args);
+    let result = client.try_set_early_exit_config(&admin, &stranger, &5000);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_set_early_exit_config_authorized_by_admin() {
+    let e = Env::default();
+    let (client, _identity, stranger) = setup(&e, false);
+    let admin = client.get_admin();
+    let args = (admin.clone(), stranger.clone(), 5000_u32).into_val(&e);
+    authorize(&e, &client, &admin, "set_early_exit_config", args);
+    client.set_early_exit_config(&admin, &stranger, &5000);
+}
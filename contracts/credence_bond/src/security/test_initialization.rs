@@ -0,0 +1,79 @@
+//! Initialization Security Tests
+//!
+//! Verifies that `initialize` can only run once, so the stored admin cannot be overwritten,
+//! and that integrators can inspect initialization state via `is_initialized`/`get_admin`.
+
+#![cfg(test)]
+
+use crate::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::Env;
+
+fn register(e: &Env) -> CredenceBondClient<'_> {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    CredenceBondClient::new(e, &contract_id)
+}
+
+#[test]
+fn test_initialize_sets_admin() {
+    let e = Env::default();
+    let client = register(&e);
+    assert!(!client.is_initialized());
+
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    assert!(client.is_initialized());
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn test_get_admin_before_initialize_fails() {
+    let e = Env::default();
+    let client = register(&e);
+    assert_eq!(
+        client.try_get_admin().err(),
+        Some(Ok(BondError::NotInitialized.into()))
+    );
+}
+
+#[test]
+fn test_second_initialize_from_attacker_fails() {
+    let e = Env::default();
+    let client = register(&e);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+
+    let attacker = Address::generate(&e);
+    assert_eq!(
+        client.try_initialize(&attacker).err(),
+        Some(Ok(BondError::AlreadyInitialized.into()))
+    );
+    assert_eq!(client.get_admin(), admin);
+
+    // The attacker still cannot pass admin checks
+    assert_eq!(
+        client.try_set_strict_amounts(&attacker, &true).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+fn test_reinitialize_by_same_admin_fails() {
+    let e = Env::default();
+    let client = register(&e);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    client.initialize(&admin);
+}
+
+#[test]
+fn test_initialize_requires_admin_auth() {
+    let e = Env::default();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    assert!(client.try_initialize(&admin).is_err());
+    assert!(!client.is_initialized());
+}
@@ -1,12 +1,12 @@
 //! Slashing Module
 //!
-//! Implements the core `slash_bond()` functionality for reducing a bond's value as a penalty
-//! for misconduct. This module manages authorization, state updates, event emission, and
+//! Implements the core `slash_bond_with_reason()` functionality for reducing a bond's value as a
+//! penalty for misconduct. This module manages authorization, state updates, event emission, and
 //! treasury fund transfers.
 //!
 //! ## Authorization
-//! Only the contract admin can execute slashing operations. Non-admin calls panic with
-//! "not admin" error message.
+//! Only the contract admin can execute slashing operations. Non-admin calls fail with
+//! `BondError::NotAdmin`.
 //!
 //! ## Design
 //! - **Partial Slashing**: Can slash any amount up to bonded_amount
@@ -14,31 +14,92 @@
 //! - **Cumulative**: Multiple slashes accumulate (tracked in slashed_amount)
 //! - **Over-slash Protection**: Ensures slashed_amount never exceeds bonded_amount
 //! - **Withdrawals**: Affected by slashing (withdrawable = bonded - slashed)
+//!
+//! ## Audit Records
+//! Every slash path (`slash`, `slash_with_reason`, `batch_slash`, `slash_bond` and
+//! `slash_pledge`) writes an immutable `SlashRecord` (reason code, approving admin, requested
+//! and applied amounts, timestamp) to persistent storage under a sequential id. Records and
+//! histories get a fresh TTL whenever they are written.
+//!
+//! ## Disputes
+//! With a dispute window configured in `SlashConfig`, records written by `slash_bond_with_reason`
+//! start out `pending`. The slashed amount is locked in the identity's pending slash total
+//! rather than added to `slashed_amount`: it cannot be withdrawn, but it does not count against
+//! the available balance yet. The identity can `dispute_slash` before the window ends, and the
+//! admin then upholds or overturns it with `adjudicate_slash` (overturning releases the
+//! amount). Undisputed slashes are made final by `finalize_slash` once the window has passed.
+//! A slash moves into `slashed_amount` when it becomes final. Only the status fields of a record
+//! change; its audit fields are never rewritten.
+//!
+//! ## Warm-up
+//! With a warm-up window set, admin slashes of a bond younger than the window are rejected
+//! unless they name an incident after the bond's creation. Creation time is tracked separately
+//! from `bond_start`, which rolling renewals move.
+//!
+//! ## Slash History
+//! Every slash of a bond is also appended to a per-identity history (time, amount, record id).
+//! `slash_summary` totals it over a time window, so an identity can prove it was not slashed in
+//! that window. Slashes overturned on dispute are counted separately and do not count against
+//! the identity.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, IntoVal, Symbol, Val, Vec};
 
-use soroban_sdk::{Address, Env, Symbol};
+use crate::bond_store::{BUMP_TARGET, BUMP_THRESHOLD};
+use crate::{
+    events, BondError, IdentityBond, SlashConfig, SlashDisputeError, SlashRecord, SlashSummary,
+};
+
+/// Persistent storage keys for slash audit records.
+#[contracttype]
+pub enum SlashDataKey {
+    /// Slash record by id.
+    Record(u64),
+    /// Every slash of the identity's bond, oldest first.
+    History(Address),
+    /// When the identity's current bond was created. Unlike `bond_start`, rolling renewals do
+    /// not move it.
+    CreatedAt(Address),
+    /// Total of the identity's pending slashes, locked in the bond until they are final.
+    PendingSlash(Address),
+}
+
+/// One slash in an identity's history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashHistoryEntry {
+    pub timestamp: u64,
+    /// Amount actually slashed (after capping at the bonded amount).
+    pub amount: i128,
+    /// Id of the slash's audit record, or 0 for slashes made before every path wrote one.
+    pub record_id: u64,
+    /// Set when the slash is overturned on dispute.
+    pub overturned: bool,
+}
+
+/// Storage key for the last assigned slash record id.
+const KEY_SLASH_SEQ: &str = "slash_seq";
+/// Storage key for the slashing config.
+const KEY_SLASH_CONFIG: &str = "slash_config";
+/// Storage key for the slash warm-up window in seconds.
+const KEY_SLASH_WARMUP: &str = "slash_warmup";
 
 /// Storage key for tracking accumulated slashed funds (for treasury transfer purposes).
 /// Not currently used for fund transfers in this implementation, but reserved for future use.
+#[allow(dead_code)] // Reserved for treasury transfers of slashed funds
 const KEY_SLASHED_FUNDS_POOL: &str = "slashed_funds_pool";
 
 /// NatSpec-style: Returns the current slashed amount for a bond.
 ///
 /// # Arguments
 /// * `e` - Soroban environment
-/// * `_bond_identity` - Address of the bonded identity
+/// * `bond_identity` - Address of the bonded identity
 ///
 /// # Returns
 /// The accumulated slashed amount (i128). Returns 0 if no bond exists.
 #[must_use]
-pub fn get_slashed_amount(e: &Env, _bond_identity: &Address) -> i128 {
-    let storage_key = crate::DataKey::Bond;
-    e.storage()
-        .instance()
-        .get::<_, i128>(&storage_key)
-        .map(|_| {
-            // In a full implementation, retrieve from bond state
-            0 // Simplified: return 0
-        })
+pub fn get_slashed_amount(e: &Env, bond_identity: &Address) -> i128 {
+    crate::bond_store::load(e, bond_identity)
+        .map(|bond| bond.slashed_amount)
         .unwrap_or(0)
 }
 
@@ -49,113 +110,528 @@ pub fn get_slashed_amount(e: &Env, _bond_identity: &Address) -> i128 {
 /// * `caller` - Address to validate as admin
 ///
 /// # Panics
-/// If caller is not the stored admin address with message "not admin"
+/// If caller is not the stored admin address with `BondError::NotAdmin`
 pub fn validate_admin(e: &Env, caller: &Address) {
     let stored_admin: Address = e
         .storage()
         .instance()
         .get(&crate::DataKey::Admin)
-        .unwrap_or_else(|| panic!("not initialized"));
+        .unwrap_or_else(|| panic_with_error!(e, BondError::NotInitialized));
     if caller != &stored_admin {
-        panic!("not admin");
+        panic_with_error!(e, BondError::NotAdmin);
     }
 }
 
-/// NatSpec-style: Core slashing logic for reducing bond value.
-///
-/// Executes the slash with full validation:
-/// 1. Validates caller is admin (panics if not)
-/// 2. Calculates new slashed total
-/// 3. Caps at bonded amount (prevents over-slash)
-/// 4. Updates bond state
-/// 5. Emits slashing event
-/// 6. Returns updated bond state
+/// Slash `amount` of the identity's bond, capped at its unslashed balance less pending slashes.
+/// A `pending` slash is added to the pending total instead of `slashed_amount`. Returns the
+/// bond and the amount applied.
+fn apply_slash(
+    e: &Env,
+    admin: &Address,
+    identity: &Address,
+    amount: i128,
+    incident_at: u64,
+    pending: bool,
+) -> (crate::IdentityBond, i128) {
+    // 1. Authorization and amount checks
+    crate::roles::validate_slash_admin(e, admin);
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    crate::validation::validate_amount_conforms(e, amount);
+
+    // 2. Retrieve current bond state
+    let mut bond = crate::bond_store::get(e, identity);
+    let before = bond.clone();
+    crate::bond_store::require_bond_active(e, &bond);
+    if in_warmup(e, &bond, incident_at) {
+        panic_with_error!(e, BondError::BondInWarmup);
+    }
+
+    // 3. Cap slashing at what is neither slashed nor pending (over-slash prevention), with
+    //    overflow protection
+    let locked = bond
+        .slashed_amount
+        .checked_add(pending_amount(e, identity))
+        .filter(|locked| locked.checked_add(amount).is_some())
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    let applied = amount.min(bond.bonded_amount.saturating_sub(locked).max(0));
+
+    // 4. Lock a pending slash until it is final; otherwise persist the new slashed amount
+    if pending {
+        add_pending(e, identity, applied);
+        return (bond, applied);
+    }
+    bond.slashed_amount += applied;
+    crate::bond_store::save(e, Some(&before), &bond, "slash");
+
+    // 5. Emit slashing event for off-chain tracking
+    emit_slashing_event(e, &bond.identity, amount, bond.slashed_amount);
+
+    (bond, applied)
+}
+
+/// NatSpec-style: Slashes a bond and records an immutable audit record with a reason code.
 ///
 /// # Arguments
 /// * `e` - Soroban environment
-/// * `admin` - Address claiming admin authority
+/// * `admin` - Address claiming admin authority (recorded as the approver)
+/// * `identity` - Identity whose bond is slashed
 /// * `amount` - Amount to slash (i128)
+/// * `reason` - Reason code stored in the record
+/// * `incident_at` - Time of the incident (0 if unknown), stored in the record
+///
+/// The slash is capped at the bonded amount, so `slashed_amount` never exceeds it. With a
+/// dispute window configured the slash is pending: the bond is unchanged and the amount is
+/// locked in the pending slash total until the slash is final.
 ///
 /// # Returns
 /// Updated `IdentityBond` with modified `slashed_amount`
 ///
 /// # Panics
-/// - "not admin" if caller is not the contract admin
-/// - "not initialized" if contract not initialized
-/// - "no bond" if no bond exists for this contract instance
-/// - If arithmetic overflows (checked_add protection)
+/// - `BondError::NotAdmin` if caller is not the slash admin
+/// - `BondError::InvalidAmount` if `amount` is not positive
+/// - `BondError::NoBond` if the identity has never bonded
+/// - `BondError::BondNotActive` if the bond is inactive
+/// - `BondError::BondInWarmup` if the bond is in its warm-up window and `incident_at` does not
+///   fall after its creation
+/// - `BondError::Overflow` if arithmetic overflows
+pub fn slash_bond_with_reason(
+    e: &Env,
+    admin: &Address,
+    identity: &Address,
+    amount: i128,
+    reason: &Symbol,
+    incident_at: u64,
+) -> crate::IdentityBond {
+    let window = get_slash_config(e).slash_dispute_window_secs;
+    let (bond, applied) = apply_slash(e, admin, identity, amount, incident_at, window > 0);
+    let mut record = new_record(e, admin, &bond, amount, applied, reason, incident_at);
+    if window > 0 {
+        record.pending = true;
+        record.dispute_ends_at = record.timestamp.saturating_add(window);
+    }
+    write_new_record(e, &record);
+    bond
+}
+
+/// NatSpec-style: Writes the audit record and history entry for a slash already applied to
+/// `bond`, and emits `slash_recorded`. Every slash path calls this (or writes its record the
+/// same way), so each slash gets a record. The record is final when written.
 ///
-/// # Security Notes
-/// - Over-slash is prevented by capping at bonded_amount
-/// - Slashing is monotonic (always increases or stays same, never decreases)
-/// - Cannot slash bonds that don't exist (panic on "no bond")
-pub fn slash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond {
-    // 1. Authorization check
-    validate_admin(e, admin);
+/// # Returns
+/// The new record's id
+pub fn record_slash(
+    e: &Env,
+    admin: &Address,
+    bond: &IdentityBond,
+    requested: i128,
+    applied: i128,
+    reason: &Symbol,
+    incident_at: u64,
+) -> u64 {
+    let record = new_record(e, admin, bond, requested, applied, reason, incident_at);
+    write_new_record(e, &record);
+    record.id
+}
 
-    // 2. Retrieve current bond state
-    let key = crate::DataKey::Bond;
-    let mut bond = e
-        .storage()
+/// Build a final record for a slash of `bond` under the next record id.
+fn new_record(
+    e: &Env,
+    admin: &Address,
+    bond: &IdentityBond,
+    requested: i128,
+    applied: i128,
+    reason: &Symbol,
+    incident_at: u64,
+) -> SlashRecord {
+    let id = get_latest_slash_record_id(e)
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    e.storage()
         .instance()
-        .get::<_, crate::IdentityBond>(&key)
-        .unwrap_or_else(|| panic!("no bond"));
+        .set(&Symbol::new(e, KEY_SLASH_SEQ), &id);
 
-    // 3. Calculate new slashed amount with overflow protection
-    let new_slashed = bond
-        .slashed_amount
-        .checked_add(amount)
-        .expect("slashing caused overflow");
+    let now = e.ledger().timestamp();
+    SlashRecord {
+        id,
+        identity: bond.identity.clone(),
+        requested_amount: requested,
+        applied_amount: applied,
+        cumulative_slashed: bond.slashed_amount,
+        reason: reason.clone(),
+        admin: admin.clone(),
+        timestamp: now,
+        incident_at,
+        pending: false,
+        disputed: false,
+        dispute_ends_at: now,
+    }
+}
 
-    // 4. Cap slashing at bonded amount (over-slash prevention)
-    bond.slashed_amount = if new_slashed > bond.bonded_amount {
-        bond.bonded_amount
+fn write_new_record(e: &Env, record: &SlashRecord) {
+    save_slash_record(e, record);
+    append_history(e, &record.identity, record.applied_amount, record.id);
+
+    events::publish_or_compact(
+        e,
+        (Symbol::new(e, "slash_recorded"), record.identity.clone()),
+        (record.id, record.reason.clone()),
+        record.id,
+    );
+}
+
+/// NatSpec-style: Returns the total of the identity's pending slashes. The amount is locked in
+/// the bond (it cannot be withdrawn) but is not part of `slashed_amount` until final.
+#[must_use]
+pub fn pending_amount(e: &Env, identity: &Address) -> i128 {
+    let key = SlashDataKey::PendingSlash(identity.clone());
+    let amount = e.storage().persistent().get(&key).unwrap_or(0);
+    if amount > 0 {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+    }
+    amount
+}
+
+fn add_pending(e: &Env, identity: &Address, amount: i128) {
+    let key = SlashDataKey::PendingSlash(identity.clone());
+    let total = pending_amount(e, identity)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow));
+    if total == 0 {
+        e.storage().persistent().remove(&key);
     } else {
-        new_slashed
+        store(e, &key, &total);
+    }
+}
+
+/// Remove a final or overturned slash from the identity's pending total.
+fn release_pending(e: &Env, record: &SlashRecord) -> i128 {
+    let released = record
+        .applied_amount
+        .min(pending_amount(e, &record.identity));
+    add_pending(e, &record.identity, -released);
+    released
+}
+
+/// Move a pending slash that has become final into the bond's `slashed_amount`.
+fn commit_pending(e: &Env, record: &SlashRecord) {
+    let amount = release_pending(e, record);
+    if amount == 0 {
+        return;
+    }
+    let mut bond = crate::bond_store::get(e, &record.identity);
+    let before = bond.clone();
+    bond.slashed_amount = bond
+        .slashed_amount
+        .saturating_add(amount)
+        .min(bond.bonded_amount);
+    crate::bond_store::save(e, Some(&before), &bond, "slash");
+    emit_slashing_event(e, &record.identity, amount, bond.slashed_amount);
+}
+
+fn get_history(e: &Env, identity: &Address) -> Vec<SlashHistoryEntry> {
+    e.storage()
+        .persistent()
+        .get(&SlashDataKey::History(identity.clone()))
+        .unwrap_or(Vec::new(e))
+}
+
+/// Append a slash of `amount` to the identity's history, stamped with the ledger time.
+/// `record_id` is the slash's audit record, or 0 if it has none.
+pub fn append_history(e: &Env, identity: &Address, amount: i128, record_id: u64) {
+    let mut history = get_history(e, identity);
+    history.push_back(SlashHistoryEntry {
+        timestamp: e.ledger().timestamp(),
+        amount,
+        record_id,
+        overturned: false,
+    });
+    store(e, &SlashDataKey::History(identity.clone()), &history);
+}
+
+fn mark_overturned(e: &Env, identity: &Address, record_id: u64) {
+    let mut history = get_history(e, identity);
+    for (index, mut entry) in history.iter().enumerate() {
+        if entry.record_id == record_id {
+            entry.overturned = true;
+            history.set(index as u32, entry);
+            store(e, &SlashDataKey::History(identity.clone()), &history);
+            return;
+        }
+    }
+}
+
+/// NatSpec-style: Totals the identity's slashes with `from_ts <= timestamp <= to_ts`.
+/// Overturned slashes only add to `overturned_count`. Read-only.
+#[must_use]
+pub fn slash_summary(e: &Env, identity: &Address, from_ts: u64, to_ts: u64) -> SlashSummary {
+    let mut summary = SlashSummary {
+        count: 0,
+        total: 0,
+        record_ids: Vec::new(e),
+        overturned_count: 0,
     };
+    for entry in get_history(e, identity).iter() {
+        if entry.timestamp < from_ts || entry.timestamp > to_ts {
+            continue;
+        }
+        if entry.overturned {
+            summary.overturned_count += 1;
+            continue;
+        }
+        summary.count += 1;
+        summary.total = summary.total.saturating_add(entry.amount);
+        if entry.record_id != 0 {
+            summary.record_ids.push_back(entry.record_id);
+        }
+    }
+    summary
+}
 
-    // 5. Persist updated bond state
-    e.storage().instance().set(&key, &bond);
+/// NatSpec-style: Returns the id of the most recent slash record (0 if none).
+#[must_use]
+pub fn get_latest_slash_record_id(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_SLASH_SEQ))
+        .unwrap_or(0)
+}
 
-    // 6. Emit slashing event for off-chain tracking
-    emit_slashing_event(e, &bond.identity, amount, bond.slashed_amount);
+/// NatSpec-style: Returns the slash record with the given id.
+///
+/// # Panics
+/// - `BondError::SlashRecordNotFound` if no record has that id
+#[must_use]
+pub fn get_slash_record(e: &Env, id: u64) -> SlashRecord {
+    e.storage()
+        .persistent()
+        .get(&SlashDataKey::Record(id))
+        .unwrap_or_else(|| panic_with_error!(e, BondError::SlashRecordNotFound))
+}
 
-    // 7. Return updated bond state
-    bond
+/// NatSpec-style: Returns up to `limit` of the identity's slash records from index `start`,
+/// oldest first, found through its slash history. Read-only.
+#[must_use]
+pub fn get_records_for_identity(
+    e: &Env,
+    identity: &Address,
+    start: u32,
+    limit: u32,
+) -> Vec<SlashRecord> {
+    let mut page = Vec::new(e);
+    let mut index: u32 = 0;
+    for entry in get_history(e, identity).iter() {
+        if entry.record_id == 0 {
+            continue;
+        }
+        if index >= start {
+            if page.len() >= limit {
+                break;
+            }
+            page.push_back(get_slash_record(e, entry.record_id));
+        }
+        index += 1;
+    }
+    page
+}
+
+/// NatSpec-style: Returns the slashing config (zero dispute window if never set).
+#[must_use]
+pub fn get_slash_config(e: &Env) -> SlashConfig {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_SLASH_CONFIG))
+        .unwrap_or(SlashConfig {
+            slash_dispute_window_secs: 0,
+        })
+}
+
+/// NatSpec-style: Sets the slashing config. Only admin should call (enforced by caller).
+/// Applies to slash records written afterwards.
+pub fn set_slash_config(e: &Env, config: &SlashConfig) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_SLASH_CONFIG), config);
+}
+
+/// NatSpec-style: Returns the slash warm-up window in seconds (0 if never set).
+#[must_use]
+pub fn get_warmup(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_SLASH_WARMUP))
+        .unwrap_or(0)
+}
+
+/// NatSpec-style: Sets the slash warm-up window. Only admin should call (enforced by caller).
+pub fn set_warmup(e: &Env, seconds: u64) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_SLASH_WARMUP), &seconds);
+}
+
+/// Record the creation time of a bond that has just become active.
+pub fn track_creation(e: &Env, before: Option<&IdentityBond>, bond: &IdentityBond) {
+    if bond.active && !before.is_some_and(|b| b.active) {
+        store(
+            e,
+            &SlashDataKey::CreatedAt(bond.identity.clone()),
+            &bond.bond_start,
+        );
+    }
+}
+
+/// NatSpec-style: True if the bond was created less than the warm-up window ago and
+/// `incident_at` is not after its creation. An `incident_at` in the future does not count.
+/// Always false when no window is set.
+#[must_use]
+pub fn in_warmup(e: &Env, bond: &IdentityBond, incident_at: u64) -> bool {
+    let warmup = get_warmup(e);
+    if warmup == 0 {
+        return false;
+    }
+    let created_at = created_at(e, bond);
+    let now = e.ledger().timestamp();
+    now < created_at.saturating_add(warmup) && !(incident_at > created_at && incident_at <= now)
+}
+
+/// When the identity's current bond was created (its `bond_start` if never tracked).
+fn created_at(e: &Env, bond: &IdentityBond) -> u64 {
+    e.storage()
+        .persistent()
+        .get(&SlashDataKey::CreatedAt(bond.identity.clone()))
+        .unwrap_or(bond.bond_start)
+}
+
+/// Write a slash entry to persistent storage with a fresh TTL.
+fn store<V: IntoVal<Env, Val>>(e: &Env, key: &SlashDataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, BUMP_THRESHOLD, BUMP_TARGET);
+}
+
+fn save_slash_record(e: &Env, record: &SlashRecord) {
+    store(e, &SlashDataKey::Record(record.id), record);
+}
+
+fn get_pending_slash_record(e: &Env, id: u64) -> SlashRecord {
+    let record = get_slash_record(e, id);
+    if !record.pending {
+        panic_with_error!(e, SlashDisputeError::NotPending);
+    }
+    record
+}
+
+/// NatSpec-style: Marks a pending slash as disputed. Requires the slashed identity's auth.
+///
+/// # Panics
+/// - `SlashDisputeError::NotPending`, `AlreadyDisputed` or `WindowClosed`
+pub fn dispute_slash(e: &Env, id: u64) -> SlashRecord {
+    let mut record = get_pending_slash_record(e, id);
+    record.identity.require_auth();
+    if record.disputed {
+        panic_with_error!(e, SlashDisputeError::AlreadyDisputed);
+    }
+    if e.ledger().timestamp() >= record.dispute_ends_at {
+        panic_with_error!(e, SlashDisputeError::WindowClosed);
+    }
+    record.disputed = true;
+    save_slash_record(e, &record);
+    events::publish(
+        e,
+        (Symbol::new(e, "slash_disputed"),),
+        (id, record.identity.clone()),
+    );
+    record
+}
+
+/// NatSpec-style: Decides a disputed slash. An upheld slash moves into the bond's
+/// `slashed_amount`; an overturned one is released from the pending total, so the amount is
+/// withdrawable again.
+///
+/// # Panics
+/// - `BondError::NotAdmin` if not authorized
+/// - `SlashDisputeError::NotPending` or `NotDisputed`
+pub fn adjudicate_slash(e: &Env, admin: &Address, id: u64, upheld: bool) -> SlashRecord {
+    validate_admin(e, admin);
+    let mut record = get_pending_slash_record(e, id);
+    if !record.disputed {
+        panic_with_error!(e, SlashDisputeError::NotDisputed);
+    }
+    record.pending = false;
+    save_slash_record(e, &record);
+    if upheld {
+        commit_pending(e, &record);
+    } else {
+        mark_overturned(e, &record.identity, id);
+        release_pending(e, &record);
+    }
+    events::publish(e, (Symbol::new(e, "slash_adjudicated"),), (id, upheld));
+    record
+}
+
+/// NatSpec-style: Makes an undisputed slash final once its dispute window has ended.
+///
+/// # Panics
+/// - `SlashDisputeError::NotPending`, `UnderDispute` or `WindowOpen`
+pub fn finalize_slash(e: &Env, id: u64) -> SlashRecord {
+    let mut record = get_pending_slash_record(e, id);
+    if record.disputed {
+        panic_with_error!(e, SlashDisputeError::UnderDispute);
+    }
+    if e.ledger().timestamp() < record.dispute_ends_at {
+        panic_with_error!(e, SlashDisputeError::WindowOpen);
+    }
+    record.pending = false;
+    save_slash_record(e, &record);
+    commit_pending(e, &record);
+    events::publish(e, (Symbol::new(e, "slash_finalized"),), id);
+    record
 }
 
 /// NatSpec-style: Reverts slashing (reduces slashed amount). Admin only.
 ///
-/// Used for correcting mistaken slashes or appeals.
-/// Only reduces slashed_amount, cannot go below 0.
+/// Used for correcting mistaken slashes or appeals. Slashed funds never leave the contract,
+/// so the recovered amount becomes withdrawable again.
 ///
 /// # Arguments
 /// * `e` - Soroban environment
-/// * `admin` - Address claiming admin authority  
+/// * `admin` - Address claiming admin authority
+/// * `identity` - Identity whose bond is unslashed
 /// * `amount` - Amount to unslash (i128)
 ///
 /// # Returns
 /// Updated bond with reduced slashed_amount
 ///
 /// # Panics
-/// - "not admin" if not authorized
-/// - If amount would reduce slashed_amount below 0
-pub fn unslash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond {
+/// - `BondError::NotAdmin` if not authorized
+/// - `BondError::InvalidAmount` if `amount` is not positive
+/// - `BondError::BondNotActive` if the bond is no longer active
+/// - `BondError::RecoveryExceedsSlashed` if `amount` exceeds the bond's slashed amount
+pub fn unslash_bond(
+    e: &Env,
+    admin: &Address,
+    identity: &Address,
+    amount: i128,
+) -> crate::IdentityBond {
     validate_admin(e, admin);
+    if amount <= 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
 
-    let key = crate::DataKey::Bond;
-    let mut bond = e
-        .storage()
-        .instance()
-        .get::<_, crate::IdentityBond>(&key)
-        .unwrap_or_else(|| panic!("no bond"));
-
-    bond.slashed_amount = bond
-        .slashed_amount
-        .checked_sub(amount)
-        .expect("unslashing would reduce below 0");
+    let mut bond = crate::bond_store::get(e, identity);
+    let before = bond.clone();
+    crate::bond_store::require_bond_active(e, &bond);
+    if amount > bond.slashed_amount {
+        panic_with_error!(e, BondError::RecoveryExceedsSlashed);
+    }
+    bond.slashed_amount -= amount;
 
-    e.storage().instance().set(&key, &bond);
+    crate::bond_store::save(e, Some(&before), &bond, "unslash");
     emit_unslashing_event(e, &bond.identity, amount, bond.slashed_amount);
 
     bond
@@ -169,11 +645,15 @@ pub fn unslash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBo
 ///
 /// # Returns
 /// Available balance = bonded_amount - slashed_amount
+///
+/// # Panics
+/// * `BondError::Overflow` if the subtraction overflows
 #[must_use]
-pub fn get_available_balance(bonded_amount: i128, slashed_amount: i128) -> i128 {
+#[allow(dead_code)] // Public API for off-chain / frontends
+pub fn get_available_balance(e: &Env, bonded_amount: i128, slashed_amount: i128) -> i128 {
     bonded_amount
         .checked_sub(slashed_amount)
-        .expect("slashed amount exceeds bonded amount")
+        .unwrap_or_else(|| panic_with_error!(e, BondError::Overflow))
 }
 
 /// NatSpec-style: Checks if a bond is fully slashed.
@@ -188,6 +668,7 @@ pub fn get_available_balance(bonded_amount: i128, slashed_amount: i128) -> i128
 /// # Returns
 /// `true` if fully slashed, `false` otherwise
 #[must_use]
+#[allow(dead_code)] // Public API for off-chain / frontends
 pub fn is_fully_slashed(bonded_amount: i128, slashed_amount: i128) -> bool {
     slashed_amount >= bonded_amount
 }
@@ -204,6 +685,7 @@ pub fn is_fully_slashed(bonded_amount: i128, slashed_amount: i128) -> bool {
 /// # Returns
 /// `true` if this is a partial slash, `false` if full slash
 #[must_use]
+#[allow(dead_code)] // Public API for off-chain / frontends
 pub fn is_partial_slash(slash_amount: i128, bonded_amount: i128) -> bool {
     slash_amount < bonded_amount
 }
@@ -216,7 +698,8 @@ pub fn is_partial_slash(slash_amount: i128, bonded_amount: i128) -> bool {
 /// * `slash_amount` - The amount just slashed
 /// * `total_slashed` - The cumulative slashed amount after this slash
 pub fn emit_slashing_event(e: &Env, identity: &Address, slash_amount: i128, total_slashed: i128) {
-    e.events().publish(
+    events::publish(
+        e,
         (Symbol::new(e, "bond_slashed"),),
         (identity.clone(), slash_amount, total_slashed),
     );
@@ -235,14 +718,16 @@ pub fn emit_unslashing_event(
     unslash_amount: i128,
     total_slashed: i128,
 ) {
-    e.events().publish(
-        (Symbol::new(e, "bond_unslashed"),),
+    events::publish(
+        e,
+        (Symbol::new(e, "slash_recovered"),),
         (identity.clone(), unslash_amount, total_slashed),
     );
 }
 
 /// Initialize the slashed funds pool for treasury transfers.
 /// Called during contract initialization.
+#[allow(dead_code)] // Reserved for treasury transfers of slashed funds
 pub fn initialize_slashed_pool(e: &Env) {
     e.storage()
         .instance()
@@ -255,9 +740,17 @@ mod tests {
 
     #[test]
     fn test_available_balance_calculation() {
-        assert_eq!(get_available_balance(1000, 300), 700);
-        assert_eq!(get_available_balance(1000, 0), 1000);
-        assert_eq!(get_available_balance(1000, 1000), 0);
+        let e = Env::default();
+        assert_eq!(get_available_balance(&e, 1000, 300), 700);
+        assert_eq!(get_available_balance(&e, 1000, 0), 1000);
+        assert_eq!(get_available_balance(&e, 1000, 1000), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Error(Contract, #6)")]
+    fn test_available_balance_overflow() {
+        let e = Env::default();
+        let _ = get_available_balance(&e, i128::MIN, 1);
     }
 
     #[test]
@@ -278,11 +771,12 @@ mod tests {
     #[test]
     fn test_available_balance_with_slashing() {
         // Verify available balance calculation
-        let available = get_available_balance(1000, 300);
+        let e = Env::default();
+        let available = get_available_balance(&e, 1000, 300);
         assert_eq!(available, 700);
 
         // Fully slashed
-        let available_full = get_available_balance(1000, 1000);
+        let available_full = get_available_balance(&e, 1000, 1000);
         assert_eq!(available_full, 0);
     }
 }
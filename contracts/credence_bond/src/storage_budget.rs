@@ -0,0 +1,77 @@
+//! Instance Storage Budget
+//!
+//! Instance storage is a single ledger entry with a hard size limit, so unbounded growth of
+//! non-critical data (attestation metadata) could eventually make every
+//! call that touches the instance fail — including withdrawals and slashes. This module keeps
+//! an approximate byte count of the non-critical entries and refuses new non-critical writes
+//! once a configurable soft limit is reached. Critical paths never consult the budget.
+//!
+//! Sizes are estimates (fixed per-record overhead plus variable payload), not exact XDR sizes;
+//! the default soft limit leaves ample headroom below the ledger entry limit.
+
+use soroban_sdk::{panic_with_error, Env, Symbol};
+
+use crate::BondError;
+
+/// Storage key for the approximate bytes used by non-critical instance entries.
+const KEY_USAGE: &str = "storage_usage";
+/// Storage key for the configured soft limit.
+const KEY_SOFT_LIMIT: &str = "storage_soft_limit";
+
+/// Default soft limit in bytes (well below the 64 KiB ledger entry limit).
+pub const DEFAULT_SOFT_LIMIT_BYTES: u32 = 32 * 1024;
+
+/// Approximate size of one attestation record plus its dedup and index entries,
+/// excluding the attestation data payload.
+pub const ATTESTATION_BASE_BYTES: u32 = 256;
+
+/// Returns the approximate bytes used by non-critical instance entries.
+#[must_use]
+pub fn get_usage(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_USAGE))
+        .unwrap_or(0)
+}
+
+/// Returns the configured soft limit in bytes.
+#[must_use]
+pub fn get_soft_limit(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_SOFT_LIMIT))
+        .unwrap_or(DEFAULT_SOFT_LIMIT_BYTES)
+}
+
+/// Set the soft limit. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * If limit is zero
+pub fn set_soft_limit(e: &Env, limit: u32) {
+    if limit == 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_SOFT_LIMIT), &limit);
+}
+
+/// Returns true if `bytes` more non-critical data fits under the soft limit.
+#[must_use]
+pub fn has_room(e: &Env, bytes: u32) -> bool {
+    get_usage(e).saturating_add(bytes) <= get_soft_limit(e)
+}
+
+/// Charge `bytes` of non-critical data against the budget.
+///
+/// # Panics
+/// * If the write would exceed the soft limit (`BondError::StorageBudgetExceeded`)
+pub fn charge(e: &Env, bytes: u32) {
+    if !has_room(e, bytes) {
+        panic_with_error!(e, BondError::StorageBudgetExceeded);
+    }
+    e.storage().instance().set(
+        &Symbol::new(e, KEY_USAGE),
+        &get_usage(e).saturating_add(bytes),
+    );
+}
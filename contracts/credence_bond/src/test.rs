@@ -1,24 +1,355 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::Env;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{symbol_short, Env};
 
 #[test]
 fn test_create_bond() {
     let e = Env::default();
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
     client.initialize(&admin);
 
     let identity = Address::generate(&e);
-    let bond = client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+    let bond = client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
 
     assert!(bond.active);
-    assert_eq!(bond.bonded_amount, 1000_i128);
+    assert_eq!(bond.bonded_amount, 1_000_000_000_i128);
     assert_eq!(bond.slashed_amount, 0);
     assert_eq!(bond.identity, identity);
 }
+
+fn setup_bond(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &0_u64);
+    (client, identity)
+}
+
+#[test]
+fn test_bond_operations_require_identity_auth() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+
+    client.top_up(&identity, &1_000_000);
+    assert_eq!(e.auths()[0].0, identity);
+    client.extend_duration(&identity, &10_u64);
+    assert_eq!(e.auths()[0].0, identity);
+    client.request_withdrawal(&identity);
+    assert_eq!(e.auths()[0].0, identity);
+    client.withdraw(&identity, &1_000_000);
+    assert_eq!(e.auths()[0].0, identity);
+}
+
+#[test]
+fn test_bond_operations_rejected_without_auth() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    e.set_auths(&[]);
+
+    let other = Address::generate(&e);
+    assert!(client
+        .try_create_bond(&other, &1_000_000_000_i128, &86400_u64, &false, &0_u64)
+        .is_err());
+    assert!(client.try_top_up(&identity, &1_000_000).is_err());
+    assert!(client.try_extend_duration(&identity, &10_u64).is_err());
+    assert!(client.try_request_withdrawal(&identity).is_err());
+    assert!(client.try_withdraw(&identity, &1_000_000).is_err());
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        1_000_000_000
+    );
+}
+
+#[test]
+fn test_errors_are_typed() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    let stranger = Address::generate(&e);
+
+    assert_eq!(
+        client.try_get_identity_state(&stranger).err(),
+        Some(Ok(BondError::NoBond.into()))
+    );
+    assert_eq!(
+        client.try_set_strict_amounts(&stranger, &true).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client.try_cancel_withdrawal_request(&identity).err(),
+        Some(Ok(BondError::NoPendingWithdrawal.into()))
+    );
+    assert_eq!(
+        client.try_withdraw(&identity, &1_000_000).err(),
+        Some(Ok(BondError::WithdrawalNotRequested.into()))
+    );
+    client.request_withdrawal(&identity);
+    assert_eq!(
+        client.try_withdraw(&identity, &2_000_000_000).err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_full_withdraw_deactivates_bond() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    client.request_withdrawal(&identity);
+    let bond = client.withdraw(&identity, &1_000_000_000);
+    assert_eq!(bond.bonded_amount, 0);
+    assert!(!bond.active);
+}
+
+#[test]
+fn test_partial_withdraw_keeps_bond_active() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    client.request_withdrawal(&identity);
+    let bond = client.withdraw(&identity, &1_000_000);
+    assert!(bond.active);
+}
+
+#[test]
+fn test_withdraw_before_lockup_end_rejected() {
+    let e = Env::default();
+    let (client, _identity) = setup_bond(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86399);
+    let result = client.try_withdraw(&identity, &1_000_000);
+    assert_eq!(result.err(), Some(Ok(BondError::LockupNotEnded.into())));
+
+    // Maturity is inclusive
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86400);
+    let bond = client.withdraw(&identity, &1_000_000);
+    assert_eq!(bond.bonded_amount, 999_000_000);
+}
+
+#[test]
+fn test_withdraw_bond_before_lockup_end_rejected() {
+    let e = Env::default();
+    let (client, rolling) = setup_bond(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(
+        &identity,
+        &1_000_000_000_i128,
+        &1_000_000_u64,
+        &false,
+        &0_u64,
+    );
+
+    let result = client.try_withdraw_bond(&identity);
+    assert_eq!(result.err(), Some(Ok(BondError::LockupNotEnded.into())));
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 999_999);
+    let result = client.try_withdraw_bond(&identity);
+    assert_eq!(result.err(), Some(Ok(BondError::LockupNotEnded.into())));
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        1_000_000_000
+    );
+
+    // Rolling bonds still need a withdrawal request first
+    assert_eq!(
+        client.try_withdraw_bond(&rolling).err(),
+        Some(Ok(BondError::WithdrawalNotRequested.into()))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 1_000_000);
+    assert_eq!(client.withdraw_bond(&identity), 1_000_000_000);
+}
+
+#[test]
+fn test_get_bond_maturity() {
+    let e = Env::default();
+    let (client, rolling) = setup_bond(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    let maturity = client.get_bond_maturity(&identity);
+    assert_eq!(maturity, 1000 + 86400);
+
+    // One second before maturity withdraw is refused; one second after it succeeds.
+    e.ledger().with_mut(|li| li.timestamp = maturity - 1);
+    assert!(client.try_withdraw(&identity, &1_000_000).is_err());
+    e.ledger().with_mut(|li| li.timestamp = maturity + 1);
+    client.withdraw(&identity, &1_000_000);
+
+    let result = client.try_get_bond_maturity(&rolling);
+    assert_eq!(result.err(), Some(Ok(BondError::NoFixedMaturity.into())));
+}
+
+#[test]
+fn test_withdraw_rolling_requires_elapsed_notice() {
+    let e = Env::default();
+    let (client, _identity) = setup_bond(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &true, &3600_u64);
+
+    let result = client.try_withdraw(&identity, &1_000_000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(BondError::WithdrawalNotRequested.into()))
+    );
+    client.request_withdrawal(&identity);
+    let result = client.try_withdraw(&identity, &1_000_000);
+    assert_eq!(
+        result.err(),
+        Some(Ok(BondError::NoticePeriodNotElapsed.into()))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 3600);
+    let bond = client.withdraw(&identity, &1_000_000);
+    assert_eq!(bond.bonded_amount, 999_000_000);
+}
+
+#[test]
+fn test_inactive_bond_rejects_mutations() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    client.request_withdrawal(&identity); // rolling, zero notice
+    client.withdraw_bond(&identity);
+    let inactive = Some(Ok(BondError::BondNotActive.into()));
+
+    assert_eq!(client.try_withdraw(&identity, &1_000_000).err(), inactive);
+    assert_eq!(client.try_top_up(&identity, &1_000_000).err(), inactive);
+    assert_eq!(client.try_extend_duration(&identity, &10).err(), inactive);
+    assert_eq!(client.try_request_withdrawal(&identity).err(), inactive);
+    assert_eq!(client.try_withdraw_bond(&identity).err(), inactive);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_slash_inactive_bond_rejected() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 86400);
+    client.withdraw_bond(&identity);
+    client.slash(&admin, &identity, &1_000_000, &symbol_short!("test"), &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #36)")]
+fn test_create_bond_refuses_to_overwrite_active_bond() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+fn test_create_bond_allowed_after_full_withdrawal() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    client.request_withdrawal(&identity);
+    client.withdraw(&identity, &1_000_000_000);
+
+    let bond = client.create_bond(&identity, &2_000_000_000_i128, &86400_u64, &false, &0_u64);
+    assert!(bond.active);
+    assert_eq!(bond.bonded_amount, 2_000_000_000);
+    assert_eq!(client.get_bond_identity_count(), 1);
+}
+
+#[test]
+fn test_close_bond_with_balance_rejected() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    assert_eq!(
+        client.try_close_bond(&identity).err(),
+        Some(Ok(BondError::BondNotEmpty.into()))
+    );
+    assert!(client.get_identity_state(&identity).active);
+}
+
+#[test]
+fn test_close_fully_slashed_bond() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    let admin = client.get_admin();
+    client.slash(
+        &admin,
+        &identity,
+        &1_000_000_000,
+        &symbol_short!("test"),
+        &0,
+    );
+
+    let bond = client.close_bond(&identity);
+    let data: (Address, u64) = event_data(&e, "bond_closed").into_val(&e);
+    assert_eq!(data, (identity.clone(), 1000));
+    assert!(!bond.active);
+    assert!(client.try_close_bond(&identity).is_err());
+
+    // A closed identity can bond again
+    let bond = client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    assert!(bond.active);
+    assert_eq!(bond.slashed_amount, 0);
+}
+
+/// Data of the most recent event published under `name`.
+fn event_data(e: &Env, name: &str) -> Val {
+    let topics: soroban_sdk::Vec<Val> = (Symbol::new(e, name),).into_val(e);
+    e.events()
+        .all()
+        .iter()
+        .filter(|(_, t, _)| *t == topics)
+        .map(|(_, _, data)| data)
+        .last()
+        .expect("event not published")
+}
+
+#[test]
+fn test_create_bond_emits_bond_created() {
+    let e = Env::default();
+    let (client, _identity) = setup_bond(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &6_000_000_000_i128, &86400_u64, &true, &3600_u64);
+
+    let data: (Address, i128, u64, u64, bool, u64, BondTier) =
+        event_data(&e, "bond_created").into_val(&e);
+    assert_eq!(
+        data,
+        (
+            identity,
+            6_000_000_000,
+            1000,
+            86400,
+            true,
+            3600,
+            BondTier::Gold
+        )
+    );
+}
+
+#[test]
+fn test_top_up_emits_bond_topped_up() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    client.top_up(&identity, &5_000_000);
+
+    let data: (Address, i128, i128) = event_data(&e, "bond_topped_up").into_val(&e);
+    assert_eq!(data, (identity, 5_000_000, 1_005_000_000));
+}
+
+#[test]
+fn test_extend_duration_emits_bond_extended() {
+    let e = Env::default();
+    let (client, identity) = setup_bond(&e);
+    client.extend_duration(&identity, &600_u64);
+
+    let data: (Address, u64, u64) = event_data(&e, "bond_extended").into_val(&e);
+    assert_eq!(data, (identity, 600, 87000));
+}
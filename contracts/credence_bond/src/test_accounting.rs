@@ -0,0 +1,196 @@
+//! Tests for the paged accounting self-check and the total bonded repair.
+
+#![cfg(test)]
+
+use crate::{
+    AccountingCheck, AccountingDiscrepancy, BondError, CredenceBond, CredenceBondClient,
+    VerificationReport,
+};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+
+fn setup(e: &Env, bonds: u32) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    for _ in 0..bonds {
+        let identity = Address::generate(e);
+        client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    }
+    (client, admin)
+}
+
+/// Run a full verification in pages of `limit` and return the final report.
+fn verify_all(client: &CredenceBondClient, limit: u32) -> VerificationReport {
+    let mut cursor = 0;
+    loop {
+        let report = client.verify_accounting(&cursor, &limit);
+        if report.complete {
+            return report;
+        }
+        assert!(report.discrepancies.is_empty());
+        cursor = report.next_cursor;
+    }
+}
+
+fn corrupt_total_bonded(e: &Env, client: &CredenceBondClient, total: i128) {
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(e, "total_bonded"), &total);
+    });
+}
+
+#[test]
+fn test_clean_report() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e, 5);
+    let report = verify_all(&client, 2);
+    assert!(report.complete);
+    assert!(report.discrepancies.is_empty());
+    assert_eq!(report.next_cursor, 5);
+}
+
+#[test]
+fn test_withdraw_bond_after_slash_stays_consistent() {
+    let e = Env::default();
+    let (client, admin) = setup(&e, 0);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    client.slash(
+        &admin,
+        &identity,
+        &(BOND_AMOUNT / 4),
+        &symbol_short!("test"),
+        &0,
+    );
+    e.ledger().with_mut(|li| li.timestamp = 86400);
+    client.withdraw_bond(&identity);
+
+    let bond = client.get_identity_state(&identity);
+    assert!(!bond.active);
+    assert_eq!(bond.bonded_amount, bond.slashed_amount);
+    assert!(verify_all(&client, 10).discrepancies.is_empty());
+}
+
+#[test]
+fn test_pages_scan_in_order() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e, 5);
+    let first = client.verify_accounting(&0, &2);
+    assert_eq!(
+        (first.scanned, first.next_cursor, first.complete),
+        (2, 2, false)
+    );
+    let second = client.verify_accounting(&2, &2);
+    assert_eq!((second.scanned, second.next_cursor), (2, 4));
+    let last = client.verify_accounting(&4, &2);
+    assert_eq!(last.scanned, 1);
+    assert!(last.complete);
+}
+
+#[test]
+fn test_empty_contract_completes_immediately() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e, 0);
+    let report = client.verify_accounting(&0, &10);
+    assert!(report.complete);
+    assert_eq!(report.scanned, 0);
+}
+
+#[test]
+fn test_out_of_order_cursor_rejected() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e, 5);
+    client.verify_accounting(&0, &2);
+    assert_eq!(
+        client.try_verify_accounting(&3, &2).err(),
+        Some(Ok(BondError::VerificationOutOfOrder.into()))
+    );
+    // Restarting from 0 is always allowed
+    assert_eq!(client.verify_accounting(&0, &2).next_cursor, 2);
+}
+
+#[test]
+fn test_corrupted_total_reported_and_repaired() {
+    let e = Env::default();
+    let (client, admin) = setup(&e, 3);
+    corrupt_total_bonded(&e, &client, 3 * BOND_AMOUNT + 7);
+
+    let report = verify_all(&client, 2);
+    assert_eq!(report.discrepancies.len(), 1);
+    assert_eq!(
+        report.discrepancies.get(0).unwrap(),
+        AccountingDiscrepancy {
+            check: AccountingCheck::TotalBonded,
+            expected: 3 * BOND_AMOUNT,
+            actual: 3 * BOND_AMOUNT + 7,
+        }
+    );
+
+    // A bond write after the run keeps the drift valid
+    let late = Address::generate(&e);
+    client.create_bond(&late, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    assert_eq!(client.repair_total_bonded(&admin), 4 * BOND_AMOUNT);
+    assert_eq!(client.get_total_bonded(), 4 * BOND_AMOUNT);
+
+    assert!(verify_all(&client, 2).discrepancies.is_empty());
+    assert_eq!(
+        client.try_repair_total_bonded(&admin).err(),
+        Some(Ok(BondError::NoAccountingDrift.into()))
+    );
+}
+
+#[test]
+fn test_over_slashed_bond_reported() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e, 0);
+    let identity = Address::generate(&e);
+    let mut bond = client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    bond.slashed_amount = BOND_AMOUNT + 1;
+    e.as_contract(&client.address, || {
+        e.storage()
+            .persistent()
+            .set(&crate::DataKey::Bond(identity.clone()), &bond);
+    });
+
+    let report = verify_all(&client, 10);
+    assert_eq!(
+        report.discrepancies.get(0).unwrap().check,
+        AccountingCheck::BondSlashed(identity)
+    );
+}
+
+#[test]
+fn test_insurance_pool_mismatch_reported() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e, 1);
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "insurance_pool"), &5_i128);
+    });
+    let report = verify_all(&client, 10);
+    assert_eq!(
+        report.discrepancies.get(0).unwrap(),
+        AccountingDiscrepancy {
+            check: AccountingCheck::InsurancePool,
+            expected: 0,
+            actual: 5,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_repair_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e, 1);
+    corrupt_total_bonded(&e, &client, 0);
+    verify_all(&client, 10);
+    client.repair_total_bonded(&Address::generate(&e));
+}
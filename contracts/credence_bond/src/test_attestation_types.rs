@@ -9,21 +9,24 @@ use soroban_sdk::{Env, String};
 
 #[test]
 fn attestation_weight_validation_accepts_valid() {
-    Attestation::validate_weight(1);
-    Attestation::validate_weight(100);
-    Attestation::validate_weight(MAX_ATTESTATION_WEIGHT);
+    let e = Env::default();
+    Attestation::validate_weight(&e, 1);
+    Attestation::validate_weight(&e, 100);
+    Attestation::validate_weight(&e, MAX_ATTESTATION_WEIGHT);
 }
 
 #[test]
-#[should_panic(expected = "attestation weight must be positive")]
+#[should_panic(expected = "Error(Contract, #1100)")]
 fn attestation_weight_validation_rejects_zero() {
-    Attestation::validate_weight(0);
+    let e = Env::default();
+    Attestation::validate_weight(&e, 0);
 }
 
 #[test]
-#[should_panic(expected = "attestation weight exceeds maximum")]
+#[should_panic(expected = "Error(Contract, #1101)")]
 fn attestation_weight_validation_rejects_over_max() {
-    Attestation::validate_weight(MAX_ATTESTATION_WEIGHT + 1);
+    let e = Env::default();
+    Attestation::validate_weight(&e, MAX_ATTESTATION_WEIGHT + 1);
 }
 
 #[test]
@@ -0,0 +1,48 @@
+//! Budget regression tests for the batch code paths. Bounds sit about 25% above the figures
+//! measured when they were set; a change that pushes a batch (or a configured page limit)
+//! past them fails here instead of on chain.
+
+#![cfg(test)]
+
+use crate::accounting::MAX_VERIFY_PAGE;
+use crate::bench;
+use soroban_sdk::Env;
+
+#[test]
+fn test_batch_slash_20_within_budget() {
+    let e = Env::default();
+    let report = bench::bench_batch_slash(&e, 20);
+    assert!(report.instructions < 18_900_000, "{report:?}");
+    assert!(report.mem_bytes < 4_200_000, "{report:?}");
+    // At most the bond, its slash record and its slash history per bond, plus contract-level
+    // state
+    assert!(report.write_entries <= 3 * 20 + 2, "{report:?}");
+}
+
+#[test]
+fn test_verify_accounting_full_page_within_budget() {
+    let e = Env::default();
+    let report = bench::bench_verify_accounting(&e, MAX_VERIFY_PAGE, MAX_VERIFY_PAGE);
+    assert!(report.instructions < 5_600_000, "{report:?}");
+    assert!(report.mem_bytes < 1_000_000, "{report:?}");
+    // The index entry and the bond per identity, plus the instance
+    assert!(report.read_entries <= 2 * MAX_VERIFY_PAGE + 1, "{report:?}");
+}
+
+#[test]
+fn test_verify_accounting_page_capped() {
+    // Asking for more than the configured page scans no more than a full page
+    let e = Env::default();
+    let report = bench::bench_verify_accounting(&e, 2 * MAX_VERIFY_PAGE, 2 * MAX_VERIFY_PAGE);
+    assert!(report.read_entries <= 2 * MAX_VERIFY_PAGE + 1, "{report:?}");
+}
+
+#[test]
+fn test_pagination_page_of_50_within_budget() {
+    let e = Env::default();
+    let report = bench::bench_pagination(&e, 100, 50);
+    assert!(report.instructions < 6_200_000, "{report:?}");
+    assert!(report.mem_bytes < 1_100_000, "{report:?}");
+    assert!(report.read_entries <= 50 + 1, "{report:?}");
+    assert_eq!(report.write_entries, 0, "{report:?}");
+}
@@ -0,0 +1,155 @@
+//! Tests for bond delegates: nomination, delegate top-ups and extensions, and the limits on
+//! what a delegate can do.
+
+#![cfg(test)]
+
+use crate::{CredenceBond, CredenceBondClient, DelegateError};
+use soroban_sdk::testutils::{Address as _, Events, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+const TOP_UP: i128 = 100_000_000;
+const DURATION: u64 = 86400;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    token: TokenClient<'a>,
+    identity: Address,
+    delegate: Address,
+}
+
+/// Funded identity and delegate; the identity has a bond and has nominated the delegate.
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    let identity = Address::generate(e);
+    let delegate = Address::generate(e);
+    let asset = StellarAssetClient::new(e, &sac.address());
+    asset.mint(&identity, &BOND_AMOUNT);
+    asset.mint(&delegate, &TOP_UP);
+    client.set_token(&admin, &sac.address());
+
+    client.create_bond(&identity, &BOND_AMOUNT, &DURATION, &false, &0_u64);
+    client.set_delegate(&identity, &delegate);
+    Setup {
+        client,
+        token: TokenClient::new(e, &sac.address()),
+        identity,
+        delegate,
+    }
+}
+
+#[test]
+fn test_set_and_revoke_delegate() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert_eq!(s.client.get_delegate(&s.identity), Some(s.delegate.clone()));
+
+    s.client.revoke_delegate(&s.identity);
+    let events = e.events().all();
+    let revoked = events
+        .iter()
+        .find(|(_, topics, _)| *topics == (Symbol::new(&e, "delegate_revoked"),).into_val(&e));
+    let data: (Address, Address) = revoked.unwrap().2.into_val(&e);
+    assert_eq!(data, (s.identity.clone(), s.delegate.clone()));
+    assert_eq!(s.client.get_delegate(&s.identity), None);
+}
+
+#[test]
+fn test_self_delegation_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert_eq!(
+        s.client.try_set_delegate(&s.identity, &s.identity).err(),
+        Some(Ok(DelegateError::SelfDelegation.into()))
+    );
+}
+
+#[test]
+fn test_delegate_top_up_paid_by_delegate() {
+    let e = Env::default();
+    let s = setup(&e);
+    let bond = s.client.top_up_as(&s.delegate, &s.identity, &TOP_UP);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT + TOP_UP);
+    assert_eq!(s.token.balance(&s.delegate), 0);
+    assert_eq!(s.token.balance(&s.identity), 0);
+    assert_eq!(s.token.balance(&s.client.address), BOND_AMOUNT + TOP_UP);
+}
+
+#[test]
+fn test_delegate_extends_with_only_its_own_auth() {
+    let e = Env::default();
+    let s = setup(&e);
+    e.mock_auths(&[MockAuth {
+        address: &s.delegate,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "extend_duration_as",
+            args: (s.delegate.clone(), s.identity.clone(), 3600_u64).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    let bond = s.client.extend_duration_as(&s.delegate, &s.identity, &3600);
+    assert_eq!(bond.bond_duration, DURATION + 3600);
+}
+
+#[test]
+fn test_stranger_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        s.client
+            .try_top_up_as(&stranger, &s.identity, &TOP_UP)
+            .err(),
+        Some(Ok(DelegateError::NotDelegate.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_extend_duration_as(&stranger, &s.identity, &3600)
+            .err(),
+        Some(Ok(DelegateError::NotDelegate.into()))
+    );
+}
+
+#[test]
+fn test_revoked_delegate_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.revoke_delegate(&s.identity);
+    assert_eq!(
+        s.client
+            .try_top_up_as(&s.delegate, &s.identity, &TOP_UP)
+            .err(),
+        Some(Ok(DelegateError::NotDelegate.into()))
+    );
+    // The identity can still use the delegate-callable variants itself
+    let bond = s.client.extend_duration_as(&s.identity, &s.identity, &3600);
+    assert_eq!(bond.bond_duration, DURATION + 3600);
+}
+
+#[test]
+fn test_delegate_cannot_withdraw() {
+    let e = Env::default();
+    let s = setup(&e);
+    e.mock_auths(&[MockAuth {
+        address: &s.delegate,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "withdraw_bond",
+            args: (s.identity.clone(),).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(s.client.try_withdraw_bond(&s.identity).is_err());
+    assert_eq!(
+        s.client.get_identity_state(&s.identity).bonded_amount,
+        BOND_AMOUNT
+    );
+}
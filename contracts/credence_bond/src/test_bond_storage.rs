@@ -0,0 +1,318 @@
+//! Tests for per-identity bond storage: independent bonds, "no bond" per identity,
+//! total bonded tracking, bond TTLs and the legacy read paths.
+
+#![cfg(test)]
+
+use crate::bond_store::{LegacyDataKey, BUMP_TARGET, BUMP_THRESHOLD};
+use crate::rolling_bond::RollingDataKey;
+use crate::slashing::SlashDataKey;
+use crate::tiered_bond::TierDataKey;
+use crate::{BondError, CredenceBond, CredenceBondClient, DataKey, IdentityBond};
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol, Val};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_two_identities_bond_independently() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+    client.create_bond(&bob, &2_000_000_000, &86400_u64, &false, &0_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&alice, &100_000_000);
+    client.slash(&admin, &bob, &300_000_000, &symbol_short!("test"), &0);
+    client.top_up(&bob, &50_000_000);
+
+    let a = client.get_identity_state(&alice);
+    let b = client.get_identity_state(&bob);
+    assert_eq!(a.identity, alice);
+    assert_eq!(a.bonded_amount, 900_000_000);
+    assert_eq!(a.slashed_amount, 0);
+    assert_eq!(b.identity, bob);
+    assert_eq!(b.bonded_amount, 2_050_000_000);
+    assert_eq!(b.slashed_amount, 300_000_000);
+    assert_eq!(client.get_total_bonded(), 2_950_000_000);
+}
+
+#[test]
+fn test_withdraw_bond_only_closes_own_bond() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+    client.create_bond(&bob, &2_000_000_000, &86400_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 86400);
+
+    assert_eq!(client.withdraw_bond(&alice), 1_000_000_000);
+    assert!(!client.get_identity_state(&alice).active);
+    assert!(client.get_identity_state(&bob).active);
+    assert_eq!(client.get_total_bonded(), 2_000_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_get_identity_state_unknown_identity() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let alice = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+    client.get_identity_state(&stranger);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_withdraw_without_own_bond() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let alice = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+    client.withdraw(&stranger, &1_000_000);
+}
+
+#[test]
+fn test_legacy_single_bond_is_readable_and_migrated_on_write() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let legacy_identity = Address::generate(&e);
+    let legacy = IdentityBond {
+        identity: legacy_identity.clone(),
+        bonded_amount: 5_000_000,
+        bond_start: 0,
+        bond_duration: 86400,
+        slashed_amount: 0,
+        active: true,
+        is_rolling: false,
+        withdrawal_requested_at: 0,
+        notice_period_duration: 0,
+    };
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&LegacyDataKey::Bond, &legacy);
+    });
+
+    // Read path falls back to the legacy slot for its owner only.
+    assert_eq!(
+        client.get_identity_state(&legacy_identity).bonded_amount,
+        5_000_000
+    );
+    assert!(client
+        .try_get_identity_state(&Address::generate(&e))
+        .is_err());
+
+    // First write moves the bond into the per-identity layout.
+    client.top_up(&legacy_identity, &1_000_000);
+    let has_legacy = e.as_contract(&client.address, || {
+        e.storage().instance().has(&LegacyDataKey::Bond)
+    });
+    assert!(!has_legacy);
+    assert_eq!(
+        client.get_identity_state(&legacy_identity).bonded_amount,
+        6_000_000
+    );
+    assert_eq!(client.get_total_bonded(), 6_000_000);
+}
+
+#[test]
+fn test_list_bond_identities() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_bond_identity_count(), 0);
+
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    let carol = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+    client.create_bond(&bob, &1_000_000_000, &86400_u64, &false, &0_u64);
+    // Further writes for a known identity do not re-index it
+    client.top_up(&alice, &1_000_000);
+    client.create_bond(&carol, &1_000_000_000, &86400_u64, &false, &0_u64);
+
+    assert_eq!(client.get_bond_identity_count(), 3);
+    let all = client.list_bond_identities(&0, &10);
+    assert_eq!(all.len(), 3);
+    assert_eq!(all.get(0).unwrap(), alice);
+    assert_eq!(all.get(1).unwrap(), bob);
+    assert_eq!(all.get(2).unwrap(), carol);
+
+    let page = client.list_bond_identities(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), bob);
+    assert_eq!(client.list_bond_identities(&5, &10).len(), 0);
+}
+
+#[test]
+fn test_migrated_legacy_bond_is_indexed() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let legacy_identity = Address::generate(&e);
+    let legacy = IdentityBond {
+        identity: legacy_identity.clone(),
+        bonded_amount: 5_000_000,
+        bond_start: 0,
+        bond_duration: 86400,
+        slashed_amount: 0,
+        active: true,
+        is_rolling: false,
+        withdrawal_requested_at: 0,
+        notice_period_duration: 0,
+    };
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&LegacyDataKey::Bond, &legacy);
+    });
+    assert_eq!(client.get_bond_identity_count(), 0);
+
+    client.top_up(&legacy_identity, &1_000_000);
+    assert_eq!(client.get_bond_identity_count(), 1);
+    assert_eq!(
+        client.list_bond_identities(&0, &1).get(0).unwrap(),
+        legacy_identity
+    );
+}
+
+fn ttl<K: IntoVal<Env, Val>>(e: &Env, client: &CredenceBondClient, key: &K) -> u32 {
+    e.as_contract(&client.address, || e.storage().persistent().get_ttl(key))
+}
+
+/// Advance the ledger until an entry written now has dropped below the bump threshold, keeping
+/// the contract instance alive.
+fn age_past_threshold(e: &Env, client: &CredenceBondClient) {
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .extend_ttl(BUMP_TARGET, 2 * BUMP_TARGET)
+    });
+    e.ledger()
+        .with_mut(|li| li.sequence_number += BUMP_TARGET - BUMP_THRESHOLD + 1);
+}
+
+#[test]
+fn test_bond_stored_persistent_with_ttl() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let alice = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+
+    let key = DataKey::Bond(alice.clone());
+    let in_instance = e.as_contract(&client.address, || e.storage().instance().has(&key));
+    assert!(!in_instance);
+    assert_eq!(ttl(&e, &client, &key), BUMP_TARGET);
+
+    // Reads extend the TTL once it falls below the threshold
+    age_past_threshold(&e, &client);
+    assert_eq!(ttl(&e, &client, &key), BUMP_THRESHOLD - 1);
+    client.get_identity_state(&alice);
+    assert_eq!(ttl(&e, &client, &key), BUMP_TARGET);
+
+    // So do writes
+    age_past_threshold(&e, &client);
+    client.top_up(&alice, &1_000_000);
+    assert_eq!(ttl(&e, &client, &key), BUMP_TARGET);
+}
+
+#[test]
+fn test_refresh_bond_ttl() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let alice = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+    let key = DataKey::Bond(alice.clone());
+
+    age_past_threshold(&e, &client);
+    client.refresh_bond_ttl(&alice);
+    assert_eq!(ttl(&e, &client, &key), BUMP_TARGET);
+
+    assert_eq!(
+        client.try_refresh_bond_ttl(&Address::generate(&e)).err(),
+        Some(Ok(BondError::NoBond.into()))
+    );
+}
+
+#[test]
+fn test_instance_bond_moved_to_persistent() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let alice = Address::generate(&e);
+    let bond = client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+    let key = DataKey::Bond(alice.clone());
+    // Rewrite the bond as an earlier deployment stored it
+    e.as_contract(&client.address, || {
+        e.storage().persistent().remove(&key);
+        e.storage().instance().set(&key, &bond);
+    });
+    assert_eq!(
+        client.get_identity_state(&alice).bonded_amount,
+        bond.bonded_amount
+    );
+
+    client.refresh_bond_ttl(&alice);
+    let in_instance = e.as_contract(&client.address, || e.storage().instance().has(&key));
+    assert!(!in_instance);
+    assert_eq!(ttl(&e, &client, &key), BUMP_TARGET);
+    assert_eq!(
+        client.get_identity_state(&alice).bonded_amount,
+        bond.bonded_amount
+    );
+    assert_eq!(client.get_bond_identity_count(), 1);
+    assert_eq!(client.get_total_bonded(), 1_000_000_000);
+}
+
+#[test]
+fn test_slash_record_written_with_ttl() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let alice = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+    client.slash_with_reason(&admin, &alice, &1_000_000, &Symbol::new(&e, "fraud"));
+    assert_eq!(ttl(&e, &client, &SlashDataKey::Record(1)), BUMP_TARGET);
+    assert_eq!(ttl(&e, &client, &SlashDataKey::History(alice)), BUMP_TARGET);
+}
+
+#[test]
+fn test_recorded_tier_written_with_ttl() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let alice = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &false, &0_u64);
+    client.top_up(&alice, &1_000_000);
+    let key = TierDataKey::Recorded(alice.clone());
+    let in_instance = e.as_contract(&client.address, || e.storage().instance().has(&key));
+    assert!(!in_instance);
+    assert_eq!(ttl(&e, &client, &key), BUMP_TARGET);
+
+    // Bond reads keep it alive alongside the bond
+    age_past_threshold(&e, &client);
+    client.get_identity_state(&alice);
+    assert_eq!(ttl(&e, &client, &key), BUMP_TARGET);
+}
+
+#[test]
+fn test_fixed_conversion_written_with_ttl() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let alice = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000, &86400_u64, &true, &3600_u64);
+    client.convert_to_fixed(&alice);
+    let key = RollingDataKey::FixedConversion(alice.clone());
+    let in_instance = e.as_contract(&client.address, || e.storage().instance().has(&key));
+    assert!(!in_instance);
+    assert_eq!(ttl(&e, &client, &key), BUMP_TARGET);
+
+    age_past_threshold(&e, &client);
+    client.get_identity_state(&alice);
+    assert_eq!(ttl(&e, &client, &key), BUMP_TARGET);
+}
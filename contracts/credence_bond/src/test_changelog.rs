@@ -0,0 +1,123 @@
+//! Tests for the opt-in bond changelog: enabling, diff entries, ordering and retention.
+
+#![cfg(test)]
+
+use crate::{ChangelogEntry, ChangelogError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn entry(e: &Env, field: &str, old: i128, new: i128, cause: &str, ts: u64) -> ChangelogEntry {
+    ChangelogEntry {
+        field: Symbol::new(e, field),
+        old_value: old,
+        new_value: new,
+        cause: Symbol::new(e, cause),
+        timestamp: ts,
+    }
+}
+
+#[test]
+fn test_changelog_disabled_by_default() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &10_000_000, &86400_u64, &false, &0_u64);
+    client.top_up(&identity, &5_000_000);
+    assert_eq!(client.get_changelog(&identity, &0, &100).len(), 0);
+}
+
+#[test]
+fn test_changelog_records_mixed_operations_in_order() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.enable_changelog(&identity, &identity);
+
+    client.create_bond(&identity, &10_000_000, &100_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    client.top_up(&identity, &5_000_000);
+    e.ledger().with_mut(|li| li.timestamp = 1020);
+    client.slash(&admin, &identity, &1_000_000, &symbol_short!("test"), &0);
+    e.ledger().with_mut(|li| li.timestamp = 1030);
+    client.request_withdrawal(&identity);
+    client.extend_duration(&identity, &50_u64);
+
+    let log = client.get_changelog(&identity, &0, &100);
+    let expected = [
+        entry(&e, "bonded_amount", 0, 10_000_000, "create_bond", 1000),
+        entry(&e, "bond_start", 0, 1000, "create_bond", 1000),
+        entry(&e, "bond_duration", 0, 100, "create_bond", 1000),
+        entry(&e, "active", 0, 1, "create_bond", 1000),
+        entry(&e, "is_rolling", 0, 1, "create_bond", 1000),
+        entry(&e, "notice_period_duration", 0, 10, "create_bond", 1000),
+        entry(&e, "bonded_amount", 10_000_000, 15_000_000, "top_up", 1010),
+        entry(&e, "slashed_amount", 0, 1_000_000, "slash", 1020),
+        entry(
+            &e,
+            "withdrawal_requested_at",
+            0,
+            1030,
+            "request_withdrawal",
+            1030,
+        ),
+        entry(&e, "bond_duration", 100, 150, "extend_duration", 1030),
+    ];
+    assert_eq!(log.len(), expected.len() as u32);
+    for (i, want) in expected.iter().enumerate() {
+        assert_eq!(&log.get(i as u32).unwrap(), want);
+    }
+
+    let page = client.get_changelog(&identity, &6, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap(), expected[6]);
+    assert_eq!(page.get(1).unwrap(), expected[7]);
+}
+
+#[test]
+fn test_changelog_retention_evicts_oldest() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &10_000_000, &86400_u64, &false, &0_u64);
+    client.enable_changelog(&admin, &identity);
+    client.set_changelog_retention(&admin, &2);
+
+    client.top_up(&identity, &1_000_000);
+    client.top_up(&identity, &2_000_000);
+    client.top_up(&identity, &3_000_000);
+
+    let log = client.get_changelog(&identity, &0, &10);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.get(0).unwrap().old_value, 11_000_000);
+    assert_eq!(log.get(1).unwrap().new_value, 16_000_000);
+}
+
+#[test]
+fn test_zero_changelog_retention_rejected() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    assert_eq!(
+        client.try_set_changelog_retention(&admin, &0).err(),
+        Some(Ok(ChangelogError::InvalidRetention.into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+fn test_enable_changelog_by_stranger() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    client.enable_changelog(&stranger, &identity);
+}
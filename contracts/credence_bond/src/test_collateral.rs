@@ -0,0 +1,242 @@
+//! Tests for the collateral floor: enforcement on every withdrawal path and the cooled-off
+//! release of the floor at bond closure.
+
+#![cfg(test)]
+
+use crate::{BondError, CollateralError, CredenceBond, CredenceBondClient, Encumbrances};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, IntoVal, Symbol};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+const FLOOR: i128 = 100_000_000;
+const COOLING: u64 = 3600;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    token: TokenClient<'a>,
+    admin: Address,
+    identity: Address,
+}
+
+/// Bond created at t=1000 with its lock-up over at t=2000, a floor and a cooling period.
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    let identity = Address::generate(e);
+    StellarAssetClient::new(e, &sac.address()).mint(&identity, &BOND_AMOUNT);
+    client.set_token(&admin, &sac.address());
+
+    client.create_bond(&identity, &BOND_AMOUNT, &1000_u64, &false, &0_u64);
+    client.set_collateral_floor(&admin, &FLOOR);
+    client.set_collateral_cooling_period(&admin, &COOLING);
+    Setup {
+        client,
+        token: TokenClient::new(e, &sac.address()),
+        admin,
+        identity,
+    }
+}
+
+fn at(e: &Env, timestamp: u64) {
+    e.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_floor_disabled_by_default() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e));
+    assert_eq!(client.get_collateral_floor(), 0);
+    assert_eq!(client.get_collateral_cooling_period(), 7 * 24 * 60 * 60);
+}
+
+#[test]
+fn test_withdraw_stops_at_floor() {
+    let e = Env::default();
+    let s = setup(&e);
+    at(&e, 2000);
+    assert_eq!(
+        s.client.try_withdraw(&s.identity, &BOND_AMOUNT).err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+    let bond = s.client.withdraw(&s.identity, &(BOND_AMOUNT - FLOOR));
+    assert_eq!(bond.bonded_amount, FLOOR);
+    assert!(bond.active);
+}
+
+#[test]
+fn test_withdraw_early_stops_at_floor() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .set_early_exit_config(&s.admin, &Address::generate(&e), &500);
+    assert_eq!(
+        s.client
+            .try_withdraw_early(&s.identity, &(BOND_AMOUNT - FLOOR + 1))
+            .err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+    let bond = s.client.withdraw_early(&s.identity, &(BOND_AMOUNT - FLOOR));
+    assert_eq!(bond.bonded_amount, FLOOR);
+}
+
+#[test]
+fn test_withdraw_bond_leaves_floor() {
+    let e = Env::default();
+    let s = setup(&e);
+    at(&e, 2000);
+    assert_eq!(s.client.withdraw_bond(&s.identity), BOND_AMOUNT - FLOOR);
+    let bond = s.client.get_identity_state(&s.identity);
+    assert_eq!(bond.bonded_amount, FLOOR);
+    assert!(bond.active);
+}
+
+#[test]
+fn test_floor_counts_after_slashes() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.slash(
+        &s.admin,
+        &s.identity,
+        &(BOND_AMOUNT - FLOOR / 2),
+        &symbol_short!("test"),
+        &0,
+    );
+    // Less than the floor is left unslashed; all of it is held back
+    assert_eq!(
+        s.client.get_encumbrances(&s.identity),
+        Encumbrances {
+            pledged: 0,
+            collateral_floor: FLOOR / 2,
+            pending_slash: 0,
+        }
+    );
+    at(&e, 2000);
+    assert_eq!(
+        s.client.try_withdraw(&s.identity, &1_000_000).err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_encumbrances_stack_pledges_and_floor() {
+    let e = Env::default();
+    let s = setup(&e);
+    let agreement = BytesN::from_array(&e, &[1; 32]);
+    s.client.pledge(
+        &s.identity,
+        &agreement,
+        &Address::generate(&e),
+        &FLOOR,
+        &5000,
+    );
+    assert_eq!(
+        s.client.get_encumbrances(&s.identity),
+        Encumbrances {
+            pledged: FLOOR,
+            collateral_floor: FLOOR,
+            pending_slash: 0,
+        }
+    );
+    at(&e, 2000);
+    assert_eq!(
+        s.client
+            .try_withdraw(&s.identity, &(BOND_AMOUNT - FLOOR))
+            .err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+    s.client.withdraw(&s.identity, &(BOND_AMOUNT - 2 * FLOOR));
+}
+
+#[test]
+fn test_close_releases_floor_after_cooling() {
+    let e = Env::default();
+    let s = setup(&e);
+    at(&e, 2000);
+    assert_eq!(
+        s.client.try_close_bond(&s.identity).err(),
+        Some(Ok(BondError::BondNotEmpty.into()))
+    );
+    s.client.withdraw(&s.identity, &(BOND_AMOUNT - FLOOR));
+
+    let bond = s.client.close_bond(&s.identity);
+    let events = e.events().all();
+    let released = events
+        .iter()
+        .find(|(_, topics, _)| *topics == (Symbol::new(&e, "collateral_released"),).into_val(&e));
+    let data: (Address, i128, u64) = released.unwrap().2.into_val(&e);
+    assert_eq!(data, (s.identity.clone(), FLOOR, 2000 + COOLING));
+
+    assert!(!bond.active);
+    assert_eq!(bond.bonded_amount, 0);
+    assert_eq!(s.client.get_total_bonded(), 0);
+    let claimable = s.client.get_claimable_collateral(&s.identity);
+    assert_eq!(
+        (claimable.amount, claimable.release_at),
+        (FLOOR, 2000 + COOLING)
+    );
+
+    at(&e, 2000 + COOLING - 1);
+    assert_eq!(
+        s.client.try_claim_collateral(&s.identity).err(),
+        Some(Ok(CollateralError::CoolingOff.into()))
+    );
+    at(&e, 2000 + COOLING);
+    assert_eq!(s.client.claim_collateral(&s.identity), FLOOR);
+    assert_eq!(s.token.balance(&s.identity), BOND_AMOUNT);
+    assert_eq!(
+        s.client.try_claim_collateral(&s.identity).err(),
+        Some(Ok(BondError::NothingToClaim.into()))
+    );
+}
+
+#[test]
+fn test_close_with_live_pledge_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    at(&e, 2000);
+    s.client.withdraw(&s.identity, &(BOND_AMOUNT - FLOOR));
+    s.client.set_collateral_floor(&s.admin, &(2 * FLOOR));
+    let agreement = BytesN::from_array(&e, &[1; 32]);
+    s.client.pledge(
+        &s.identity,
+        &agreement,
+        &Address::generate(&e),
+        &1_000_000,
+        &5000,
+    );
+    assert_eq!(
+        s.client.try_close_bond(&s.identity).err(),
+        Some(Ok(BondError::BondNotEmpty.into()))
+    );
+}
+
+#[test]
+fn test_collateral_config_admin_only() {
+    let e = Env::default();
+    let s = setup(&e);
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        s.client.try_set_collateral_floor(&stranger, &0).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_set_collateral_cooling_period(&stranger, &0)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        s.client.try_set_collateral_floor(&s.admin, &-1).err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+}
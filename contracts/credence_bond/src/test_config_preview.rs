@@ -0,0 +1,125 @@
+//! Tests for the config dry runs: validation errors surface, nothing is written, and applying
+//! the same arguments yields the previewed configuration.
+
+#![cfg(test)]
+
+use crate::{BondError, CredenceBond, CredenceBondClient, TierConfig};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_preview_early_exit_config() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let treasury = Address::generate(&e);
+
+    // Off by a factor of 10: above the 5000 bps ceiling
+    assert_eq!(
+        client
+            .try_preview_early_exit_config(&admin, &treasury, &5_001)
+            .err(),
+        Some(Ok(BondError::InvalidPenaltyBps.into()))
+    );
+
+    let preview = client.preview_early_exit_config(&admin, &treasury, &500);
+    assert_eq!(e.events().all().len(), 0);
+    assert_eq!(
+        client.try_get_early_exit_config().err(),
+        Some(Ok(BondError::EarlyExitConfigNotSet.into()))
+    );
+
+    client.set_early_exit_config(&admin, &treasury, &500);
+    assert_eq!(client.get_early_exit_config(), preview);
+}
+
+#[test]
+fn test_preview_tier_thresholds() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let before = client.get_tier_config();
+
+    assert_eq!(
+        client
+            .try_preview_tier_thresholds(&admin, &5_000_000_000, &1_000_000_000, &10_000_000_000)
+            .err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+
+    let preview =
+        client.preview_tier_thresholds(&admin, &2_000_000_000, &6_000_000_000, &20_000_000_000);
+    assert_eq!(e.events().all().len(), 0);
+    assert_eq!(client.get_tier_config(), before);
+    assert_eq!(
+        (preview.bronze_min, preview.diamond_min),
+        (before.bronze_min, before.diamond_min)
+    );
+
+    client.set_tier_thresholds(&admin, &2_000_000_000, &6_000_000_000, &20_000_000_000);
+    assert_eq!(client.get_tier_config(), preview);
+}
+
+#[test]
+fn test_preview_tier_config() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let config = TierConfig {
+        bronze_min: 0,
+        silver_min: 1_000,
+        gold_min: 2_000,
+        platinum_min: 3_000,
+        diamond_min: 4_000,
+    };
+    let invalid = TierConfig {
+        diamond_min: 3_000,
+        ..config.clone()
+    };
+    assert_eq!(
+        client.try_preview_tier_config(&admin, &invalid).err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+    let preview = client.preview_tier_config(&admin, &config);
+    assert_ne!(client.get_tier_config(), preview);
+    client.set_tier_config(&admin, &config);
+    assert_eq!(client.get_tier_config(), preview);
+}
+
+#[test]
+fn test_preview_min_bond_amount() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let before = client.get_min_bond_amount();
+    assert!(client.try_preview_min_bond_amount(&admin, &-1).is_err());
+
+    let preview = client.preview_min_bond_amount(&admin, &(before * 2));
+    assert_eq!(client.get_min_bond_amount(), before);
+    client.set_min_bond_amount(&admin, &(before * 2));
+    assert_eq!(client.get_min_bond_amount(), preview);
+}
+
+#[test]
+fn test_preview_admin_only() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_preview_early_exit_config(&stranger, &stranger, &500)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client
+            .try_preview_tier_thresholds(&stranger, &2_000, &3_000, &4_000)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
@@ -0,0 +1,385 @@
+//! Tests for contract identities: a custom account contract bonding end to end through its
+//! own `__check_auth`, and a controller running day-to-day operations on its behalf.
+
+#![cfg(test)]
+
+extern crate std;
+
+use crate::{CredenceBond, CredenceBondClient, DelegateError};
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::xdr::{
+    AccountId, InvokeContractArgs, PublicKey, ScAddress, ScSymbol, ScVal,
+    SorobanAddressCredentials, SorobanAuthorizationEntry, SorobanAuthorizedFunction,
+    SorobanAuthorizedInvocation, SorobanCredentials, Uint256,
+};
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val, Vec};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+const TOP_UP: i128 = 100_000_000;
+const DURATION: u64 = 86400;
+const NOTICE: u64 = 3600;
+const PIN: u32 = 4242;
+
+mod account {
+    use soroban_sdk::auth::{Context, CustomAccountInterface};
+    use soroban_sdk::crypto::Hash;
+    use soroban_sdk::{contract, contracterror, contractimpl, symbol_short, Env, Vec};
+
+    #[contracterror]
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    #[repr(u32)]
+    pub enum AccountError {
+        BadSignature = 1,
+    }
+
+    /// Minimal custom account: a call is authorized by signing it with the account's PIN.
+    #[contract]
+    pub struct PinAccount;
+
+    #[contractimpl]
+    impl PinAccount {
+        pub fn __constructor(e: Env, pin: u32) {
+            e.storage().instance().set(&symbol_short!("pin"), &pin);
+        }
+
+        /// Number of authorizations `__check_auth` has accepted.
+        pub fn checks(e: Env) -> u32 {
+            e.storage()
+                .instance()
+                .get(&symbol_short!("checks"))
+                .unwrap_or(0)
+        }
+    }
+
+    #[contractimpl]
+    impl CustomAccountInterface for PinAccount {
+        type Signature = u32;
+        type Error = AccountError;
+
+        fn __check_auth(
+            e: Env,
+            _signature_payload: Hash<32>,
+            signature: u32,
+            _auth_contexts: Vec<Context>,
+        ) -> Result<(), AccountError> {
+            let pin: u32 = e.storage().instance().get(&symbol_short!("pin")).unwrap();
+            if signature != pin {
+                return Err(AccountError::BadSignature);
+            }
+            let checks = Self::checks(e.clone()) + 1;
+            e.storage()
+                .instance()
+                .set(&symbol_short!("checks"), &checks);
+            Ok(())
+        }
+    }
+}
+
+use account::{PinAccount, PinAccountClient};
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    token: TokenClient<'a>,
+    account: PinAccountClient<'a>,
+    controller: Address,
+}
+
+/// Bond contract with a token, and a funded PIN account. Auths are mocked only for setup.
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    let asset = StellarAssetClient::new(e, &sac.address());
+    client.set_token(&admin, &sac.address());
+
+    let account_id = e.register(PinAccount, (PIN,));
+    let controller = Address::generate(e);
+    asset.mint(&account_id, &(BOND_AMOUNT + TOP_UP));
+    asset.mint(&controller, &TOP_UP);
+    Setup {
+        client,
+        token: TokenClient::new(e, &sac.address()),
+        account: PinAccountClient::new(e, &account_id),
+        controller,
+    }
+}
+
+fn invocation(
+    e: &Env,
+    contract: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+    sub_invocations: std::vec::Vec<SorobanAuthorizedInvocation>,
+) -> SorobanAuthorizedInvocation {
+    let args: std::vec::Vec<ScVal> = args
+        .iter()
+        .map(|arg| ScVal::try_from_val(e, &arg).unwrap())
+        .collect();
+    SorobanAuthorizedInvocation {
+        function: SorobanAuthorizedFunction::ContractFn(InvokeContractArgs {
+            contract_address: contract.into(),
+            function_name: ScSymbol(fn_name.try_into().unwrap()),
+            args: args.try_into().unwrap(),
+        }),
+        sub_invocations: sub_invocations.try_into().unwrap(),
+    }
+}
+
+/// Authorize `root` for the PIN account, signed with `pin`. The next call must match it.
+fn sign(e: &Env, account: &Address, pin: u32, nonce: i64, root: SorobanAuthorizedInvocation) {
+    e.set_auths(&[SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: account.into(),
+            nonce,
+            signature_expiration_ledger: e.ledger().sequence() + 100,
+            signature: ScVal::U32(pin),
+        }),
+        root_invocation: root,
+    }]);
+}
+
+/// Authorization for a bond call that pulls `amount` of the token from the account.
+fn paying(
+    e: &Env,
+    s: &Setup,
+    fn_name: &str,
+    args: Vec<Val>,
+    amount: i128,
+) -> SorobanAuthorizedInvocation {
+    let transfer = invocation(
+        e,
+        &s.token.address,
+        "transfer",
+        (s.account.address.clone(), s.client.address.clone(), amount).into_val(e),
+        std::vec![],
+    );
+    invocation(e, &s.client.address, fn_name, args, std::vec![transfer])
+}
+
+fn create_rolling_bond(e: &Env, s: &Setup) {
+    let identity = s.account.address.clone();
+    let args = (identity.clone(), BOND_AMOUNT, DURATION, true, NOTICE).into_val(e);
+    sign(
+        e,
+        &identity,
+        PIN,
+        1,
+        paying(e, s, "create_bond", args, BOND_AMOUNT),
+    );
+    s.client
+        .create_bond(&identity, &BOND_AMOUNT, &DURATION, &true, &NOTICE);
+}
+
+#[test]
+fn test_contract_identity_detected() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert!(s.client.is_contract_identity(&s.account.address));
+
+    let account = Address::try_from_val(
+        &e,
+        &ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([7; 32])))),
+    )
+    .unwrap();
+    assert!(!s.client.is_contract_identity(&account));
+}
+
+#[test]
+fn test_contract_identity_bonds_end_to_end_through_check_auth() {
+    let e = Env::default();
+    let s = setup(&e);
+    let identity = s.account.address.clone();
+
+    create_rolling_bond(&e, &s);
+    assert_eq!(s.token.balance(&identity), TOP_UP);
+
+    let args = (identity.clone(), TOP_UP).into_val(&e);
+    sign(
+        &e,
+        &identity,
+        PIN,
+        2,
+        paying(&e, &s, "top_up", args, TOP_UP),
+    );
+    let bond = s.client.top_up(&identity, &TOP_UP);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT + TOP_UP);
+    assert_eq!(s.token.balance(&identity), 0);
+
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let args = (identity.clone(),).into_val(&e);
+    let root = invocation(
+        &e,
+        &s.client.address,
+        "request_withdrawal",
+        args,
+        std::vec![],
+    );
+    sign(&e, &identity, PIN, 3, root);
+    let bond = s.client.request_withdrawal(&identity);
+    assert_eq!(bond.withdrawal_requested_at, 1000);
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + NOTICE);
+    let args = (identity.clone(), BOND_AMOUNT).into_val(&e);
+    let root = invocation(&e, &s.client.address, "withdraw", args, std::vec![]);
+    sign(&e, &identity, PIN, 4, root);
+    let bond = s.client.withdraw(&identity, &BOND_AMOUNT);
+    assert_eq!(bond.bonded_amount, TOP_UP);
+    assert_eq!(s.token.balance(&identity), BOND_AMOUNT);
+
+    assert_eq!(s.account.checks(), 4);
+}
+
+#[test]
+fn test_contract_identity_bad_signature_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let identity = s.account.address.clone();
+    let args = (identity.clone(), BOND_AMOUNT, DURATION, true, NOTICE).into_val(&e);
+    sign(
+        &e,
+        &identity,
+        PIN + 1,
+        1,
+        paying(&e, &s, "create_bond", args, BOND_AMOUNT),
+    );
+    assert!(s
+        .client
+        .try_create_bond(&identity, &BOND_AMOUNT, &DURATION, &true, &NOTICE)
+        .is_err());
+    assert_eq!(s.account.checks(), 0);
+}
+
+#[test]
+fn test_controller_runs_day_to_day_operations() {
+    let e = Env::default();
+    let s = setup(&e);
+    let identity = s.account.address.clone();
+    create_rolling_bond(&e, &s);
+
+    let args = (identity.clone(), s.controller.clone()).into_val(&e);
+    let root = invocation(&e, &s.client.address, "set_controller", args, std::vec![]);
+    sign(&e, &identity, PIN, 2, root);
+    s.client.set_controller(&identity, &s.controller);
+    assert_eq!(
+        s.client.get_controller(&identity),
+        Some(s.controller.clone())
+    );
+
+    // From here on only the controller signs; the account is never asked again
+    e.mock_auths(&[MockAuth {
+        address: &s.controller,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "top_up_as",
+            args: (s.controller.clone(), identity.clone(), TOP_UP).into_val(&e),
+            sub_invokes: &[MockAuthInvoke {
+                contract: &s.token.address,
+                fn_name: "transfer",
+                args: (s.controller.clone(), s.client.address.clone(), TOP_UP).into_val(&e),
+                sub_invokes: &[],
+            }],
+        },
+    }]);
+    let bond = s.client.top_up_as(&s.controller, &identity, &TOP_UP);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT + TOP_UP);
+    assert_eq!(s.token.balance(&s.controller), 0);
+
+    e.mock_auths(&[MockAuth {
+        address: &s.controller,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "extend_duration_as",
+            args: (s.controller.clone(), identity.clone(), 3600_u64).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    let bond = s.client.extend_duration_as(&s.controller, &identity, &3600);
+    assert_eq!(bond.bond_duration, DURATION + 3600);
+
+    e.mock_auths(&[MockAuth {
+        address: &s.controller,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "request_withdrawal_as",
+            args: (s.controller.clone(), identity.clone()).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    let bond = s.client.request_withdrawal_as(&s.controller, &identity);
+    assert_eq!(bond.withdrawal_requested_at, e.ledger().timestamp());
+    assert_eq!(s.account.checks(), 2);
+}
+
+#[test]
+fn test_controller_cannot_withdraw() {
+    let e = Env::default();
+    let s = setup(&e);
+    let identity = s.account.address.clone();
+    create_rolling_bond(&e, &s);
+    e.mock_all_auths();
+    s.client.set_controller(&identity, &s.controller);
+    s.client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = NOTICE);
+
+    e.mock_auths(&[MockAuth {
+        address: &s.controller,
+        invoke: &MockAuthInvoke {
+            contract: &s.client.address,
+            fn_name: "withdraw",
+            args: (identity.clone(), BOND_AMOUNT).into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(s.client.try_withdraw(&identity, &BOND_AMOUNT).is_err());
+    assert_eq!(
+        s.client.get_identity_state(&identity).bonded_amount,
+        BOND_AMOUNT
+    );
+}
+
+#[test]
+fn test_controller_rules() {
+    let e = Env::default();
+    let s = setup(&e);
+    let identity = s.account.address.clone();
+    create_rolling_bond(&e, &s);
+    e.mock_all_auths();
+
+    let account = Address::try_from_val(
+        &e,
+        &ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([7; 32])))),
+    )
+    .unwrap();
+    assert_eq!(
+        s.client.try_set_controller(&account, &s.controller).err(),
+        Some(Ok(DelegateError::NotContractIdentity.into()))
+    );
+    assert_eq!(
+        s.client.try_set_controller(&identity, &identity).err(),
+        Some(Ok(DelegateError::SelfDelegation.into()))
+    );
+
+    // A delegate may top up but not request withdrawals
+    s.client.set_delegate(&identity, &s.controller);
+    assert_eq!(
+        s.client
+            .try_request_withdrawal_as(&s.controller, &identity)
+            .err(),
+        Some(Ok(DelegateError::NotDelegate.into()))
+    );
+
+    s.client.set_controller(&identity, &s.controller);
+    s.client.remove_controller(&identity);
+    assert_eq!(s.client.get_controller(&identity), None);
+    assert_eq!(
+        s.client
+            .try_request_withdrawal_as(&s.controller, &identity)
+            .err(),
+        Some(Ok(DelegateError::NotDelegate.into()))
+    );
+}
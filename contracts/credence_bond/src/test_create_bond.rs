@@ -8,7 +8,7 @@ use soroban_sdk::Env;
 #[test]
 fn test_create_bond_success() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -31,7 +31,7 @@ fn test_create_bond_success() {
 #[test]
 fn test_create_bond_zero_amount() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -48,7 +48,7 @@ fn test_create_bond_zero_amount() {
 #[test]
 fn test_create_bond_negative_amount() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -64,7 +64,7 @@ fn test_create_bond_negative_amount() {
 #[test]
 fn test_create_bond_max_amount() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -81,7 +81,7 @@ fn test_create_bond_max_amount() {
 #[test]
 fn test_create_bond_zero_duration() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -98,7 +98,7 @@ fn test_create_bond_zero_duration() {
 #[test]
 fn test_create_bond_max_duration() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -113,14 +113,14 @@ fn test_create_bond_max_duration() {
 
 /// Test bond creation with duration that causes timestamp overflow
 #[test]
-#[should_panic(expected = "bond end timestamp would overflow")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_create_bond_duration_overflow() {
     let e = Env::default();
     e.ledger().with_mut(|li| {
         li.timestamp = u64::MAX - 1000; // Set timestamp close to max
     });
     
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -135,7 +135,7 @@ fn test_create_bond_duration_overflow() {
 #[test]
 fn test_create_bond_duplicate() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -153,7 +153,7 @@ fn test_create_bond_duplicate() {
     assert_eq!(bond2.bond_duration, 172800);
 
     // Verify storage contains second bond
-    let stored_bond = client.get_identity_state();
+    let stored_bond = client.get_identity_state(&identity);
     assert_eq!(stored_bond.bonded_amount, 2000);
 }
 
@@ -161,7 +161,7 @@ fn test_create_bond_duplicate() {
 #[test]
 fn test_create_bond_different_identities() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -174,7 +174,7 @@ fn test_create_bond_different_identities() {
     let _bond2 = client.create_bond(&identity2, &2000_i128, &172800_u64);
 
     // Due to single bond storage, only the last bond is stored
-    let stored_bond = client.get_identity_state();
+    let stored_bond = client.get_identity_state(&identity);
     assert_eq!(stored_bond.identity, identity2);
     assert_eq!(stored_bond.bonded_amount, 2000);
 }
@@ -183,7 +183,7 @@ fn test_create_bond_different_identities() {
 #[test]
 fn test_create_bond_field_initialization() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -203,7 +203,7 @@ fn test_create_bond_field_initialization() {
 #[test]
 fn test_create_bond_storage_persistence() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -215,7 +215,7 @@ fn test_create_bond_storage_persistence() {
 
     client.create_bond(&identity, &amount, &duration);
 
-    let retrieved_bond = client.get_identity_state();
+    let retrieved_bond = client.get_identity_state(&identity);
     assert_eq!(retrieved_bond.identity, identity);
     assert_eq!(retrieved_bond.bonded_amount, amount);
     assert_eq!(retrieved_bond.bond_duration, duration);
@@ -225,7 +225,7 @@ fn test_create_bond_storage_persistence() {
 #[test]
 fn test_create_bond_min_positive_amount() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -242,7 +242,7 @@ fn test_create_bond_min_positive_amount() {
 #[test]
 fn test_create_bond_usdc_amount() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -259,7 +259,7 @@ fn test_create_bond_usdc_amount() {
 #[test]
 fn test_create_bond_timestamp() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -277,7 +277,7 @@ fn test_create_bond_timestamp() {
 #[test]
 fn test_create_bond_sequential() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
 
     let admin = Address::generate(&e);
@@ -292,6 +292,6 @@ fn test_create_bond_sequential() {
     }
 
     // Last bond should be stored
-    let stored_bond = client.get_identity_state();
+    let stored_bond = client.get_identity_state(&identity);
     assert_eq!(stored_bond.bonded_amount, 5000);
 }
@@ -0,0 +1,241 @@
+//! Tests for the large deposit review hold: held top-ups do not count toward the bond until
+//! released, rejected ones become claimable, and unreviewed ones auto-release after the deadline.
+
+#![cfg(test)]
+
+use crate::{BondError, BondTier, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+const START_BALANCE: i128 = 10_000_000_000;
+const BOND_AMOUNT: i128 = 500_000_000;
+const THRESHOLD: i128 = 1_000_000_000;
+const LARGE: i128 = 2_000_000_000;
+const WINDOW: u64 = 3600;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    token: TokenClient<'a>,
+    admin: Address,
+    identity: Address,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(e));
+    let identity = Address::generate(e);
+    StellarAssetClient::new(e, &sac.address()).mint(&identity, &START_BALANCE);
+    client.set_token(&admin, &sac.address());
+
+    client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    client.set_large_deposit_threshold(&admin, &THRESHOLD);
+    client.set_deposit_review_window(&admin, &WINDOW);
+    Setup {
+        client,
+        token: TokenClient::new(e, &sac.address()),
+        admin,
+        identity,
+    }
+}
+
+#[test]
+fn test_review_disabled_by_default() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e));
+    assert_eq!(client.get_large_deposit_threshold(), 0);
+    assert_eq!(client.get_deposit_review_window(), 7 * 24 * 60 * 60);
+}
+
+#[test]
+fn test_top_up_at_threshold_not_held() {
+    let e = Env::default();
+    let s = setup(&e);
+    let bond = s.client.top_up(&s.identity, &THRESHOLD);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT + THRESHOLD);
+}
+
+#[test]
+fn test_large_top_up_held() {
+    let e = Env::default();
+    let s = setup(&e);
+    let bond = s.client.top_up(&s.identity, &LARGE);
+
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "deposit_held"),).into_val(&e));
+    let data: (Address, u64, i128, u64) = data.into_val(&e);
+    assert_eq!(data, (s.identity.clone(), 1, LARGE, 1000 + WINDOW));
+
+    // Funds are in the contract but not bonded
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT);
+    assert_eq!(
+        s.client.get_identity_state(&s.identity).bonded_amount,
+        BOND_AMOUNT
+    );
+    assert_eq!(s.client.get_tier(&s.identity), BondTier::Bronze);
+    assert_eq!(s.token.balance(&s.client.address), BOND_AMOUNT + LARGE);
+    let pending = s.client.get_pending_deposit(&1);
+    assert_eq!(pending.amount, LARGE);
+    assert_eq!(pending.release_at, 1000 + WINDOW);
+}
+
+#[test]
+fn test_release_deposit_bonds_funds() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.top_up(&s.identity, &LARGE);
+
+    let bond = s.client.release_deposit(&s.admin, &s.identity, &1);
+    let events = e.events().all();
+    let tier_changed = events
+        .iter()
+        .any(|(_, topics, _)| topics == (Symbol::new(&e, "tier_changed"),).into_val(&e));
+    assert!(tier_changed);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT + LARGE);
+    assert_eq!(s.client.get_tier(&s.identity), BondTier::Silver);
+    assert_eq!(
+        s.client.try_get_pending_deposit(&1).err(),
+        Some(Ok(BondError::DepositNotFound.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_release_deposit(&s.admin, &s.identity, &1)
+            .err(),
+        Some(Ok(BondError::DepositNotFound.into()))
+    );
+}
+
+#[test]
+fn test_reject_deposit_becomes_claimable() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.top_up(&s.identity, &LARGE);
+
+    s.client.reject_deposit(&s.admin, &s.identity, &1);
+    assert_eq!(
+        s.client.get_identity_state(&s.identity).bonded_amount,
+        BOND_AMOUNT
+    );
+    assert_eq!(s.client.get_claimable_deposits(&s.identity), LARGE);
+
+    assert_eq!(s.client.claim_rejected_deposits(&s.identity), LARGE);
+    assert_eq!(s.token.balance(&s.identity), START_BALANCE - BOND_AMOUNT);
+    assert_eq!(s.client.get_claimable_deposits(&s.identity), 0);
+    assert_eq!(
+        s.client.try_claim_rejected_deposits(&s.identity).err(),
+        Some(Ok(BondError::NothingToClaim.into()))
+    );
+}
+
+#[test]
+fn test_rejected_deposit_cannot_be_released() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.top_up(&s.identity, &LARGE);
+    s.client.reject_deposit(&s.admin, &s.identity, &1);
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + WINDOW);
+    assert_eq!(
+        s.client.try_release_expired_deposit(&s.identity, &1).err(),
+        Some(Ok(BondError::DepositNotFound.into()))
+    );
+}
+
+#[test]
+fn test_auto_release_after_deadline() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.top_up(&s.identity, &LARGE);
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + WINDOW - 1);
+    assert_eq!(
+        s.client.try_release_expired_deposit(&s.identity, &1).err(),
+        Some(Ok(BondError::DepositReviewPending.into()))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1000 + WINDOW);
+    let bond = s.client.release_expired_deposit(&s.identity, &1);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT + LARGE);
+}
+
+#[test]
+fn test_deposit_id_must_match_identity() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.top_up(&s.identity, &LARGE);
+    let other = Address::generate(&e);
+    assert_eq!(
+        s.client.try_reject_deposit(&s.admin, &other, &1).err(),
+        Some(Ok(BondError::DepositNotFound.into()))
+    );
+}
+
+#[test]
+fn test_top_up_and_extend_above_threshold_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert_eq!(
+        s.client
+            .try_top_up_and_extend(&s.identity, &LARGE, &3600_u64)
+            .err(),
+        Some(Ok(BondError::DepositRequiresReview.into()))
+    );
+}
+
+#[test]
+fn test_review_admin_only() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.top_up(&s.identity, &LARGE);
+    let stranger = Address::generate(&e);
+    let not_admin = Some(Ok(BondError::NotAdmin.into()));
+    assert_eq!(
+        s.client
+            .try_release_deposit(&stranger, &s.identity, &1)
+            .err(),
+        not_admin
+    );
+    assert_eq!(
+        s.client
+            .try_reject_deposit(&stranger, &s.identity, &1)
+            .err(),
+        not_admin
+    );
+    assert_eq!(
+        s.client
+            .try_set_large_deposit_threshold(&stranger, &0)
+            .err(),
+        not_admin
+    );
+    assert_eq!(
+        s.client.try_set_deposit_review_window(&stranger, &1).err(),
+        not_admin
+    );
+}
+
+#[test]
+fn test_invalid_review_config_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let invalid = Some(Ok(BondError::InvalidConfig.into()));
+    assert_eq!(
+        s.client
+            .try_set_large_deposit_threshold(&s.admin, &-1)
+            .err(),
+        invalid
+    );
+    assert_eq!(
+        s.client.try_set_deposit_review_window(&s.admin, &0).err(),
+        invalid
+    );
+}
@@ -0,0 +1,138 @@
+//! Tests for the deposit premium routed to the insurance pool on create_bond and top_up.
+
+#![cfg(test)]
+
+use crate::insurance::{calculate_premium, MAX_DEPOSIT_PREMIUM_BPS};
+use crate::tiered_bond::TIER_BRONZE_MAX;
+use crate::validation::MIN_BOND_AMOUNT;
+use crate::{BondTier, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Env};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+#[test]
+fn test_calculate_premium_rounds_up() {
+    let e = Env::default();
+    assert_eq!(calculate_premium(&e, 10_000, 100), 100);
+    assert_eq!(calculate_premium(&e, 10_001, 100), 101);
+    assert_eq!(calculate_premium(&e, 1, 1), 1);
+    assert_eq!(calculate_premium(&e, 1_000_000, 0), 0);
+    assert_eq!(calculate_premium(&e, 0, 100), 0);
+    assert_eq!(calculate_premium(&e, -1_000, 100), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_calculate_premium_overflow() {
+    let e = Env::default();
+    let _ = calculate_premium(&e, i128::MAX, MAX_DEPOSIT_PREMIUM_BPS);
+}
+
+#[test]
+fn test_zero_premium_default_preserves_behavior() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    assert_eq!(client.get_deposit_premium(), 0);
+
+    let bond = client.create_bond(&identity, &10_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 10_000_000);
+    let bond = client.top_up(&identity, &5_000_000);
+    assert_eq!(bond.bonded_amount, 15_000_000);
+
+    assert_eq!(client.get_insurance_pool_balance(), 0);
+    let stats = client.get_deposit_stats();
+    assert_eq!(stats.total_gross, 15_000_000);
+    assert_eq!(stats.total_premium, 0);
+    assert_eq!(stats.total_net, 15_000_000);
+}
+
+#[test]
+fn test_create_bond_splits_premium() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_deposit_premium(&admin, &250);
+    let identity = Address::generate(&e);
+
+    // 10_000_001 * 250 / 10_000 = 250_000.025 -> rounded up to 250_001
+    let bond = client.create_bond(&identity, &10_000_001, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 10_000_001 - 250_001);
+    assert_eq!(client.get_insurance_pool_balance(), 250_001);
+}
+
+#[test]
+fn test_top_up_splits_premium_and_accumulates_stats() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_deposit_premium(&admin, &100);
+    let identity = Address::generate(&e);
+
+    client.create_bond(&identity, &10_000_000, &86400_u64, &false, &0_u64);
+    let bond = client.top_up(&identity, &20_000_000);
+    assert_eq!(bond.bonded_amount, 9_900_000 + 19_800_000);
+    assert_eq!(client.get_insurance_pool_balance(), 100_000 + 200_000);
+
+    let stats = client.get_deposit_stats();
+    assert_eq!(stats.total_gross, 30_000_000);
+    assert_eq!(stats.total_premium, 300_000);
+    assert_eq!(stats.total_net, 29_700_000);
+    assert_eq!(stats.total_gross, stats.total_premium + stats.total_net);
+}
+
+#[test]
+fn test_tier_uses_net_bonded_amount() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_deposit_premium(&admin, &100);
+    let identity = Address::generate(&e);
+
+    // Gross reaches the Silver threshold, but the net bonded amount stays Bronze.
+    client.create_bond(&identity, &TIER_BRONZE_MAX, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_tier(&identity), BondTier::Bronze);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #701)")]
+fn test_create_bond_net_must_satisfy_minimum() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_deposit_premium(&admin, &100);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64, &false, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_top_up_net_must_satisfy_minimum() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &10_000_000, &86400_u64, &false, &0_u64);
+    client.set_deposit_premium(&admin, &100);
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_set_deposit_premium_above_maximum() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_deposit_premium(&admin, &(MAX_DEPOSIT_PREMIUM_BPS + 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_deposit_premium_non_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let other = Address::generate(&e);
+    client.set_deposit_premium(&other, &100);
+}
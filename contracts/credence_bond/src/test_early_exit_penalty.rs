@@ -5,16 +5,18 @@
 #![cfg(test)]
 
 use crate::early_exit_penalty;
-use crate::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, Env};
+use crate::{BondError, CredenceBond, CredenceBondClient, EarlyExitConfig, TreasuryError};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol};
 
 fn setup<'a>(
     e: &'a Env,
     treasury: &Address,
     penalty_bps: u32,
 ) -> (CredenceBondClient<'a>, Address) {
-    let contract_id = e.register_contract(None, CredenceBond);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
     client.initialize(&admin);
@@ -27,12 +29,12 @@ fn test_early_exit_penalty_calculation_zero_penalty_rate() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let treasury = Address::generate(&e);
-    let (client, admin) = setup(&e, &treasury, 0);
+    let (client, _admin) = setup(&e, &treasury, 0);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &100_u64, &false, &0_u64);
 
-    let bond = client.withdraw_early(&500);
-    assert_eq!(bond.bonded_amount, 500);
+    let bond = client.withdraw_early(&identity, &500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
 }
 
 #[test]
@@ -40,13 +42,13 @@ fn test_early_exit_penalty_calculation_max_penalty() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let treasury = Address::generate(&e);
-    let (client, _admin) = setup(&e, &treasury, 10_000); // 100%
+    let (client, _admin) = setup(&e, &treasury, 5_000); // 50%, the hard ceiling
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &100_u64, &false, &0_u64);
     // Withdraw at start: remaining = 100, total = 100 -> full penalty
-    let bond = client.withdraw_early(&500);
-    assert_eq!(bond.bonded_amount, 500);
-    // Penalty = 500 * 100% = 500; user effectively gets 0 (penalty to treasury)
+    let bond = client.withdraw_early(&identity, &500_000_000);
+    assert_eq!(bond.bonded_amount, 500_000_000);
+    // Penalty = 500 * 50% = 250 to the treasury
 }
 
 #[test]
@@ -56,11 +58,11 @@ fn test_early_exit_penalty_half_remaining() {
     let treasury = Address::generate(&e);
     let (client, _admin) = setup(&e, &treasury, 1000); // 10%
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &100_u64, &false, &0_u64);
     // At t=1050: remaining=50, total=100 -> 50% of penalty rate -> 5% of amount
     e.ledger().with_mut(|li| li.timestamp = 1050);
-    let bond = client.withdraw_early(&100);
-    assert_eq!(bond.bonded_amount, 900);
+    let bond = client.withdraw_early(&identity, &100_000_000);
+    assert_eq!(bond.bonded_amount, 900_000_000);
     // Penalty = 100 * 10% * (50/100) = 5
 }
 
@@ -71,46 +73,48 @@ fn test_early_exit_emits_penalty_event() {
     let treasury = Address::generate(&e);
     let (client, _admin) = setup(&e, &treasury, 500); // 5%
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
-    client.withdraw_early(&200);
+    client.create_bond(&identity, &1_000_000_000_i128, &100_u64, &false, &0_u64);
+    client.withdraw_early(&identity, &200_000_000);
     // Event (early_exit_penalty, (identity, 200, penalty, treasury)) should be emitted
     // We can't easily assert events in Soroban test without event parsing; bond state is updated
-    let state = client.get_identity_state();
-    assert_eq!(state.bonded_amount, 800);
+    let state = client.get_identity_state(&identity);
+    assert_eq!(state.bonded_amount, 800_000_000);
 }
 
 #[test]
-#[should_panic(expected = "use withdraw for post lock-up")]
+#[should_panic(expected = "Error(Contract, #13)")]
 fn test_early_exit_rejected_after_lock_up() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let treasury = Address::generate(&e);
     let (client, _admin) = setup(&e, &treasury, 500);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    client.create_bond(&identity, &1_000_000_000_i128, &100_u64, &false, &0_u64);
     e.ledger().with_mut(|li| li.timestamp = 1101);
-    client.withdraw_early(&100);
+    client.withdraw_early(&identity, &100_000_000);
 }
 
 #[test]
-#[should_panic(expected = "early exit config not set")]
+#[should_panic(expected = "Error(Contract, #26)")]
 fn test_early_exit_fails_without_config() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
-    let contract_id = e.register_contract(None, CredenceBond);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
     client.initialize(&admin);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
-    client.withdraw_early(&100);
+    client.create_bond(&identity, &1_000_000_000_i128, &100_u64, &false, &0_u64);
+    client.withdraw_early(&identity, &100_000_000);
 }
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #2)")]
 fn test_set_early_exit_config_unauthorized() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
     client.initialize(&admin);
@@ -120,10 +124,11 @@ fn test_set_early_exit_config_unauthorized() {
 }
 
 #[test]
-#[should_panic(expected = "penalty_bps must be <= 10000")]
+#[should_panic(expected = "Error(Contract, #27)")]
 fn test_set_early_exit_config_invalid_bps() {
     let e = Env::default();
-    let contract_id = e.register_contract(None, CredenceBond);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(&e, &contract_id);
     let admin = Address::generate(&e);
     client.initialize(&admin);
@@ -133,7 +138,6 @@ fn test_set_early_exit_config_invalid_bps() {
 
 #[test]
 fn test_calculate_penalty_unit() {
-    let e = Env::default();
     // remaining = total -> full penalty rate applied
     let p = early_exit_penalty::calculate_penalty(1000, 100, 100, 500);
     assert_eq!(p, 50); // 5% of 1000
@@ -142,3 +146,699 @@ fn test_calculate_penalty_unit() {
     let p = early_exit_penalty::calculate_penalty(1000, 50, 100, 10000);
     assert_eq!(p, 500);
 }
+
+/// Data of the `early_exit_penalty` event: (identity, amount, penalty, treasury, surge
+/// multiplier bps, bracket, waived, total penalties collected).
+type PenaltyEvent = (
+    Address,
+    i128,
+    i128,
+    Address,
+    u32,
+    Option<(u32, u32)>,
+    bool,
+    i128,
+);
+
+fn last_penalty_event(e: &Env) -> PenaltyEvent {
+    let name = Symbol::new(e, "early_exit_penalty");
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            let topic: Symbol = topics.get(0).unwrap().into_val(e);
+            topic == name
+        })
+        .last()
+        .unwrap();
+    data.into_val(e)
+}
+
+fn setup_surge(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(e);
+    let (client, admin) = setup(e, &treasury, 1000); // 10%
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    // +50% above 10% outflow, 2x above 30% outflow, within a 100s window
+    let thresholds = vec![e, (1_000_u32, 15_000_u32), (3_000_u32, 20_000_u32)];
+    client.set_surge_penalty(&admin, &thresholds, &100_u64);
+    (client, identity)
+}
+
+#[test]
+fn test_surge_multiplier_quote_matches_charge() {
+    let e = Env::default();
+    let (client, identity) = setup_surge(&e);
+    assert_eq!(client.get_surge_multiplier(), 10_000);
+
+    // 20% of bonded value exits early: no surge yet for this withdrawal itself.
+    client.withdraw_early(&identity, &200_000_000);
+    let (_, _, penalty, _, surge, _, _, _) = last_penalty_event(&e);
+    assert_eq!(surge, 10_000);
+    assert_eq!(penalty, 20_000_000); // 10% of 200M, full lock remaining
+
+    // 200M / 800M = 25% outflow > 10% -> 1.5x, quoted and charged alike.
+    let quoted = client.get_surge_multiplier();
+    assert_eq!(quoted, 15_000);
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, _, penalty, _, surge, _, _, _) = last_penalty_event(&e);
+    assert_eq!(surge, quoted);
+    assert_eq!(penalty, 15_000_000);
+
+    // 300M / 700M ≈ 42% outflow > 30% -> 2x
+    assert_eq!(client.get_surge_multiplier(), 20_000);
+}
+
+#[test]
+fn test_surge_expires_with_window() {
+    let e = Env::default();
+    let (client, identity) = setup_surge(&e);
+    client.withdraw_early(&identity, &200_000_000);
+    assert_eq!(client.get_surge_multiplier(), 15_000);
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    assert_eq!(client.get_surge_multiplier(), 10_000);
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, _, _, _, surge, _, _, _) = last_penalty_event(&e);
+    assert_eq!(surge, 10_000);
+}
+
+#[test]
+fn test_surge_penalty_capped_at_amount() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 5_000); // 50%
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    client.set_surge_penalty(&admin, &vec![&e, (0_u32, 30_000_u32)], &100_u64);
+    client.withdraw_early(&identity, &100_000_000);
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, amount, penalty, _, surge, _, _, _) = last_penalty_event(&e);
+    assert_eq!(surge, 30_000);
+    assert_eq!(penalty, amount);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #28)")]
+fn test_set_surge_penalty_unsorted() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1000);
+    let thresholds = vec![&e, (3_000_u32, 20_000_u32), (1_000_u32, 15_000_u32)];
+    client.set_surge_penalty(&admin, &thresholds, &100_u64);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #28)")]
+fn test_set_surge_penalty_discount_rejected() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1000);
+    client.set_surge_penalty(&admin, &vec![&e, (1_000_u32, 5_000_u32)], &100_u64);
+}
+
+#[test]
+fn test_full_early_exit_deactivates_bond() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, _admin) = setup(&e, &treasury, 1000);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    let bond = client.withdraw_early(&identity, &1_000_000_000);
+    assert!(!bond.active);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_early_exit_on_inactive_bond_rejected() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, _admin) = setup(&e, &treasury, 1000);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    client.withdraw_early(&identity, &1_000_000_000);
+    client.withdraw_early(&identity, &1_000_000);
+}
+
+#[test]
+fn test_penalty_above_ceiling_rejected() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1000);
+    assert_eq!(client.get_early_exit_max_penalty(), 5_000);
+    assert_eq!(
+        client
+            .try_set_early_exit_config(&admin, &treasury, &5_001)
+            .err(),
+        Some(Ok(BondError::InvalidPenaltyBps.into()))
+    );
+
+    client.set_early_exit_max_penalty(&admin, &2_000);
+    assert_eq!(
+        client
+            .try_set_early_exit_config(&admin, &treasury, &2_001)
+            .err(),
+        Some(Ok(BondError::InvalidPenaltyBps.into()))
+    );
+    client.set_early_exit_config(&admin, &treasury, &2_000);
+    assert_eq!(
+        client.get_early_exit_config(),
+        EarlyExitConfig {
+            treasury,
+            penalty_bps: 2_000,
+        }
+    );
+}
+
+#[test]
+fn test_set_max_penalty_validation() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1000);
+    let invalid = Some(Ok(BondError::InvalidPenaltyBps.into()));
+    // Above the hard cap, or below the rate in force
+    assert_eq!(
+        client.try_set_early_exit_max_penalty(&admin, &5_001).err(),
+        invalid
+    );
+    assert_eq!(
+        client.try_set_early_exit_max_penalty(&admin, &999).err(),
+        invalid
+    );
+    assert_eq!(
+        client
+            .try_set_early_exit_max_penalty(&Address::generate(&e), &2_000)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    client.set_early_exit_max_penalty(&admin, &1_000);
+    assert_eq!(client.get_early_exit_max_penalty(), 1_000);
+}
+
+#[test]
+fn test_rate_stored_above_ceiling_charged_at_ceiling() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, _admin) = setup(&e, &treasury, 1000);
+    // A rate written before the ceiling existed
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "early_exit_penalty_bps"), &10_000_u32);
+    });
+    assert_eq!(
+        client.get_early_exit_config(),
+        EarlyExitConfig {
+            treasury,
+            penalty_bps: 5_000,
+        }
+    );
+
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &100_u64, &false, &0_u64);
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, _, penalty, _, _, _, _, _) = last_penalty_event(&e);
+    assert_eq!(penalty, 50_000_000);
+}
+
+#[test]
+fn test_quote_matches_withdraw_early_under_surge() {
+    let e = Env::default();
+    let (client, identity) = setup_surge(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1250);
+    // 20% outflow in the window: the next early exit pays the 1.5x surge
+    client.withdraw_early(&identity, &200_000_000);
+
+    let quote = client.quote_early_exit(&identity, &100_000_000);
+    assert!(!quote.lockup_ended);
+    assert_eq!(quote.remaining_secs, 750);
+    assert_eq!(quote.surge_multiplier_bps, 15_000);
+    // 10% * 750/1000 = 7.5%, surged to 11.25%
+    assert_eq!(quote.penalty, 11_250_000);
+    assert_eq!(quote.effective_bps, 1_125);
+    assert_eq!(quote.net, 100_000_000 - quote.penalty);
+
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, amount, penalty, _, surge, _, _, _) = last_penalty_event(&e);
+    assert_eq!(
+        (amount, penalty, surge),
+        (100_000_000, quote.penalty, quote.surge_multiplier_bps)
+    );
+    let receipt = client.get_withdrawal_receipt(&client.get_receipt_count());
+    assert_eq!(receipt.net, quote.net);
+}
+
+#[test]
+fn test_quote_is_read_only() {
+    let e = Env::default();
+    let (client, identity) = setup_surge(&e);
+    let first = client.quote_early_exit(&identity, &400_000_000);
+    assert!(e.events().all().is_empty());
+    // Quoting does not count as outflow, so the surge stays off
+    let second = client.quote_early_exit(&identity, &400_000_000);
+    assert_eq!(first, second);
+    assert_eq!(second.surge_multiplier_bps, 10_000);
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        1_000_000_000
+    );
+}
+
+#[test]
+fn test_quote_after_lockup_is_penalty_free() {
+    let e = Env::default();
+    let (client, identity) = setup_surge(&e);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    let quote = client.quote_early_exit(&identity, &100_000_000);
+    assert!(quote.lockup_ended);
+    assert_eq!(
+        (
+            quote.penalty,
+            quote.net,
+            quote.remaining_secs,
+            quote.effective_bps
+        ),
+        (0, 100_000_000, 0, 0)
+    );
+}
+
+#[test]
+fn test_quote_rejects_amount_above_balance() {
+    let e = Env::default();
+    let (client, identity) = setup_surge(&e);
+    assert_eq!(
+        client.try_quote_early_exit(&identity, &1_000_000_001).err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+}
+
+#[test]
+fn test_quote_rejects_non_positive_amount() {
+    let e = Env::default();
+    let (client, identity) = setup_surge(&e);
+    assert_eq!(
+        client.try_quote_early_exit(&identity, &0).err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client.try_quote_early_exit(&identity, &-1).err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+}
+
+/// Bond of 1000 tokens over t=1000..2000 with 10% above 75% remaining, 5% from 25% to 75% and
+/// 1% in the final quarter.
+fn setup_brackets(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(e);
+    let (client, admin) = setup(e, &treasury, 300);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    let brackets = vec![
+        e,
+        (2_500_u32, 100_u32),
+        (7_500_u32, 500_u32),
+        (10_000_u32, 1_000_u32),
+    ];
+    client.set_early_exit_brackets(&admin, &brackets);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_bracket_boundaries() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_brackets(&e);
+    // (timestamp, expected rate, selected bracket): remaining shares 7510, 7500, 2510, 2500
+    let cases = [
+        (1249_u64, 1_000_i128, (10_000_u32, 1_000_u32)),
+        (1250, 500, (7_500, 500)),
+        (1749, 500, (7_500, 500)),
+        (1750, 100, (2_500, 100)),
+    ];
+    for (timestamp, rate, bracket) in cases {
+        e.ledger().with_mut(|li| li.timestamp = timestamp);
+        client.withdraw_early(&identity, &10_000_000);
+        let (_, _, penalty, _, _, selected, _, _) = last_penalty_event(&e);
+        assert_eq!(penalty, 10_000_000 * rate / 10_000, "t={timestamp}");
+        assert_eq!(selected, Some(bracket), "t={timestamp}");
+    }
+}
+
+#[test]
+fn test_brackets_unconfigured_fall_back_to_single_rate() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_brackets(&e);
+    client.set_early_exit_brackets(&admin, &vec![&e]);
+    assert_eq!(client.get_early_exit_brackets().len(), 0);
+    e.ledger().with_mut(|li| li.timestamp = 1500);
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, _, penalty, _, _, selected, _, _) = last_penalty_event(&e);
+    // 3% pro-rated over the half still remaining
+    assert_eq!(penalty, 1_500_000);
+    assert_eq!(selected, None);
+}
+
+#[test]
+fn test_brackets_validation() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup_brackets(&e);
+    let unsorted = vec![&e, (7_500_u32, 500_u32), (2_500_u32, 100_u32)];
+    assert_eq!(
+        client.try_set_early_exit_brackets(&admin, &unsorted).err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+    let out_of_range = vec![&e, (10_001_u32, 100_u32)];
+    assert_eq!(
+        client
+            .try_set_early_exit_brackets(&admin, &out_of_range)
+            .err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+    let above_ceiling = vec![&e, (10_000_u32, 5_001_u32)];
+    assert_eq!(
+        client
+            .try_set_early_exit_brackets(&admin, &above_ceiling)
+            .err(),
+        Some(Ok(BondError::InvalidPenaltyBps.into()))
+    );
+    // The ceiling cannot drop below a bracket rate
+    assert_eq!(
+        client.try_set_early_exit_max_penalty(&admin, &999).err(),
+        Some(Ok(BondError::InvalidPenaltyBps.into()))
+    );
+    assert_eq!(
+        client
+            .try_set_early_exit_brackets(&Address::generate(&e), &vec![&e])
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_penalty_amount_cap() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1_000); // 10%
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    assert_eq!(client.get_early_exit_max_amount(), 0);
+    client.set_early_exit_max_amount(&admin, &5_000_000);
+
+    // Below the cap: unaffected
+    client.withdraw_early(&identity, &10_000_000);
+    let (_, _, penalty, _, _, _, _, _) = last_penalty_event(&e);
+    assert_eq!(penalty, 1_000_000);
+
+    // 10% of 500M would be 50M; clamped, and the event carries the clamped value
+    client.withdraw_early(&identity, &500_000_000);
+    let (_, amount, penalty, _, _, _, _, _) = last_penalty_event(&e);
+    assert_eq!((amount, penalty), (500_000_000, 5_000_000));
+    let receipt_id = client.get_receipt_count();
+    assert_eq!(
+        client.get_withdrawal_receipt(&receipt_id).penalty,
+        5_000_000
+    );
+    let breakdown = client.explain_charge(&receipt_id);
+    assert_eq!(breakdown.base_amount, 50_000_000);
+    assert_eq!(
+        breakdown.discounts,
+        vec![&e, (Symbol::new(&e, "penalty_cap"), 45_000_000_i128)]
+    );
+
+    // 0 restores the uncapped penalty
+    client.set_early_exit_max_amount(&admin, &0);
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, _, penalty, _, _, _, _, _) = last_penalty_event(&e);
+    assert_eq!(penalty, 10_000_000);
+}
+
+#[test]
+fn test_penalty_amount_cap_validation() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 500);
+    assert_eq!(
+        client.try_set_early_exit_max_amount(&admin, &-1).err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+    assert_eq!(
+        client
+            .try_set_early_exit_max_amount(&Address::generate(&e), &1)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_penalty_waiver_expires_mid_lockup() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1_000); // 10%
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    assert!(!client.has_penalty_waiver(&identity));
+    client.add_penalty_waiver(&admin, &identity, &1500);
+    assert!(client.has_penalty_waiver(&identity));
+
+    e.ledger().with_mut(|li| li.timestamp = 1499);
+    let quote = client.quote_early_exit(&identity, &100_000_000);
+    assert_eq!(quote.penalty, 0);
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, amount, penalty, _, _, _, waived, _) = last_penalty_event(&e);
+    assert_eq!((amount, penalty, waived), (100_000_000, 0, true));
+
+    // Expired at 1500 with half the lock-up left: charged as if never waived
+    e.ledger().with_mut(|li| li.timestamp = 1500);
+    assert!(!client.has_penalty_waiver(&identity));
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, _, penalty, _, _, _, waived, _) = last_penalty_event(&e);
+    assert_eq!((penalty, waived), (5_000_000, false));
+}
+
+#[test]
+fn test_penalty_waiver_removed() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1_000);
+    let identity = Address::generate(&e);
+    let other = Address::generate(&e);
+    client.create_bond(&identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    client.create_bond(&other, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    client.add_penalty_waiver(&admin, &identity, &2000);
+
+    // Waivers are per identity
+    client.withdraw_early(&other, &100_000_000);
+    let (_, _, penalty, _, _, _, waived, _) = last_penalty_event(&e);
+    assert_eq!((penalty, waived), (10_000_000, false));
+
+    client.remove_penalty_waiver(&admin, &identity);
+    assert!(!client.has_penalty_waiver(&identity));
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, _, penalty, _, _, _, waived, _) = last_penalty_event(&e);
+    assert_eq!((penalty, waived), (10_000_000, false));
+}
+
+#[test]
+fn test_penalty_waiver_validation() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1_000);
+    let identity = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_add_penalty_waiver(&admin, &identity, &1000)
+            .err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+    assert_eq!(
+        client
+            .try_add_penalty_waiver(&identity, &identity, &2000)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_fee_accounting_totals_penalties() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1_000); // 10%
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+    client.create_bond(&alice, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    client.create_bond(&bob, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    let accounting = client.get_fee_accounting();
+    assert_eq!(
+        (
+            accounting.total_penalties_collected,
+            accounting.penalty_count
+        ),
+        (0, 0)
+    );
+
+    client.withdraw_early(&alice, &100_000_000); // 10% of 100M, full lock-up left
+    let (_, _, _, _, _, _, _, total) = last_penalty_event(&e);
+    assert_eq!(total, 10_000_000);
+
+    e.ledger().with_mut(|li| li.timestamp = 1500);
+    client.withdraw_early(&alice, &200_000_000); // 10M, half the lock-up left
+    client.withdraw_early(&bob, &100_000_000); // 5M
+
+    // A waived exit collects nothing and is not counted
+    client.add_penalty_waiver(&admin, &bob, &2000);
+    client.withdraw_early(&bob, &100_000_000);
+    let (_, _, penalty, _, _, _, _, total) = last_penalty_event(&e);
+    assert_eq!((penalty, total), (0, 25_000_000));
+
+    let accounting = client.get_fee_accounting();
+    assert_eq!(accounting.total_penalties_collected, 25_000_000);
+    assert_eq!(accounting.penalty_count, 3);
+}
+
+#[test]
+fn test_treasury_cannot_be_contract() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1_000);
+    assert_eq!(
+        client
+            .try_set_early_exit_config(&admin, &client.address, &1_000)
+            .err(),
+        Some(Ok(TreasuryError::InvalidTreasury.into()))
+    );
+    assert_eq!(
+        client
+            .try_preview_early_exit_config(&admin, &client.address, &1_000)
+            .err(),
+        Some(Ok(TreasuryError::InvalidTreasury.into()))
+    );
+    assert_eq!(
+        client.get_early_exit_config(),
+        EarlyExitConfig {
+            treasury,
+            penalty_bps: 1_000,
+        }
+    );
+}
+
+#[test]
+fn test_treasury_cannot_charge_itself() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, _admin) = setup(&e, &treasury, 1_000);
+    let other = Address::generate(&e);
+    client.create_bond(&treasury, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+    client.create_bond(&other, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+
+    // The treasury's own bond cannot pay its penalty to itself
+    assert_eq!(
+        client.try_withdraw_early(&treasury, &100_000_000).err(),
+        Some(Ok(TreasuryError::InvalidTreasury.into()))
+    );
+    assert_eq!(
+        client.try_quote_early_exit(&treasury, &100_000_000).err(),
+        Some(Ok(TreasuryError::InvalidTreasury.into()))
+    );
+    client.withdraw_early(&other, &100_000_000);
+    assert_eq!(
+        client.get_fee_accounting().total_penalties_collected,
+        10_000_000
+    );
+}
+
+#[test]
+fn test_burn_mode_burns_penalties() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin) = setup(&e, &treasury, 1_000); // 10%
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    let token = TokenClient::new(&e, &sac.address());
+    client.set_token(&admin, &sac.address());
+    let identity = Address::generate(&e);
+    StellarAssetClient::new(&e, &sac.address()).mint(&identity, &1_000_000_000);
+    client.create_bond(&identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+
+    assert!(!client.get_early_exit_burn());
+    client.set_early_exit_burn(&admin, &true);
+    assert!(client.get_early_exit_burn());
+    client.withdraw_early(&identity, &100_000_000);
+    let (_, _, penalty, recipient, _, _, _, total) = last_penalty_event(&e);
+    assert_eq!((penalty, total), (10_000_000, 10_000_000));
+    assert_eq!(recipient, client.address);
+
+    // The penalty left the contract without reaching anyone
+    assert_eq!(token.balance(&identity), 90_000_000);
+    assert_eq!(token.balance(&treasury), 0);
+    assert_eq!(token.balance(&client.address), 900_000_000);
+
+    // Back to routing: only the penalties burned above stay in the burned total
+    client.set_early_exit_burn(&admin, &false);
+    client.withdraw_early(&identity, &100_000_000);
+    assert_eq!(token.balance(&treasury), 10_000_000);
+    let accounting = client.get_fee_accounting();
+    assert_eq!(accounting.total_penalties_collected, 20_000_000);
+    assert_eq!(accounting.total_penalties_burned, 10_000_000);
+    assert_eq!(accounting.penalty_count, 2);
+
+    assert_eq!(
+        client.try_set_early_exit_burn(&identity, &true).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_early_exit_config_updated_event() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    assert_eq!(
+        client.try_get_early_exit_config().err(),
+        Some(Ok(BondError::EarlyExitConfigNotSet.into()))
+    );
+
+    let treasury = Address::generate(&e);
+    let first = EarlyExitConfig {
+        treasury: treasury.clone(),
+        penalty_bps: 500,
+    };
+    client.set_early_exit_config(&admin, &treasury, &500);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "early_exit_config_updated"),).into_val(&e)
+    );
+    let data: (Option<EarlyExitConfig>, EarlyExitConfig) = data.into_val(&e);
+    assert_eq!(data, (None, first.clone()));
+
+    let new_treasury = Address::generate(&e);
+    client.set_early_exit_config(&admin, &new_treasury, &800);
+    let (_, _, data) = e.events().all().last().unwrap();
+    let second = EarlyExitConfig {
+        treasury: new_treasury,
+        penalty_bps: 800,
+    };
+    let data: (Option<EarlyExitConfig>, EarlyExitConfig) = data.into_val(&e);
+    assert_eq!(data, (Some(first), second.clone()));
+    assert_eq!(client.get_early_exit_config(), second);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_apply_surge_overflow() {
+    let e = Env::default();
+    let _ = early_exit_penalty::apply_surge(&e, i128::MAX, i128::MAX, 20_000);
+}
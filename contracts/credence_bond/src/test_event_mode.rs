@@ -0,0 +1,157 @@
+//! Tests for the event payload mode: rich and compact payloads for the same operation, and
+//! reconstruction of the compact events' missing detail from the record getters.
+
+#![cfg(test)]
+
+use crate::{BondError, BondTier, CredenceBond, CredenceBondClient, EventMode};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, BytesN, Env, Symbol, TryFromVal, Val};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+/// Data of the first event of the last invocation whose first topic is `name`.
+fn event_data<T: TryFromVal<Env, Val>>(e: &Env, name: &str) -> T {
+    let topic = Symbol::new(e, name);
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .is_some_and(|t| Symbol::try_from_val(e, &t).is_ok_and(|s| s == topic))
+        })
+        .expect("event not published");
+    T::try_from_val(e, &data).expect("unexpected payload shape")
+}
+
+#[test]
+fn test_default_mode_is_rich() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_event_mode(), EventMode::Rich);
+}
+
+#[test]
+fn test_set_event_mode_admin_only() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client
+            .try_set_event_mode(&Address::generate(&e), &EventMode::Compact)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_bond_created_payload_by_mode() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+
+    let rich_identity = Address::generate(&e);
+    client.create_bond(&rich_identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    let rich: (Address, i128, u64, u64, bool, u64, BondTier) = event_data(&e, "bond_created");
+    assert_eq!(
+        rich,
+        (
+            rich_identity,
+            BOND_AMOUNT,
+            1000,
+            86400,
+            false,
+            0,
+            BondTier::Silver
+        )
+    );
+
+    client.set_event_mode(&admin, &EventMode::Compact);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    let compact: (Address, i128) = event_data(&e, "bond_created");
+    assert_eq!(compact, (identity.clone(), BOND_AMOUNT));
+
+    // The rest is in the bond state
+    let bond = client.get_identity_state(&identity);
+    assert_eq!(
+        (bond.bond_start, bond.bond_duration, bond.is_rolling),
+        (1000, 86400, false)
+    );
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+
+    // Switching back applies immediately
+    client.set_event_mode(&admin, &EventMode::Rich);
+    let other = Address::generate(&e);
+    client.create_bond(&other, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    let rich: (Address, i128, u64, u64, bool, u64, BondTier) = event_data(&e, "bond_created");
+    assert_eq!(rich.0, other);
+}
+
+#[test]
+fn test_compact_slash_recorded_reconstructed_from_record() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    let reason = Symbol::new(&e, "fraud");
+
+    client.slash_with_reason(&admin, &identity, &1_000_000, &reason);
+    let rich: (u64, Symbol) = event_data(&e, "slash_recorded");
+    assert_eq!(rich, (1, reason.clone()));
+
+    client.set_event_mode(&admin, &EventMode::Compact);
+    client.slash_with_reason(&admin, &identity, &2_000_000, &reason);
+    let id: u64 = event_data(&e, "slash_recorded");
+    // Amounts stay in the bond_slashed event in both modes
+    let slashed: (Address, i128, i128) = event_data(&e, "bond_slashed");
+    assert_eq!(slashed, (identity.clone(), 2_000_000, 3_000_000));
+
+    let record = client.get_slash_record(&id);
+    assert_eq!(record.reason, reason);
+    assert_eq!(record.identity, identity);
+    assert_eq!(record.applied_amount, 2_000_000);
+}
+
+#[test]
+fn test_compact_pledge_reconstructed_from_record() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    let counterparty = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    client.set_event_mode(&admin, &EventMode::Compact);
+
+    let agreement = BytesN::from_array(&e, &[1; 32]);
+    client.pledge(&identity, &agreement, &counterparty, &1_000_000, &5000);
+    let compact: (BytesN<32>, i128) = event_data(&e, "bond_pledged");
+    assert_eq!(compact, (agreement.clone(), 1_000_000));
+
+    let pledge = client.get_pledge(&compact.0);
+    assert_eq!(
+        (pledge.identity, pledge.counterparty, pledge.ends_at),
+        (identity, counterparty, 5000)
+    );
+}
+
+#[test]
+fn test_unchanged_events_identical_in_compact_mode() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    client.set_event_mode(&admin, &EventMode::Compact);
+
+    client.top_up(&identity, &1_000_000);
+    let topped_up: (Address, i128, i128) = event_data(&e, "bond_topped_up");
+    assert_eq!(topped_up, (identity, 1_000_000, BOND_AMOUNT + 1_000_000));
+}
@@ -0,0 +1,214 @@
+//! Tests for the contract-wide pause switch: guarded entry points fail while paused and work
+//! again after unpause; admin slashing is unaffected.
+
+#![cfg(test)]
+
+use crate::{BondError, CredenceBond, CredenceBondClient, WithdrawalIntent};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    client.set_early_exit_config(&admin, &Address::generate(e), &1000);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+/// Set up a rolling bond instead of a fixed one.
+fn setup_rolling(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, _identity) = setup(e);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    (client, admin, identity)
+}
+
+fn paused_err() -> Option<Result<soroban_sdk::Error, soroban_sdk::InvokeError>> {
+    Some(Ok(BondError::ContractPaused.into()))
+}
+
+#[test]
+fn test_not_paused_by_default() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    assert!(!client.is_paused());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_pause_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.pause(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_unpause_requires_admin() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    client.pause(&admin);
+    client.unpause(&Address::generate(&e));
+}
+
+#[test]
+fn test_pause_and_unpause_emit_events() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+
+    client.pause(&admin);
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "paused"),).into_val(&e));
+    let (by, at): (Address, u64) = data.into_val(&e);
+    assert_eq!((by, at), (admin.clone(), 1000));
+    assert!(client.is_paused());
+
+    client.unpause(&admin);
+    let events = e.events().all();
+    let (_, topics, _) = events.last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "unpaused"),).into_val(&e));
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_create_bond_paused() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    let other = Address::generate(&e);
+    client.pause(&admin);
+    let result = client.try_create_bond(&other, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+    assert_eq!(result.err(), paused_err());
+
+    client.unpause(&admin);
+    client.create_bond(&other, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+}
+
+#[test]
+fn test_top_up_paused() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.pause(&admin);
+    assert_eq!(
+        client.try_top_up(&identity, &BOND_AMOUNT).err(),
+        paused_err()
+    );
+
+    client.unpause(&admin);
+    let bond = client.top_up(&identity, &BOND_AMOUNT);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT * 2);
+}
+
+#[test]
+fn test_withdraw_paused() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    client.pause(&admin);
+    assert_eq!(
+        client.try_withdraw(&identity, &BOND_AMOUNT).err(),
+        paused_err()
+    );
+
+    client.unpause(&admin);
+    let bond = client.withdraw(&identity, &BOND_AMOUNT);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+fn test_withdraw_early_paused() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.pause(&admin);
+    assert_eq!(
+        client.try_withdraw_early(&identity, &BOND_AMOUNT).err(),
+        paused_err()
+    );
+
+    client.unpause(&admin);
+    let bond = client.withdraw_early(&identity, &BOND_AMOUNT);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+fn test_withdraw_bond_paused() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    client.pause(&admin);
+    assert_eq!(client.try_withdraw_bond(&identity).err(), paused_err());
+
+    client.unpause(&admin);
+    assert_eq!(client.withdraw_bond(&identity), BOND_AMOUNT);
+}
+
+#[test]
+fn test_withdrawal_intent_paused() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    let intent = WithdrawalIntent {
+        identity: identity.clone(),
+        amount: BOND_AMOUNT,
+        destination: Address::generate(&e),
+        deadline: 1300,
+        nonce: 1,
+    };
+    client.pause(&admin);
+    assert_eq!(
+        client.try_execute_withdrawal_intent(&intent).err(),
+        paused_err()
+    );
+
+    // The nonce was not consumed by the refused attempt
+    client.unpause(&admin);
+    let bond = client.execute_withdrawal_intent(&intent);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+fn test_request_withdrawal_paused() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_rolling(&e);
+    client.pause(&admin);
+    assert_eq!(client.try_request_withdrawal(&identity).err(), paused_err());
+
+    client.unpause(&admin);
+    let bond = client.request_withdrawal(&identity);
+    assert_eq!(bond.withdrawal_requested_at, 1000);
+}
+
+#[test]
+fn test_renew_if_rolling_paused() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_rolling(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    client.pause(&admin);
+    assert_eq!(client.try_renew_if_rolling(&identity).err(), paused_err());
+
+    client.unpause(&admin);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 1200);
+}
+
+#[test]
+fn test_slash_allowed_while_paused() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.pause(&admin);
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(BOND_AMOUNT / 2),
+        &symbol_short!("test"),
+        &0,
+    );
+    assert_eq!(bond.slashed_amount, BOND_AMOUNT / 2);
+}
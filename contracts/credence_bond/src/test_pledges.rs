@@ -0,0 +1,272 @@
+//! Tests for service agreement pledges: encumbrance, counterparty slashing limited to the
+//! pledged amount, and automatic release at the end of the agreement.
+
+#![cfg(test)]
+
+use crate::{BondError, CredenceBond, CredenceBondClient, PledgeError};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, Symbol};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+const PLEDGED: i128 = 400_000_000;
+const ENDS_AT: u64 = 5000;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    identity: Address,
+    counterparty: Address,
+    agreement: BytesN<32>,
+}
+
+/// Matured fixed bond with `PLEDGED` pledged until `ENDS_AT`.
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    client.initialize(&Address::generate(e));
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 2000); // lock-up ended
+
+    let counterparty = Address::generate(e);
+    let agreement = BytesN::from_array(e, &[1; 32]);
+    client.pledge(&identity, &agreement, &counterparty, &PLEDGED, &ENDS_AT);
+    Setup {
+        client,
+        identity,
+        counterparty,
+        agreement,
+    }
+}
+
+fn evidence(e: &Env) -> BytesN<32> {
+    BytesN::from_array(e, &[9; 32])
+}
+
+#[test]
+fn test_pledge_encumbers_bond() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert_eq!(s.client.get_encumbered_amount(&s.identity), PLEDGED);
+    let pledge = s.client.get_pledge(&s.agreement);
+    assert_eq!(pledge.amount, PLEDGED);
+    assert_eq!(pledge.counterparty, s.counterparty);
+    assert_eq!(pledge.slashed, 0);
+}
+
+#[test]
+fn test_pledge_emits_event() {
+    let e = Env::default();
+    let s = setup(&e);
+    let other = BytesN::from_array(&e, &[2; 32]);
+    s.client
+        .pledge(&s.identity, &other, &s.counterparty, &1_000_000, &ENDS_AT);
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "bond_pledged"),).into_val(&e));
+    let data: (Address, BytesN<32>, Address, i128, u64) = data.into_val(&e);
+    assert_eq!(
+        data,
+        (
+            s.identity.clone(),
+            other,
+            s.counterparty.clone(),
+            1_000_000,
+            ENDS_AT
+        )
+    );
+}
+
+#[test]
+fn test_withdrawal_ceiling_while_pledged() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert_eq!(
+        s.client.try_withdraw(&s.identity, &BOND_AMOUNT).err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+    assert_eq!(
+        s.client.try_withdraw_bond(&s.identity).err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+    let bond = s.client.withdraw(&s.identity, &(BOND_AMOUNT - PLEDGED));
+    assert_eq!(bond.bonded_amount, PLEDGED);
+}
+
+#[test]
+fn test_cannot_pledge_more_than_free_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    let other = BytesN::from_array(&e, &[2; 32]);
+    assert_eq!(
+        s.client
+            .try_pledge(
+                &s.identity,
+                &other,
+                &s.counterparty,
+                &(BOND_AMOUNT - PLEDGED + 1),
+                &ENDS_AT
+            )
+            .err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+    assert_eq!(
+        s.client
+            .try_pledge(
+                &s.identity,
+                &s.agreement,
+                &s.counterparty,
+                &1_000_000,
+                &ENDS_AT
+            )
+            .err(),
+        Some(Ok(PledgeError::AlreadyExists.into()))
+    );
+}
+
+#[test]
+fn test_partial_slash_pledge() {
+    let e = Env::default();
+    let s = setup(&e);
+    let bond = s
+        .client
+        .slash_pledge(&s.counterparty, &s.agreement, &100_000_000, &evidence(&e));
+    assert_eq!(bond.slashed_amount, 100_000_000);
+
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "pledge_slashed"),).into_val(&e));
+    let data: (BytesN<32>, Address, i128, BytesN<32>) = data.into_val(&e);
+    assert_eq!(
+        data,
+        (
+            s.agreement.clone(),
+            s.counterparty.clone(),
+            100_000_000,
+            evidence(&e)
+        )
+    );
+
+    assert_eq!(s.client.get_pledge(&s.agreement).slashed, 100_000_000);
+    assert_eq!(s.client.get_encumbered_amount(&s.identity), 300_000_000);
+    let record = s
+        .client
+        .get_slash_record(&s.client.get_latest_slash_record_id());
+    assert_eq!(record.reason, Symbol::new(&e, "pledge"));
+    assert_eq!(record.admin, s.counterparty);
+    assert_eq!(record.applied_amount, 100_000_000);
+    // Unpledged balance stays withdrawable: 1000 - 100 slashed - 300 still pledged
+    let bond = s.client.withdraw(&s.identity, &600_000_000);
+    assert_eq!(bond.bonded_amount, 400_000_000);
+}
+
+#[test]
+fn test_slash_limited_to_pledged_amount() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .slash_pledge(&s.counterparty, &s.agreement, &300_000_000, &evidence(&e));
+    assert_eq!(
+        s.client
+            .try_slash_pledge(&s.counterparty, &s.agreement, &100_000_001, &evidence(&e))
+            .err(),
+        Some(Ok(PledgeError::ExceedsPledge.into()))
+    );
+    s.client
+        .slash_pledge(&s.counterparty, &s.agreement, &100_000_000, &evidence(&e));
+    assert_eq!(s.client.get_encumbered_amount(&s.identity), 0);
+}
+
+#[test]
+fn test_only_counterparty_can_slash() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert_eq!(
+        s.client
+            .try_slash_pledge(&s.identity, &s.agreement, &1_000_000, &evidence(&e))
+            .err(),
+        Some(Ok(PledgeError::NotCounterparty.into()))
+    );
+    let unknown = BytesN::from_array(&e, &[7; 32]);
+    assert_eq!(
+        s.client
+            .try_slash_pledge(&s.counterparty, &unknown, &1_000_000, &evidence(&e))
+            .err(),
+        Some(Ok(PledgeError::NotFound.into()))
+    );
+}
+
+#[test]
+fn test_pledge_released_at_agreement_end() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    e.ledger().with_mut(|li| li.timestamp = ENDS_AT - 1);
+    assert_eq!(s.client.get_encumbered_amount(&s.identity), PLEDGED);
+
+    e.ledger().with_mut(|li| li.timestamp = ENDS_AT);
+    assert_eq!(s.client.get_encumbered_amount(&s.identity), 0);
+    assert_eq!(
+        s.client
+            .try_slash_pledge(&s.counterparty, &s.agreement, &1_000_000, &evidence(&e))
+            .err(),
+        Some(Ok(PledgeError::Ended.into()))
+    );
+    let bond = s.client.withdraw(&s.identity, &BOND_AMOUNT);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+fn test_live_pledge_cap() {
+    let e = Env::default();
+    let s = setup(&e);
+    for i in 2..11u8 {
+        let id = BytesN::from_array(&e, &[i; 32]);
+        s.client
+            .pledge(&s.identity, &id, &s.counterparty, &1_000_000, &ENDS_AT);
+    }
+    let eleventh = BytesN::from_array(&e, &[11; 32]);
+    assert_eq!(
+        s.client
+            .try_pledge(
+                &s.identity,
+                &eleventh,
+                &s.counterparty,
+                &1_000_000,
+                &ENDS_AT
+            )
+            .err(),
+        Some(Ok(PledgeError::TooMany.into()))
+    );
+
+    // Ended pledges no longer count toward the cap
+    e.ledger().with_mut(|li| li.timestamp = ENDS_AT);
+    s.client.pledge(
+        &s.identity,
+        &eleventh,
+        &s.counterparty,
+        &1_000_000,
+        &(ENDS_AT + 10),
+    );
+}
+
+#[test]
+fn test_pledge_rejects_invalid_terms() {
+    let e = Env::default();
+    let s = setup(&e);
+    let other = BytesN::from_array(&e, &[2; 32]);
+    let invalid = Some(Ok(BondError::InvalidAmount.into()));
+    assert_eq!(
+        s.client
+            .try_pledge(&s.identity, &other, &s.counterparty, &0, &ENDS_AT)
+            .err(),
+        invalid
+    );
+    assert_eq!(
+        s.client
+            .try_pledge(&s.identity, &other, &s.counterparty, &1_000_000, &2000)
+            .err(),
+        invalid
+    );
+}
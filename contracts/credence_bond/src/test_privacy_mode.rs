@@ -0,0 +1,129 @@
+//! Tests for privacy mode: restricted bond detail reads and the public tier attestation.
+
+#![cfg(test)]
+
+use crate::{BondTier, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, Env, IntoVal};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+/// Authorize exactly one `get_identity_state_as(reader, identity)` call, signed by `reader`.
+fn authorize_read(e: &Env, client: &CredenceBondClient<'_>, reader: &Address, identity: &Address) {
+    e.mock_auths(&[MockAuth {
+        address: reader,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "get_identity_state_as",
+            args: (reader.clone(), identity.clone()).into_val(e),
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn test_privacy_mode_off_by_default() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    assert!(!client.get_privacy_mode());
+
+    // Anyone can read without authorization
+    e.set_auths(&[]);
+    let stranger = Address::generate(&e);
+    let bond = client.get_identity_state_as(&stranger, &identity);
+    assert_eq!(bond.bonded_amount, 1_000_000_000);
+    client.get_identity_state(&identity);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_privacy_mode_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.set_privacy_mode(&Address::generate(&e), &true);
+}
+
+#[test]
+fn test_identity_can_read_own_bond() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_privacy_mode(&admin, &true);
+
+    authorize_read(&e, &client, &identity, &identity);
+    let bond = client.get_identity_state_as(&identity, &identity);
+    assert_eq!(bond.identity, identity);
+}
+
+#[test]
+fn test_admin_can_read_any_bond() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_privacy_mode(&admin, &true);
+
+    authorize_read(&e, &client, &admin, &identity);
+    let bond = client.get_identity_state_as(&admin, &identity);
+    assert_eq!(bond.identity, identity);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_stranger_read_rejected() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_privacy_mode(&admin, &true);
+
+    let stranger = Address::generate(&e);
+    authorize_read(&e, &client, &stranger, &identity);
+    client.get_identity_state_as(&stranger, &identity);
+}
+
+#[test]
+fn test_unauthorized_read_rejected() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_privacy_mode(&admin, &true);
+
+    // Claiming to be the identity or admin without their signature fails
+    e.set_auths(&[]);
+    assert!(client.try_get_identity_state(&identity).is_err());
+    assert!(client.try_get_identity_state_as(&admin, &identity).is_err());
+}
+
+#[test]
+fn test_balance_views_restricted() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_privacy_mode(&admin, &true);
+
+    e.set_auths(&[]);
+    assert!(client.try_get_available_balance(&identity).is_err());
+    assert!(client.try_get_bonded_amount(&identity).is_err());
+    assert!(client.try_get_slashed_amount(&identity).is_err());
+
+    e.mock_all_auths();
+    assert_eq!(client.get_available_balance(&identity), 1_000_000_000);
+}
+
+#[test]
+fn test_attest_min_tier_remains_public() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.set_privacy_mode(&admin, &true);
+    e.set_auths(&[]);
+
+    // 1000 tokens bonded: Silver
+    assert!(client.attest_min_tier(&identity, &BondTier::Bronze));
+    assert!(client.attest_min_tier(&identity, &BondTier::Silver));
+    assert!(!client.attest_min_tier(&identity, &BondTier::Gold));
+    assert!(!client.attest_min_tier(&Address::generate(&e), &BondTier::Bronze));
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+}
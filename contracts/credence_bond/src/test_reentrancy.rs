@@ -75,7 +75,7 @@ mod slash_attacker {
                 .get(&Symbol::new(&e, "admin"))
                 .unwrap();
             let client = CredenceBondClient::new(&e, &bond_addr);
-            client.slash_bond(&admin, &100_i128);
+            client.slash_bond(&admin, &identity, &100_i128);
         }
 
         pub fn setup(e: Env, target: Address, admin: Address) {
@@ -160,7 +160,7 @@ mod cross_attacker {
                 .get(&Symbol::new(&e, "admin"))
                 .unwrap();
             let client = CredenceBondClient::new(&e, &bond_addr);
-            client.slash_bond(&admin, &100_i128);
+            client.slash_bond(&admin, &identity, &100_i128);
         }
 
         pub fn setup(e: Env, target: Address, admin: Address) {
@@ -184,7 +184,7 @@ use withdraw_attacker::{WithdrawAttacker, WithdrawAttackerClient};
 // Helper: set up a bond contract with admin, identity, and a bond.
 // ---------------------------------------------------------------------------
 fn setup_bond(e: &Env) -> (Address, Address, Address) {
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
 
     let admin = Address::generate(e);
@@ -207,7 +207,7 @@ fn test_withdraw_reentrancy_blocked() {
     let (bond_id, _admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    let attacker_id = e.register_contract(None, WithdrawAttacker);
+    let attacker_id = e.register(WithdrawAttacker, ());
     let attacker_client = WithdrawAttackerClient::new(&e, &attacker_id);
     attacker_client.setup(&bond_id, &identity);
     client.set_callback(&attacker_id);
@@ -226,12 +226,12 @@ fn test_slash_reentrancy_blocked() {
     let (bond_id, admin, _identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    let attacker_id = e.register_contract(None, SlashAttacker);
+    let attacker_id = e.register(SlashAttacker, ());
     let attacker_client = SlashAttackerClient::new(&e, &attacker_id);
     attacker_client.setup(&bond_id, &admin);
     client.set_callback(&attacker_id);
 
-    client.slash_bond(&admin, &500_i128);
+    client.slash_bond(&admin, &identity, &500_i128);
 }
 
 // ===========================================================================
@@ -247,7 +247,7 @@ fn test_fee_collection_reentrancy_blocked() {
 
     client.deposit_fees(&500_i128);
 
-    let attacker_id = e.register_contract(None, FeeAttacker);
+    let attacker_id = e.register(FeeAttacker, ());
     let attacker_client = FeeAttackerClient::new(&e, &attacker_id);
     attacker_client.setup(&bond_id, &admin);
     client.set_callback(&attacker_id);
@@ -278,7 +278,7 @@ fn test_lock_released_after_withdraw() {
     let (bond_id, _admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    let benign_id = e.register_contract(None, BenignCallback);
+    let benign_id = e.register(BenignCallback, ());
     client.set_callback(&benign_id);
 
     client.withdraw_bond(&identity);
@@ -295,10 +295,10 @@ fn test_lock_released_after_slash() {
     let (bond_id, admin, _identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    let benign_id = e.register_contract(None, BenignCallback);
+    let benign_id = e.register(BenignCallback, ());
     client.set_callback(&benign_id);
 
-    client.slash_bond(&admin, &100_i128);
+    client.slash_bond(&admin, &identity, &100_i128);
     assert!(!client.is_locked());
 }
 
@@ -314,7 +314,7 @@ fn test_lock_released_after_fee_collection() {
 
     client.deposit_fees(&200_i128);
 
-    let benign_id = e.register_contract(None, BenignCallback);
+    let benign_id = e.register(BenignCallback, ());
     client.set_callback(&benign_id);
 
     let collected = client.collect_fees(&admin);
@@ -335,7 +335,7 @@ fn test_normal_withdraw_succeeds() {
     let amount = client.withdraw_bond(&identity);
     assert_eq!(amount, 10_000_i128);
 
-    let state = client.get_identity_state();
+    let state = client.get_identity_state(&identity);
     assert!(!state.active);
     assert_eq!(state.bonded_amount, 0);
 }
@@ -350,10 +350,10 @@ fn test_normal_slash_succeeds() {
     let (bond_id, admin, _identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    let slashed = client.slash_bond(&admin, &3_000_i128);
+    let slashed = client.slash_bond(&admin, &identity, &3_000_i128);
     assert_eq!(slashed, 3_000_i128);
 
-    let state = client.get_identity_state();
+    let state = client.get_identity_state(&identity);
     assert_eq!(state.slashed_amount, 3_000_i128);
     assert!(state.active);
 }
@@ -383,7 +383,7 @@ fn test_sequential_operations_succeed() {
     let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.slash_bond(&admin, &1_000_i128);
+    client.slash_bond(&admin, &identity, &1_000_i128);
     assert!(!client.is_locked());
 
     client.deposit_fees(&100_i128);
@@ -400,14 +400,14 @@ fn test_sequential_operations_succeed() {
 // 12. Slash exceeding bond is rejected
 // ===========================================================================
 #[test]
-#[should_panic(expected = "slash exceeds bond")]
+#[should_panic(expected = "Error(Contract, #21)")]
 fn test_slash_exceeds_bond_rejected() {
     let e = Env::default();
     e.mock_all_auths();
     let (bond_id, admin, _identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.slash_bond(&admin, &20_000_i128);
+    client.slash_bond(&admin, &identity, &20_000_i128);
 }
 
 // ===========================================================================
@@ -429,7 +429,7 @@ fn test_withdraw_non_owner_rejected() {
 // 14. Double withdrawal is rejected (bond inactive after first)
 // ===========================================================================
 #[test]
-#[should_panic(expected = "bond not active")]
+#[should_panic(expected = "Error(Contract, #7)")]
 fn test_double_withdraw_rejected() {
     let e = Env::default();
     e.mock_all_auths();
@@ -451,7 +451,7 @@ fn test_cross_function_reentrancy_blocked() {
     let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    let attacker_id = e.register_contract(None, CrossAttacker);
+    let attacker_id = e.register(CrossAttacker, ());
     let attacker_client = CrossAttackerClient::new(&e, &attacker_id);
     attacker_client.setup(&bond_id, &admin);
     client.set_callback(&attacker_id);
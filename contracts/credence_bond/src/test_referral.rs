@@ -0,0 +1,246 @@
+//! Tests for referral attribution: reward accrual from the insurance pool, the minimum-age
+//! gate, forfeits on early closure, claiming, and rejected self and circular referrals.
+
+#![cfg(test)]
+
+use crate::{BondError, CredenceBond, CredenceBondClient, ReferralError, ReferralStats};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+/// Net of the 10% deposit premium.
+const NET: i128 = 900_000_000;
+/// 1% of the net amount.
+const REWARD: i128 = 9_000_000;
+const MIN_AGE: u64 = 1000;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    token: TokenClient<'a>,
+    sac: StellarAssetClient<'a>,
+    admin: Address,
+    referrer: Address,
+}
+
+/// Referrer bonded at t=1000 with a 10% deposit premium, so the pool holds 100M.
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+
+    let token_admin = e.register_stellar_asset_contract_v2(Address::generate(e));
+    let sac = StellarAssetClient::new(e, &token_admin.address());
+    client.set_token(&admin, &token_admin.address());
+    client.set_deposit_premium(&admin, &1_000);
+    client.set_referral_config(&admin, &100, &MIN_AGE);
+
+    let referrer = Address::generate(e);
+    sac.mint(&referrer, &BOND_AMOUNT);
+    client.create_bond(&referrer, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+    Setup {
+        client,
+        token: TokenClient::new(e, &token_admin.address()),
+        sac,
+        admin,
+        referrer,
+    }
+}
+
+fn referred(e: &Env, s: &Setup, referrer: &Address) -> Address {
+    let identity = Address::generate(e);
+    s.sac.mint(&identity, &BOND_AMOUNT);
+    s.client
+        .create_bond_with_referrer(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64, referrer);
+    identity
+}
+
+fn at(e: &Env, timestamp: u64) {
+    e.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+fn stats(referred_count: u32, pending: i128, claimable: i128, claimed: i128) -> ReferralStats {
+    ReferralStats {
+        referred_count,
+        pending,
+        claimable,
+        claimed,
+        forfeited: 0,
+    }
+}
+
+#[test]
+fn test_referral_recorded_and_reward_reserved() {
+    let e = Env::default();
+    let s = setup(&e);
+    let identity = referred(&e, &s, &s.referrer);
+
+    let referral = s.client.get_referral(&identity).unwrap();
+    assert_eq!(
+        (referral.referrer, referral.reward, referral.bond_start),
+        (s.referrer.clone(), REWARD, 1000)
+    );
+    assert_eq!(
+        s.client.get_referral_stats(&s.referrer),
+        stats(1, REWARD, 0, 0)
+    );
+    // Two premiums in, one reward reserved out
+    assert_eq!(
+        s.client.get_insurance_pool_balance(),
+        2 * (BOND_AMOUNT - NET) - REWARD
+    );
+    assert!(s.client.verify_accounting(&0, &10).discrepancies.is_empty());
+    assert_eq!(s.client.get_referral(&s.referrer), None);
+}
+
+#[test]
+fn test_reward_vests_at_min_age_and_is_claimed() {
+    let e = Env::default();
+    let s = setup(&e);
+    referred(&e, &s, &s.referrer);
+
+    at(&e, 1000 + MIN_AGE - 1);
+    assert_eq!(
+        s.client.get_referral_stats(&s.referrer),
+        stats(1, REWARD, 0, 0)
+    );
+    assert_eq!(
+        s.client.try_claim_referral_rewards(&s.referrer).err(),
+        Some(Ok(BondError::NothingToClaim.into()))
+    );
+
+    at(&e, 1000 + MIN_AGE);
+    assert_eq!(
+        s.client.get_referral_stats(&s.referrer),
+        stats(1, 0, REWARD, 0)
+    );
+    assert_eq!(s.client.claim_referral_rewards(&s.referrer), REWARD);
+    assert_eq!(s.token.balance(&s.referrer), REWARD);
+    assert_eq!(
+        s.client.get_referral_stats(&s.referrer),
+        stats(1, 0, 0, REWARD)
+    );
+    assert!(s.client.verify_accounting(&0, &10).discrepancies.is_empty());
+    assert_eq!(
+        s.client.try_claim_referral_rewards(&s.referrer).err(),
+        Some(Ok(BondError::NothingToClaim.into()))
+    );
+}
+
+#[test]
+fn test_reward_forfeited_when_bond_closes_early() {
+    let e = Env::default();
+    let s = setup(&e);
+    let identity = referred(&e, &s, &s.referrer);
+    let pool = s.client.get_insurance_pool_balance();
+
+    // Lock-up over, but short of the minimum age
+    at(&e, 1100);
+    s.client.withdraw(&identity, &NET);
+    assert_eq!(s.client.get_insurance_pool_balance(), pool + REWARD);
+    let forfeited = s.client.get_referral_stats(&s.referrer);
+    assert_eq!((forfeited.pending, forfeited.forfeited), (0, REWARD));
+
+    at(&e, 1000 + MIN_AGE);
+    assert_eq!(
+        s.client.try_claim_referral_rewards(&s.referrer).err(),
+        Some(Ok(BondError::NothingToClaim.into()))
+    );
+    assert!(s.client.verify_accounting(&0, &10).discrepancies.is_empty());
+}
+
+#[test]
+fn test_reward_kept_when_bond_closes_after_min_age() {
+    let e = Env::default();
+    let s = setup(&e);
+    let identity = referred(&e, &s, &s.referrer);
+    at(&e, 1000 + MIN_AGE);
+    s.client.withdraw(&identity, &NET);
+    assert_eq!(
+        s.client.get_referral_stats(&s.referrer),
+        stats(1, 0, REWARD, 0)
+    );
+    assert_eq!(s.client.claim_referral_rewards(&s.referrer), REWARD);
+}
+
+#[test]
+fn test_reward_capped_at_pool_balance() {
+    let e = Env::default();
+    let s = setup(&e);
+    // No premium on the referred deposits: the pool keeps the referrer's 100M
+    s.client.set_deposit_premium(&s.admin, &0);
+    s.client.set_referral_config(&s.admin, &1_000, &MIN_AGE);
+
+    let first = referred(&e, &s, &s.referrer);
+    assert_eq!(
+        s.client.get_referral(&first).unwrap().reward,
+        BOND_AMOUNT - NET
+    );
+    assert_eq!(s.client.get_insurance_pool_balance(), 0);
+
+    // Nothing left to fund the next reward; the referral is still recorded
+    let second = referred(&e, &s, &s.referrer);
+    assert_eq!(s.client.get_referral(&second).unwrap().reward, 0);
+    assert_eq!(
+        s.client.get_referral_stats(&s.referrer),
+        stats(2, BOND_AMOUNT - NET, 0, 0)
+    );
+    assert!(s.client.verify_accounting(&0, &10).discrepancies.is_empty());
+}
+
+#[test]
+fn test_self_referral_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let identity = Address::generate(&e);
+    s.sac.mint(&identity, &BOND_AMOUNT);
+    assert_eq!(
+        s.client
+            .try_create_bond_with_referrer(
+                &identity,
+                &BOND_AMOUNT,
+                &100_u64,
+                &false,
+                &0_u64,
+                &identity,
+            )
+            .err(),
+        Some(Ok(ReferralError::SelfReferral.into()))
+    );
+}
+
+#[test]
+fn test_circular_referral_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    let b = referred(&e, &s, &s.referrer);
+    let c = referred(&e, &s, &b);
+    // The referrer's bond closes, then it tries to reopen as referred by its own downline
+    at(&e, 1100);
+    s.client.withdraw(&s.referrer, &NET);
+    assert_eq!(
+        s.client
+            .try_create_bond_with_referrer(&s.referrer, &NET, &100_u64, &false, &0_u64, &c,)
+            .err(),
+        Some(Ok(ReferralError::CircularReferral.into()))
+    );
+}
+
+#[test]
+fn test_referral_config_admin_only() {
+    let e = Env::default();
+    let s = setup(&e);
+    assert_eq!(
+        s.client
+            .try_set_referral_config(&Address::generate(&e), &100, &0)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        s.client.try_set_referral_config(&s.admin, &1_001, &0).err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+}
@@ -6,9 +6,9 @@ use crate::*;
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Env, String};
 
-fn setup(e: &Env) -> (CredenceBondClient, soroban_sdk::Address) {
+fn setup(e: &Env) -> (CredenceBondClient<'_>, soroban_sdk::Address) {
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = soroban_sdk::Address::generate(e);
     client.initialize(&admin);
@@ -37,7 +37,7 @@ fn nonce_increments_after_add_attestation() {
 }
 
 #[test]
-#[should_panic(expected = "invalid nonce")]
+#[should_panic(expected = "Error(Contract, #800)")]
 fn replay_add_attestation_rejected() {
     let e = Env::default();
     let (client, attester) = setup(&e);
@@ -48,7 +48,7 @@ fn replay_add_attestation_rejected() {
 }
 
 #[test]
-#[should_panic(expected = "invalid nonce")]
+#[should_panic(expected = "Error(Contract, #800)")]
 fn wrong_nonce_rejected() {
     let e = Env::default();
     let (client, attester) = setup(&e);
@@ -73,7 +73,7 @@ fn nonce_increments_after_revoke() {
 }
 
 #[test]
-#[should_panic(expected = "invalid nonce")]
+#[should_panic(expected = "Error(Contract, #800)")]
 fn replay_revoke_rejected() {
     let e = Env::default();
     let (client, attester) = setup(&e);
@@ -0,0 +1,131 @@
+//! Tests for the slash and treasury admin roles: defaults at initialization, delegation by the
+//! admin, and separation between the roles.
+
+#![cfg(test)]
+
+use crate::{BondError, CredenceBond, CredenceBondClient, EarlyExitConfig};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, vec, Address, Env, Symbol};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_roles_default_to_admin() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    assert_eq!(client.get_slash_admin(), admin);
+    assert_eq!(client.get_treasury_admin(), admin);
+    client.slash(&admin, &identity, &1_000, &symbol_short!("test"), &0);
+    client.set_early_exit_config(&admin, &Address::generate(&e), &500);
+}
+
+#[test]
+fn test_slash_admin_separated() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let slasher = Address::generate(&e);
+    client.set_slash_admin(&admin, &slasher);
+    assert_eq!(client.get_slash_admin(), slasher);
+
+    // The super-admin no longer slashes
+    assert_eq!(
+        client
+            .try_slash(&admin, &identity, &1_000, &symbol_short!("test"), &0)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client
+            .try_slash_with_reason(&admin, &identity, &1_000, &Symbol::new(&e, "fraud"))
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client
+            .try_batch_slash(&admin, &vec![&e, (identity.clone(), 1_000_i128)])
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+
+    client.slash(&slasher, &identity, &1_000, &symbol_short!("test"), &0);
+    client.slash_with_reason(&slasher, &identity, &1_000, &Symbol::new(&e, "fraud"));
+    let bonds = client.batch_slash(&slasher, &vec![&e, (identity.clone(), 1_000_i128)]);
+    assert_eq!(bonds.get(0).unwrap().slashed_amount, 3_000);
+
+    // ...and holds no other privilege
+    assert_eq!(
+        client
+            .try_set_early_exit_config(&slasher, &slasher, &500)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client.try_set_slash_admin(&slasher, &slasher).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_treasury_admin_separated() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let treasurer = Address::generate(&e);
+    client.set_treasury_admin(&admin, &treasurer);
+    assert_eq!(client.get_treasury_admin(), treasurer);
+
+    let treasury = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_set_early_exit_config(&admin, &treasury, &500)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client.try_set_deposit_premium(&admin, &100).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    client.set_early_exit_config(&treasurer, &treasury, &500);
+    client.set_deposit_premium(&treasurer, &100);
+    assert_eq!(
+        client.get_early_exit_config(),
+        EarlyExitConfig {
+            treasury,
+            penalty_bps: 500,
+        }
+    );
+
+    assert_eq!(
+        client
+            .try_slash(&treasurer, &identity, &1_000, &symbol_short!("test"), &0)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    // The super-admin keeps its own setters
+    client.set_collateral_floor(&admin, &0);
+}
+
+#[test]
+fn test_only_admin_delegates_roles() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    let stranger = Address::generate(&e);
+    assert_eq!(
+        client.try_set_slash_admin(&stranger, &stranger).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client.try_set_treasury_admin(&stranger, &stranger).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
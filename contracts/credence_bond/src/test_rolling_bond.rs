@@ -2,12 +2,16 @@
 
 #![cfg(test)]
 
-use crate::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, Env};
+use crate::{rolling_bond, BondError, CredenceBond, CredenceBondClient, UnclaimedPolicy};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
-    let contract_id = e.register_contract(None, CredenceBond);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
     client.initialize(&admin);
@@ -20,7 +24,7 @@ fn test_rolling_bond_creation() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    let bond = client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
+    let bond = client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
     assert!(bond.is_rolling);
     assert_eq!(bond.notice_period_duration, 10);
     assert_eq!(bond.withdrawal_requested_at, 0);
@@ -32,31 +36,31 @@ fn test_request_withdrawal() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
-    let bond = client.request_withdrawal();
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    let bond = client.request_withdrawal(&identity);
     assert_eq!(bond.withdrawal_requested_at, 1000);
 }
 
 #[test]
-#[should_panic(expected = "not a rolling bond")]
+#[should_panic(expected = "Error(Contract, #8)")]
 fn test_request_withdrawal_non_rolling() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
-    client.request_withdrawal();
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+    client.request_withdrawal(&identity);
 }
 
 #[test]
-#[should_panic(expected = "withdrawal already requested")]
+#[should_panic(expected = "Error(Contract, #9)")]
 fn test_request_withdrawal_twice() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
-    client.request_withdrawal();
-    client.request_withdrawal();
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    client.request_withdrawal(&identity);
 }
 
 #[test]
@@ -65,12 +69,12 @@ fn test_renew_if_rolling_advances_period() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
-    let bond = client.get_identity_state();
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    let bond = client.get_identity_state(&identity);
     assert_eq!(bond.bond_start, 1000);
 
     e.ledger().with_mut(|li| li.timestamp = 1101);
-    let bond = client.renew_if_rolling();
+    let bond = client.renew_if_rolling(&identity);
     assert_eq!(bond.bond_start, 1101);
     assert_eq!(bond.withdrawal_requested_at, 0);
 }
@@ -81,9 +85,9 @@ fn test_renew_if_rolling_no_op_before_period_end() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
     e.ledger().with_mut(|li| li.timestamp = 1050);
-    let bond = client.renew_if_rolling();
+    let bond = client.renew_if_rolling(&identity);
     assert_eq!(bond.bond_start, 1000);
 }
 
@@ -93,9 +97,9 @@ fn test_renew_if_rolling_no_op_for_non_rolling() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &false, &0_u64);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
     e.ledger().with_mut(|li| li.timestamp = 1101);
-    let bond = client.renew_if_rolling();
+    let bond = client.renew_if_rolling(&identity);
     assert_eq!(bond.bond_start, 1000);
 }
 
@@ -105,9 +109,777 @@ fn test_withdraw_after_notice_period() {
     e.ledger().with_mut(|li| li.timestamp = 1000);
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    client.create_bond(&identity, &1000_i128, &100_u64, &true, &10_u64);
-    client.request_withdrawal();
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1011);
+    let bond = client.withdraw(&identity, &(BOND_AMOUNT / 2));
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT / 2);
+}
+
+#[test]
+fn test_renew_if_rolling_emits_event() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    client.renew_if_rolling(&identity);
+    assert_eq!(e.events().all().len(), 1);
+}
+
+#[test]
+fn test_renew_if_rolling_skips_inactive_bond() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    client.withdraw_bond(&identity);
+
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 1000);
+    assert!(!bond.active);
+    assert_eq!(e.events().all().len(), 0);
+}
+
+#[test]
+fn test_renew_if_rolling_skips_fully_slashed_bond() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.slash(&admin, &identity, &BOND_AMOUNT, &symbol_short!("test"), &0);
+
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 1000);
+    assert_eq!(e.events().all().len(), 0);
+}
+
+#[test]
+fn test_renew_if_rolling_skips_matured_withdrawal_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1095);
+    client.request_withdrawal(&identity);
+
+    e.ledger().with_mut(|li| li.timestamp = 1105);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 1000);
+    assert_eq!(bond.withdrawal_requested_at, 1095);
+    assert_eq!(e.events().all().len(), 0);
+}
+
+#[test]
+fn test_renew_if_rolling_skips_pending_notice() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1099);
+    client.request_withdrawal(&identity);
+
+    // Period has ended but the notice has not yet matured: the request is kept.
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 1000);
+    assert_eq!(bond.withdrawal_requested_at, 1099);
+    assert_eq!(e.events().all().len(), 0);
+
+    // Once matured the identity can exit.
+    e.ledger().with_mut(|li| li.timestamp = 1109);
+    let bond = client.withdraw(&identity, &BOND_AMOUNT);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+fn test_renew_after_cancel_resumes_rolling() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    client.cancel_withdrawal_request(&identity);
+
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 1101);
+}
+
+#[test]
+fn test_withdraw_via_notice_emits_exit_event() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    client.withdraw(&identity, &BOND_AMOUNT);
+    let exit = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == (Symbol::new(&e, "rolling_bond_exited"),).into_val(&e))
+        .expect("no exit event");
+    let data: (Address, i128, u64) = exit.2.into_val(&e);
+    assert_eq!(data, (identity, BOND_AMOUNT, 1000));
+}
+
+fn setup_requested(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(e);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    (client, identity)
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_withdraw_rolling_without_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    client.withdraw(&identity, &1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_withdraw_rolling_immediately_after_request() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    client.withdraw(&identity, &1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_withdraw_rolling_one_second_before_notice_end() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1009);
+    client.withdraw(&identity, &1_000_000);
+}
+
+#[test]
+fn test_withdraw_rolling_exactly_at_notice_end() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    let bond = client.withdraw(&identity, &1_000_000);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT - 1_000_000);
+}
+
+#[test]
+fn test_withdraw_rolling_one_second_after_notice_end() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
     e.ledger().with_mut(|li| li.timestamp = 1011);
-    let bond = client.withdraw(&500);
-    assert_eq!(bond.bonded_amount, 500);
+    let bond = client.withdraw(&identity, &1_000_000);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT - 1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_withdraw_bond_rolling_without_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 2000); // well past the period end
+    client.withdraw_bond(&identity);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_withdraw_bond_rolling_one_second_before_notice_end() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1009);
+    client.withdraw_bond(&identity);
+}
+
+#[test]
+fn test_withdraw_bond_rolling_exactly_at_notice_end() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    assert_eq!(client.withdraw_bond(&identity), BOND_AMOUNT);
+    assert!(!client.get_identity_state(&identity).active);
+}
+
+#[test]
+fn test_check_notice_period_elapsed_unit() {
+    let e = Env::default();
+    let mut bond = crate::IdentityBond {
+        identity: Address::generate(&e),
+        bonded_amount: BOND_AMOUNT,
+        bond_start: 0,
+        bond_duration: 100,
+        slashed_amount: 0,
+        active: true,
+        is_rolling: false,
+        withdrawal_requested_at: 0,
+        notice_period_duration: 10,
+    };
+    // Non-rolling bonds are not subject to the notice period
+    rolling_bond::check_notice_period_elapsed(&e, &bond, 0);
+    bond.is_rolling = true;
+    bond.withdrawal_requested_at = 50;
+    rolling_bond::check_notice_period_elapsed(&e, &bond, 60);
+    rolling_bond::check_notice_period_elapsed(&e, &bond, 61);
+}
+
+#[test]
+fn test_cancel_withdrawal_request() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1005);
+    let bond = client.cancel_withdrawal_request(&identity);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    let topic: Symbol = topics.get(0).unwrap().into_val(&e);
+    assert_eq!(topic, Symbol::new(&e, "withdrawal_cancelled"));
+    let (who, at): (Address, u64) = data.into_val(&e);
+    assert_eq!(who, identity);
+    assert_eq!(at, 1005);
+
+    assert_eq!(
+        client.get_identity_state(&identity).withdrawal_requested_at,
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_withdraw_fails_after_cancel() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    client.cancel_withdrawal_request(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    client.withdraw(&identity, &1_000_000);
+}
+
+#[test]
+fn test_rerequest_after_cancel_starts_fresh_notice() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    client.cancel_withdrawal_request(&identity);
+
+    e.ledger().with_mut(|li| li.timestamp = 1050);
+    let bond = client.request_withdrawal(&identity);
+    assert_eq!(bond.withdrawal_requested_at, 1050);
+
+    // The original notice (ending at 1010) no longer applies
+    e.ledger().with_mut(|li| li.timestamp = 1059);
+    assert!(client.try_withdraw(&identity, &1_000_000).is_err());
+    e.ledger().with_mut(|li| li.timestamp = 1060);
+    client.withdraw(&identity, &1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_cancel_without_pending_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.cancel_withdrawal_request(&identity);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_cancel_non_rolling_bond() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+    client.cancel_withdrawal_request(&identity);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_request_withdrawal_on_inactive_bond_rejected() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    client.withdraw(&identity, &BOND_AMOUNT);
+    client.request_withdrawal(&identity);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_cancel_on_inactive_bond_rejected() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    client.withdraw_bond(&identity);
+    client.cancel_withdrawal_request(&identity);
+}
+
+#[test]
+fn test_renew_leaves_fully_withdrawn_bond_untouched() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1010);
+    client.withdraw(&identity, &BOND_AMOUNT);
+    e.ledger().with_mut(|li| li.timestamp = 5000);
+    let bond = client.renew_if_rolling(&identity);
+    assert!(!bond.active);
+    assert_eq!(bond.bond_start, 1000);
+}
+
+#[test]
+fn test_convert_to_rolling_keeps_lockup() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = 1050);
+    let bond = client.convert_to_rolling(&identity, &10_u64);
+    assert!(bond.is_rolling);
+    assert_eq!(bond.notice_period_duration, 10);
+    assert_eq!(bond.bond_start, 1000);
+    assert_eq!(bond.bond_duration, 100);
+
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "converted_to_rolling"),).into_val(&e)
+    );
+    let data: (Address, u64) = data.into_val(&e);
+    assert_eq!(data, (identity.clone(), 10));
+
+    // The bond now exits through the notice period
+    assert!(client.try_withdraw(&identity, &BOND_AMOUNT).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+fn test_convert_to_rolling_rejects_rolling_bond() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.convert_to_rolling(&identity, &10_u64);
+}
+
+#[test]
+fn test_convert_to_rolling_validates_notice_period() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+
+    for notice in [0_u64, 101] {
+        assert_eq!(
+            client.try_convert_to_rolling(&identity, &notice).err(),
+            Some(Ok(crate::BondError::InvalidNoticePeriod.into()))
+        );
+    }
+    assert!(client.convert_to_rolling(&identity, &100_u64).is_rolling);
+}
+
+#[test]
+fn test_convert_to_fixed_waits_for_period_end() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+
+    let bond = client.convert_to_fixed(&identity);
+    assert!(bond.is_rolling);
+    assert_eq!(bond.notice_period_duration, 10);
+    let effective_at: (Address, u64) = find_event(&e, "fixed_conversion_scheduled")
+        .unwrap()
+        .into_val(&e);
+    assert_eq!(effective_at, (identity.clone(), 1100));
+    assert!(client.is_fixed_conversion_pending(&identity));
+
+    // One second before the period ends the bond is still rolling
+    e.ledger().with_mut(|li| li.timestamp = 1099);
+    let bond = client.renew_if_rolling(&identity);
+    assert!(bond.is_rolling);
+    assert!(client.get_identity_state(&identity).is_rolling);
+
+    // At the boundary the conversion applies instead of a renewal
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    let bond = client.renew_if_rolling(&identity);
+    assert!(!bond.is_rolling);
+    assert_eq!(bond.bond_start, 1000);
+    assert_eq!(bond.notice_period_duration, 0);
+    let events = e.events().all();
+    let (_, topics, _) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "converted_to_fixed"),).into_val(&e)
+    );
+    assert!(!client.is_fixed_conversion_pending(&identity));
+
+    // Matured fixed bond: withdrawable without a notice period
+    let bond = client.withdraw(&identity, &BOND_AMOUNT);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+fn test_convert_to_fixed_applied_on_withdraw() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.convert_to_fixed(&identity);
+
+    // Before the period end the bond is still rolling and needs a withdrawal request
+    e.ledger().with_mut(|li| li.timestamp = 1099);
+    assert_eq!(
+        client.try_withdraw(&identity, &BOND_AMOUNT).err(),
+        Some(Ok(crate::BondError::WithdrawalNotRequested.into()))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    let bond = client.withdraw(&identity, &BOND_AMOUNT);
+    assert!(!bond.is_rolling);
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+fn test_convert_to_fixed_rejections() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let fixed = Address::generate(&e);
+    client.create_bond(&fixed, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+    assert_eq!(
+        client.try_convert_to_fixed(&fixed).err(),
+        Some(Ok(crate::BondError::NotRollingBond.into()))
+    );
+
+    let rolling = Address::generate(&e);
+    client.create_bond(&rolling, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.convert_to_fixed(&rolling);
+    assert_eq!(
+        client.try_convert_to_fixed(&rolling).err(),
+        Some(Ok(crate::BondError::FixedConversionPending.into()))
+    );
+}
+
+#[test]
+fn test_convert_to_fixed_rejects_pending_withdrawal_request() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+
+    assert_eq!(
+        client.try_convert_to_fixed(&identity).err(),
+        Some(Ok(crate::BondError::WithdrawalAlreadyRequested.into()))
+    );
+    assert!(!client.is_fixed_conversion_pending(&identity));
+    assert_eq!(
+        client.get_identity_state(&identity).withdrawal_requested_at,
+        1000
+    );
+
+    // Once the request is cancelled the conversion can be scheduled
+    client.cancel_withdrawal_request(&identity);
+    assert!(client.convert_to_fixed(&identity).is_rolling);
+    assert!(client.is_fixed_conversion_pending(&identity));
+}
+
+#[test]
+fn test_conversion_round_trip() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+
+    // fixed -> rolling -> fixed at the period end -> rolling again
+    client.convert_to_rolling(&identity, &10_u64);
+    client.convert_to_fixed(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    let bond = client.renew_if_rolling(&identity);
+    assert!(!bond.is_rolling);
+    assert_eq!(bond.notice_period_duration, 0);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+
+    let bond = client.convert_to_rolling(&identity, &20_u64);
+    assert!(bond.is_rolling);
+    assert_eq!(bond.notice_period_duration, 20);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+    assert!(!client.is_fixed_conversion_pending(&identity));
+}
+
+/// Data of the event named `name` in the last invocation.
+fn find_event(e: &Env, name: &str) -> Option<soroban_sdk::Val> {
+    e.events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == (Symbol::new(e, name),).into_val(e))
+        .map(|(_, _, data)| data)
+}
+
+#[test]
+fn test_unclaimed_withdrawal_held_by_default() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    assert_eq!(client.get_unclaimed_policy(), (UnclaimedPolicy::Hold, 0));
+    e.ledger().with_mut(|li| li.timestamp = 5000);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.withdrawal_requested_at, 1000);
+    assert_eq!(bond.bond_start, 1000);
+}
+
+#[test]
+fn test_unclaimed_withdrawal_reabsorbed_after_reclaim_window() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    client.set_unclaimed_policy(&client.get_admin(), &UnclaimedPolicy::Reabsorb, &50_u64);
+
+    // Matured at 1010; the reclaim window runs until 1060
+    e.ledger().with_mut(|li| li.timestamp = 1059);
+    assert_eq!(
+        client.renew_if_rolling(&identity).withdrawal_requested_at,
+        1000
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1060);
+    let bond = client.renew_if_rolling(&identity);
+    let data: (Address, i128, u64) = find_event(&e, "withdrawal_reabsorbed")
+        .expect("no reabsorb event")
+        .into_val(&e);
+    assert_eq!(data, (identity.clone(), BOND_AMOUNT, 1000));
+    assert_eq!(bond.withdrawal_requested_at, 0);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT);
+    // The period has not ended yet; it renews at the next renewal
+    assert_eq!(bond.bond_start, 1000);
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 1100);
+}
+
+#[test]
+fn test_reabsorbed_withdrawal_renews_in_same_call() {
+    let e = Env::default();
+    let (client, identity) = setup_requested(&e);
+    client.set_unclaimed_policy(&client.get_admin(), &UnclaimedPolicy::Reabsorb, &50_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    let bond = client.renew_if_rolling(&identity);
+    assert!(find_event(&e, "withdrawal_reabsorbed").is_some());
+    assert!(find_event(&e, "bond_renewed").is_some());
+    assert_eq!(bond.bond_start, 1200);
+    assert_eq!(bond.withdrawal_requested_at, 0);
+}
+
+#[test]
+fn test_unclaimed_withdrawal_moved_to_claimable() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin) = setup(&e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    let token = TokenClient::new(&e, &sac.address());
+    let identity = Address::generate(&e);
+    StellarAssetClient::new(&e, &sac.address()).mint(&identity, &BOND_AMOUNT);
+    client.set_token(&admin, &sac.address());
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.request_withdrawal(&identity);
+    client.set_unclaimed_policy(&admin, &UnclaimedPolicy::Claimable, &50_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = 1059);
+    assert!(client.renew_if_rolling(&identity).active);
+    assert_eq!(client.get_unclaimed_withdrawal(&identity), 0);
+
+    e.ledger().with_mut(|li| li.timestamp = 1060);
+    let bond = client.renew_if_rolling(&identity);
+    let data: (Address, i128) = find_event(&e, "withdrawal_unclaimed")
+        .expect("no unclaimed event")
+        .into_val(&e);
+    assert_eq!(data, (identity.clone(), BOND_AMOUNT));
+    assert!(!bond.active);
+    assert_eq!(bond.bonded_amount, 0);
+    assert_eq!(client.get_total_bonded(), 0);
+    assert_eq!(client.get_unclaimed_withdrawal(&identity), BOND_AMOUNT);
+    assert_eq!(token.balance(&identity), 0);
+
+    assert_eq!(client.claim_unclaimed_withdrawal(&identity), BOND_AMOUNT);
+    let data: (Address, i128) = find_event(&e, "unclaimed_withdrawal_claimed")
+        .expect("no claim event")
+        .into_val(&e);
+    assert_eq!(data, (identity.clone(), BOND_AMOUNT));
+    assert_eq!(token.balance(&identity), BOND_AMOUNT);
+    assert_eq!(
+        client.try_claim_unclaimed_withdrawal(&identity).err(),
+        Some(Ok(BondError::NothingToClaim.into()))
+    );
+}
+
+#[test]
+fn test_set_unclaimed_policy_admin_only() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client
+            .try_set_unclaimed_policy(&Address::generate(&e), &UnclaimedPolicy::Reabsorb, &0)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_withdrawal_ready_fixed() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = 1099);
+    assert!(!client.is_withdrawal_ready(&identity));
+    assert_eq!(client.time_until_withdrawal_ready(&identity), 1);
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    assert!(client.is_withdrawal_ready(&identity));
+    assert_eq!(client.time_until_withdrawal_ready(&identity), 0);
+}
+
+#[test]
+fn test_withdrawal_ready_rolling() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+
+    // Not ready past the period end without a request
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    assert!(!client.is_withdrawal_ready(&identity));
+    assert_eq!(client.time_until_withdrawal_ready(&identity), u64::MAX);
+
+    client.request_withdrawal(&identity);
+    e.ledger().with_mut(|li| li.timestamp = 1204);
+    assert!(!client.is_withdrawal_ready(&identity));
+    assert_eq!(client.time_until_withdrawal_ready(&identity), 6);
+
+    e.ledger().with_mut(|li| li.timestamp = 1210);
+    assert!(client.is_withdrawal_ready(&identity));
+    assert_eq!(client.time_until_withdrawal_ready(&identity), 0);
+    client.withdraw(&identity, &BOND_AMOUNT);
+}
+
+#[test]
+fn test_withdrawal_ready_no_bond() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client.try_is_withdrawal_ready(&Address::generate(&e)).err(),
+        Some(Ok(BondError::NoBond.into()))
+    );
+}
+
+#[test]
+fn test_renew_with_new_terms() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    let bond = client.renew_with_new_terms(&identity, &500, &50);
+    assert_eq!(
+        (
+            bond.bond_start,
+            bond.bond_duration,
+            bond.notice_period_duration
+        ),
+        (1100, 500, 50)
+    );
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "bond_renewed_with_new_terms"),).into_val(&e)
+    );
+    let data: (Address, u64, u64, u64, u64, u64) = data.into_val(&e);
+    assert_eq!(data, (identity.clone(), 1100, 100, 10, 500, 50));
+    assert_eq!(client.get_identity_state(&identity).bond_duration, 500);
+
+    // The new terms carry into later renewals
+    e.ledger().with_mut(|li| li.timestamp = 1599);
+    assert_eq!(client.renew_if_rolling(&identity).bond_start, 1100);
+    e.ledger().with_mut(|li| li.timestamp = 1600);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!((bond.bond_start, bond.bond_duration), (1600, 500));
+}
+
+#[test]
+fn test_renew_with_new_terms_rejected() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    let fixed = Address::generate(&e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &true, &10_u64);
+    client.create_bond(&fixed, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = 1099);
+    assert_eq!(
+        client.try_renew_with_new_terms(&identity, &500, &50).err(),
+        Some(Ok(BondError::LockupNotEnded.into()))
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    assert_eq!(
+        client.try_renew_with_new_terms(&fixed, &500, &50).err(),
+        Some(Ok(BondError::NotRollingBond.into()))
+    );
+    assert_eq!(
+        client.try_renew_with_new_terms(&identity, &500, &0).err(),
+        Some(Ok(BondError::InvalidNoticePeriod.into()))
+    );
+    assert_eq!(
+        client.try_renew_with_new_terms(&identity, &50, &60).err(),
+        Some(Ok(BondError::InvalidNoticePeriod.into()))
+    );
+    client.request_withdrawal(&identity);
+    assert_eq!(
+        client.try_renew_with_new_terms(&identity, &500, &50).err(),
+        Some(Ok(BondError::WithdrawalAlreadyRequested.into()))
+    );
+    // The old terms are untouched
+    let bond = client.get_identity_state(&identity);
+    assert_eq!(
+        (
+            bond.bond_start,
+            bond.bond_duration,
+            bond.notice_period_duration
+        ),
+        (1000, 100, 10)
+    );
 }
@@ -9,19 +9,31 @@
 //! 6. Event emission and audit trails
 //! 7. Integration with withdrawals
 //! 8. Cumulative slashing scenarios
+//! 9. Slash audit records (`slash_with_reason`)
+//! 10. Batch slashing (`batch_slash`)
+//! 11. Slash recovery (`recover_slash`)
+//! 12. Slash disputes (`dispute_slash`, `adjudicate_slash`, `finalize_slash`)
+//! 13. Balance views (`get_available_balance`, `get_bonded_amount`, `get_slashed_amount`)
+//! 14. Slash summaries (`get_slash_summary`, `attest_clean_record`)
 
 #![cfg(test)]
 
-use crate::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env};
+use crate::validation::MAX_BOND_AMOUNT;
+use crate::{BondError, CredenceBond, CredenceBondClient, SlashConfig, SlashDisputeError};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, Symbol};
 
 // ============================================================================
 // Test Setup Utilities
 // ============================================================================
 
+/// One whole token (6 decimals); amounts below are in whole tokens.
+const UNIT: i128 = 1_000_000;
+
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
-    let contract_id = e.register_contract(None, CredenceBond);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
     client.initialize(&admin);
@@ -46,46 +58,52 @@ fn setup_with_bond(
 #[test]
 fn test_slash_basic_success() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    let bond = client.slash(&admin, &300_i128);
+    let bond = client.slash(&admin, &identity, &(300 * UNIT), &symbol_short!("test"), &0);
 
-    assert_eq!(bond.slashed_amount, 300);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 300 * UNIT);
+    assert_eq!(bond.bonded_amount, 1000 * UNIT);
     assert!(bond.active);
 }
 
 #[test]
 fn test_slash_small_amount() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 10000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 10000 * UNIT, 86400_u64);
 
-    let bond = client.slash(&admin, &1_i128);
+    let bond = client.slash(&admin, &identity, &UNIT, &symbol_short!("test"), &0);
 
-    assert_eq!(bond.slashed_amount, 1);
-    assert_eq!(bond.bonded_amount, 10000);
+    assert_eq!(bond.slashed_amount, UNIT);
+    assert_eq!(bond.bonded_amount, 10000 * UNIT);
 }
 
 #[test]
 fn test_slash_exact_half() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    let bond = client.slash(&admin, &500_i128);
+    let bond = client.slash(&admin, &identity, &(500 * UNIT), &symbol_short!("test"), &0);
 
-    assert_eq!(bond.slashed_amount, 500);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 500 * UNIT);
+    assert_eq!(bond.bonded_amount, 1000 * UNIT);
 }
 
 #[test]
 fn test_slash_entire_amount() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
-
-    let bond = client.slash(&admin, &1000_i128);
-
-    assert_eq!(bond.slashed_amount, 1000);
-    assert_eq!(bond.bonded_amount, 1000);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(1000 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
+
+    assert_eq!(bond.slashed_amount, 1000 * UNIT);
+    assert_eq!(bond.bonded_amount, 1000 * UNIT);
 }
 
 // ============================================================================
@@ -93,36 +111,54 @@ fn test_slash_entire_amount() {
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #2)")]
 fn test_slash_unauthorized_rejection() {
     let e = Env::default();
-    let (client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, _admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     let other = Address::generate(&e);
-    client.slash(&other, &100_i128);
+    client.slash(&other, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
 }
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #2)")]
 fn test_slash_unauthorized_different_address() {
     let e = Env::default();
-    let (client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, _admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     let attacker1 = Address::generate(&e);
     let attacker2 = Address::generate(&e);
-    client.slash(&attacker1, &500_i128);
+    client.slash(
+        &attacker1,
+        &identity,
+        &(500 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
     // Second attempt with different attacker also fails
-    client.slash(&attacker2, &500_i128);
+    client.slash(
+        &attacker2,
+        &identity,
+        &(500 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
 }
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #2)")]
 fn test_slash_identity_cannot_slash_own_bond() {
     let e = Env::default();
-    let (client, _admin, identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, _admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     // Identity tries to slash their own bond (not authorized)
-    client.slash(&identity, &100_i128);
+    client.slash(
+        &identity,
+        &identity,
+        &(100 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
 }
 
 // ============================================================================
@@ -132,37 +168,49 @@ fn test_slash_identity_cannot_slash_own_bond() {
 #[test]
 fn test_slash_over_amount_capped() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    let bond = client.slash(&admin, &2000_i128);
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(2000 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
 
     // Should be capped at bonded_amount
-    assert_eq!(bond.slashed_amount, 1000);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 1000 * UNIT);
+    assert_eq!(bond.bonded_amount, 1000 * UNIT);
 }
 
 #[test]
 fn test_slash_way_over_amount_capped() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 500_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 500 * UNIT, 86400_u64);
 
-    let bond = client.slash(&admin, &999999_i128);
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(999999 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
 
     // Should be capped at bonded_amount
-    assert_eq!(bond.slashed_amount, 500);
-    assert_eq!(bond.bonded_amount, 500);
+    assert_eq!(bond.slashed_amount, 500 * UNIT);
+    assert_eq!(bond.bonded_amount, 500 * UNIT);
 }
 
 #[test]
 fn test_slash_max_i128_capped() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    let bond = client.slash(&admin, &i128::MAX);
+    let bond = client.slash(&admin, &identity, &i128::MAX, &symbol_short!("test"), &0);
 
     // Should be capped at bonded_amount
-    assert_eq!(bond.slashed_amount, 1000);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 1000 * UNIT);
+    assert_eq!(bond.bonded_amount, 1000 * UNIT);
 }
 
 // ============================================================================
@@ -172,35 +220,54 @@ fn test_slash_max_i128_capped() {
 #[test]
 fn test_slash_zero_amount() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
-
-    let bond = client.slash(&admin, &0_i128);
-
-    assert_eq!(bond.slashed_amount, 0);
-    assert_eq!(bond.bonded_amount, 1000);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+
+    assert_eq!(
+        client
+            .try_slash(&admin, &identity, &0_i128, &symbol_short!("test"), &0)
+            .err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client
+            .try_slash(&admin, &identity, &-1_i128, &symbol_short!("test"), &0)
+            .err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+    assert_eq!(
+        client.try_slash_bond(&admin, &identity, &-1_i128).err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
 }
 
 #[test]
-#[should_panic(expected = "slashing caused overflow")]
+#[should_panic(expected = "Error(Contract, #6)")]
 fn test_slash_overflow_prevention() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, i128::MAX - 100, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, MAX_BOND_AMOUNT, 86400_u64);
 
     // First slash: amount = 50
-    client.slash(&admin, &50_i128);
+    client.slash(&admin, &identity, &(50 * UNIT), &symbol_short!("test"), &0);
 
-    // Second slash: would overflow (MAX - 100 + 50 = MAX - 50, then + i128::MAX)
-    client.slash(&admin, &i128::MAX);
+    // Second slash: cumulative slashed + i128::MAX would overflow
+    client.slash(&admin, &identity, &i128::MAX, &symbol_short!("test"), &0);
 }
 
 #[test]
 fn test_slash_on_very_large_bond() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, i128::MAX / 2, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, MAX_BOND_AMOUNT, 86400_u64);
 
-    let bond = client.slash(&admin, &(i128::MAX / 4));
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(MAX_BOND_AMOUNT / 4),
+        &symbol_short!("test"),
+        &0,
+    );
 
-    assert_eq!(bond.slashed_amount, i128::MAX / 4);
+    assert_eq!(bond.slashed_amount, MAX_BOND_AMOUNT / 4);
 }
 
 // ============================================================================
@@ -210,42 +277,48 @@ fn test_slash_on_very_large_bond() {
 #[test]
 fn test_slash_history_single_slash() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    client.slash(&admin, &200_i128);
-    let bond = client.get_identity_state();
+    client.slash(&admin, &identity, &(200 * UNIT), &symbol_short!("test"), &0);
+    let bond = client.get_identity_state(&identity);
 
-    assert_eq!(bond.slashed_amount, 200);
-    assert_eq!(bond.bonded_amount, 1000);
+    assert_eq!(bond.slashed_amount, 200 * UNIT);
+    assert_eq!(bond.bonded_amount, 1000 * UNIT);
 }
 
 #[test]
 fn test_slash_history_cumulative() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    let bond1 = client.slash(&admin, &200_i128);
-    assert_eq!(bond1.slashed_amount, 200);
+    let bond1 = client.slash(&admin, &identity, &(200 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(bond1.slashed_amount, 200 * UNIT);
 
-    let bond2 = client.slash(&admin, &300_i128);
-    assert_eq!(bond2.slashed_amount, 500);
+    let bond2 = client.slash(&admin, &identity, &(300 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(bond2.slashed_amount, 500 * UNIT);
 
-    let bond3 = client.get_identity_state();
-    assert_eq!(bond3.slashed_amount, 500);
+    let bond3 = client.get_identity_state(&identity);
+    assert_eq!(bond3.slashed_amount, 500 * UNIT);
 }
 
 #[test]
 fn test_slash_multiple_accumulate() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 10000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 10000 * UNIT, 86400_u64);
 
     // Linear accumulation: 1000 + 2000 + 3000 + 4000 + 5000
     // But capped at bonded_amount (10000)
     for i in 1..=5 {
-        let bond = client.slash(&admin, &(i as i128 * 1000_i128));
-        let expected_slashed = (i as i128 * (i as i128 + 1) / 2) * 1000_i128;
-        let capped = if expected_slashed > 10000_i128 {
-            10000_i128
+        let bond = client.slash(
+            &admin,
+            &identity,
+            &(i as i128 * (1000 * UNIT)),
+            &symbol_short!("test"),
+            &0,
+        );
+        let expected_slashed = (i as i128 * (i as i128 + 1) / 2) * (1000 * UNIT);
+        let capped = if expected_slashed > (10000 * UNIT) {
+            10000 * UNIT
         } else {
             expected_slashed
         };
@@ -256,16 +329,16 @@ fn test_slash_multiple_accumulate() {
 #[test]
 fn test_slash_does_not_affect_other_fields() {
     let e = Env::default();
-    let (client, admin, identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    let original_bond = client.get_identity_state();
+    let original_bond = client.get_identity_state(&identity);
     let original_bonded = original_bond.bonded_amount;
     let original_start = original_bond.bond_start;
     let original_duration = original_bond.bond_duration;
 
-    client.slash(&admin, &300_i128);
+    client.slash(&admin, &identity, &(300 * UNIT), &symbol_short!("test"), &0);
 
-    let updated_bond = client.get_identity_state();
+    let updated_bond = client.get_identity_state(&identity);
     assert_eq!(updated_bond.bonded_amount, original_bonded);
     assert_eq!(updated_bond.bond_start, original_start);
     assert_eq!(updated_bond.bond_duration, original_duration);
@@ -279,37 +352,46 @@ fn test_slash_does_not_affect_other_fields() {
 #[test]
 fn test_slash_event_emitted_basic() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    let _bond = client.slash(&admin, &250_i128);
+    let _bond = client.slash(&admin, &identity, &(250 * UNIT), &symbol_short!("test"), &0);
 
     // Verify event was published by checking bond state
-    let state = client.get_identity_state();
-    assert_eq!(state.slashed_amount, 250);
+    let state = client.get_identity_state(&identity);
+    assert_eq!(state.slashed_amount, 250 * UNIT);
 }
 
 #[test]
 fn test_slash_event_contains_correct_event_data() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    let bond1 = client.slash(&admin, &100_i128);
-    assert_eq!(bond1.slashed_amount, 100);
+    let bond1 = client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(bond1.slashed_amount, 100 * UNIT);
 
-    let bond2 = client.slash(&admin, &200_i128);
+    let bond2 = client.slash(&admin, &identity, &(200 * UNIT), &symbol_short!("test"), &0);
     // Event should contain slash_amount=200, total_slashed=300
-    assert_eq!(bond2.slashed_amount, 300);
+    assert_eq!(bond2.slashed_amount, 300 * UNIT);
 }
 
 #[test]
 fn test_slash_multiple_events() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     // Each slash emits an event
     for i in 1..=3 {
-        let bond = client.slash(&admin, &(100_i128 * i as i128));
-        assert_eq!(bond.slashed_amount, 100_i128 * (i * (i + 1) / 2) as i128);
+        let bond = client.slash(
+            &admin,
+            &identity,
+            &((100 * UNIT) * i as i128),
+            &symbol_short!("test"),
+            &0,
+        );
+        assert_eq!(
+            bond.slashed_amount,
+            (100 * UNIT) * (i * (i + 1) / 2) as i128
+        );
     }
 }
 
@@ -320,84 +402,108 @@ fn test_slash_multiple_events() {
 #[test]
 fn test_withdraw_after_slash_respects_available() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    client.slash(&admin, &400_i128);
-    let bond = client.withdraw(&600_i128);
+    client.slash(&admin, &identity, &(400 * UNIT), &symbol_short!("test"), &0);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    let bond = client.withdraw(&identity, &(600 * UNIT));
 
-    assert_eq!(bond.bonded_amount, 400);
-    assert_eq!(bond.slashed_amount, 400);
+    assert_eq!(bond.bonded_amount, 400 * UNIT);
+    assert_eq!(bond.slashed_amount, 400 * UNIT);
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_withdraw_more_than_available_after_slash() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    client.slash(&admin, &400_i128);
+    client.slash(&admin, &identity, &(400 * UNIT), &symbol_short!("test"), &0);
     // Available = 1000 - 400 = 600, trying to withdraw 601
-    client.withdraw(&601_i128);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &(601 * UNIT));
 }
 
 #[test]
-#[should_panic(expected = "insufficient balance for withdrawal")]
+#[should_panic(expected = "Error(Contract, #4)")]
 fn test_withdraw_when_fully_slashed() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     // Fully slash the bond
-    client.slash(&admin, &1000_i128);
+    client.slash(
+        &admin,
+        &identity,
+        &(1000 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
 
     // Cannot withdraw anything
-    client.withdraw(&1_i128);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &UNIT);
 }
 
 #[test]
 fn test_withdraw_exact_available_balance() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    client.slash(&admin, &400_i128);
-    let bond = client.withdraw(&600_i128);
+    client.slash(&admin, &identity, &(400 * UNIT), &symbol_short!("test"), &0);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    let bond = client.withdraw(&identity, &(600 * UNIT));
 
-    assert_eq!(bond.bonded_amount, 400);
+    assert_eq!(bond.bonded_amount, 400 * UNIT);
 }
 
 #[test]
 fn test_slash_then_withdraw_then_slash_again() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     // Slash, withdraw, slash again
-    client.slash(&admin, &200_i128);
-    assert_eq!(client.get_identity_state().bonded_amount, 1000);
-
-    client.withdraw(&300_i128);
-    assert_eq!(client.get_identity_state().bonded_amount, 700);
-
-    let bond = client.slash(&admin, &100_i128);
-    assert_eq!(bond.slashed_amount, 300);
-    assert_eq!(bond.bonded_amount, 700);
+    client.slash(&admin, &identity, &(200 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        1000 * UNIT
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &(300 * UNIT));
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        700 * UNIT
+    );
+
+    let bond = client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(bond.slashed_amount, 300 * UNIT);
+    assert_eq!(bond.bonded_amount, 700 * UNIT);
 }
 
 #[test]
 fn test_slash_after_partial_withdrawal() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     // Withdraw first
-    client.withdraw(&300_i128);
-    assert_eq!(client.get_identity_state().bonded_amount, 700);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &(300 * UNIT));
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        700 * UNIT
+    );
 
     // Then slash
-    let bond = client.slash(&admin, &200_i128);
-    assert_eq!(bond.bonded_amount, 700);
-    assert_eq!(bond.slashed_amount, 200);
+    let bond = client.slash(&admin, &identity, &(200 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(bond.bonded_amount, 700 * UNIT);
+    assert_eq!(bond.slashed_amount, 200 * UNIT);
 
     // Available should be 700 - 200 = 500
-    client.withdraw(&500_i128);
-    assert_eq!(client.get_identity_state().bonded_amount, 200);
+    client.withdraw(&identity, &(500 * UNIT));
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        200 * UNIT
+    );
 }
 
 // ============================================================================
@@ -407,54 +513,84 @@ fn test_slash_after_partial_withdrawal() {
 #[test]
 fn test_cumulative_slash_with_capping() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     // First slash: 600 (cumulative = 600)
-    client.slash(&admin, &600_i128);
-    assert_eq!(client.get_identity_state().slashed_amount, 600);
+    client.slash(&admin, &identity, &(600 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(
+        client.get_identity_state(&identity).slashed_amount,
+        600 * UNIT
+    );
 
     // Second slash: 600 (cumulative would be 1200, capped at 1000)
-    let bond = client.slash(&admin, &600_i128);
-    assert_eq!(bond.slashed_amount, 1000);
+    let bond = client.slash(&admin, &identity, &(600 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(bond.slashed_amount, 1000 * UNIT);
 }
 
 #[test]
 fn test_cumulative_slash_incremental() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 10000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 10000 * UNIT, 86400_u64);
 
     // Slash 10% at a time
     for i in 1..=10 {
-        let bond = client.slash(&admin, &1000_i128);
-        assert_eq!(bond.slashed_amount, (i as i128) * 1000_i128);
+        let bond = client.slash(
+            &admin,
+            &identity,
+            &(1000 * UNIT),
+            &symbol_short!("test"),
+            &0,
+        );
+        assert_eq!(bond.slashed_amount, (i as i128) * (1000 * UNIT));
     }
 }
 
 #[test]
 fn test_full_slash_prevents_further_slashing() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     // Fully slash
-    client.slash(&admin, &1000_i128);
-    assert_eq!(client.get_identity_state().slashed_amount, 1000);
+    client.slash(
+        &admin,
+        &identity,
+        &(1000 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
+    assert_eq!(
+        client.get_identity_state(&identity).slashed_amount,
+        1000 * UNIT
+    );
 
     // Attempt further slash (should cap at bonded_amount)
-    let bond = client.slash(&admin, &500_i128);
-    assert_eq!(bond.slashed_amount, 1000);
+    let bond = client.slash(&admin, &identity, &(500 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(bond.slashed_amount, 1000 * UNIT);
 }
 
 #[test]
 fn test_slash_large_amounts() {
     let e = Env::default();
     let large_amount = 1_000_000_000_000_i128;
-    let (client, admin, _identity) = setup_with_bond(&e, large_amount, 86400_u64);
-
-    let bond1 = client.slash(&admin, &(large_amount / 4));
+    let (client, admin, identity) = setup_with_bond(&e, large_amount, 86400_u64);
+
+    let bond1 = client.slash(
+        &admin,
+        &identity,
+        &(large_amount / 4),
+        &symbol_short!("test"),
+        &0,
+    );
     assert_eq!(bond1.slashed_amount, large_amount / 4);
 
     // Second slash accumulates
-    let bond2 = client.slash(&admin, &(large_amount / 4));
+    let bond2 = client.slash(
+        &admin,
+        &identity,
+        &(large_amount / 4),
+        &symbol_short!("test"),
+        &0,
+    );
     // The sum should be capped at bonded_amount
     assert_eq!(bond2.slashed_amount, large_amount / 2);
 }
@@ -466,24 +602,24 @@ fn test_slash_large_amounts() {
 #[test]
 fn test_slash_state_persists() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    client.slash(&admin, &300_i128);
-    let bond1 = client.get_identity_state();
-    assert_eq!(bond1.slashed_amount, 300);
+    client.slash(&admin, &identity, &(300 * UNIT), &symbol_short!("test"), &0);
+    let bond1 = client.get_identity_state(&identity);
+    assert_eq!(bond1.slashed_amount, 300 * UNIT);
 
     // Verify again
-    let bond2 = client.get_identity_state();
-    assert_eq!(bond2.slashed_amount, 300);
+    let bond2 = client.get_identity_state(&identity);
+    assert_eq!(bond2.slashed_amount, 300 * UNIT);
 }
 
 #[test]
 fn test_slash_result_matches_get_state() {
     let e = Env::default();
-    let (client, admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
-    let slash_result = client.slash(&admin, &250_i128);
-    let state = client.get_identity_state();
+    let slash_result = client.slash(&admin, &identity, &(250 * UNIT), &symbol_short!("test"), &0);
+    let state = client.get_identity_state(&identity);
 
     assert_eq!(slash_result.slashed_amount, state.slashed_amount);
     assert_eq!(slash_result.bonded_amount, state.bonded_amount);
@@ -494,21 +630,915 @@ fn test_slash_result_matches_get_state() {
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "not admin")]
+#[should_panic(expected = "Error(Contract, #2)")]
 fn test_error_message_not_admin() {
     let e = Env::default();
-    let (client, _admin, _identity) = setup_with_bond(&e, 1000_i128, 86400_u64);
+    let (client, _admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
 
     let random = Address::generate(&e);
-    client.slash(&random, &100_i128);
+    client.slash(
+        &random,
+        &identity,
+        &(100 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
 }
 
 #[test]
-#[should_panic(expected = "no bond")]
+#[should_panic(expected = "Error(Contract, #3)")]
 fn test_error_message_no_bond() {
     let e = Env::default();
-    let (client, admin, _identity) = setup(&e);
+    let (client, admin, identity) = setup(&e);
 
     // No bond created, try to slash
-    client.slash(&admin, &100_i128);
+    client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
+}
+
+// ============================================================================
+// Category 11: Slash Audit Records
+// ============================================================================
+
+#[test]
+fn test_no_slash_records_initially() {
+    let e = Env::default();
+    let (client, _, _) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    assert_eq!(client.get_latest_slash_record_id(), 0);
+}
+
+#[test]
+fn test_every_slash_path_writes_a_record() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+
+    client.batch_slash(&admin, &vec![&e, (identity.clone(), 100 * UNIT)]);
+    client.slash_bond(&admin, &identity, &(50 * UNIT));
+
+    let records = client.get_slash_records_for_identity(&identity, &0, &10);
+    assert_eq!(records.len(), 2);
+    let batch = records.get(0).unwrap();
+    assert_eq!(batch.reason, Symbol::new(&e, "batch"));
+    assert_eq!(batch.applied_amount, 100 * UNIT);
+    let direct = records.get(1).unwrap();
+    assert_eq!(direct.reason, Symbol::new(&e, "slash_bond"));
+    assert_eq!(direct.cumulative_slashed, 150 * UNIT);
+    assert_eq!(client.get_latest_slash_record_id(), 2);
+}
+
+#[test]
+fn test_slash_with_reason_record_fields() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    e.ledger().with_mut(|li| li.timestamp = 5000);
+    client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
+
+    let reason = Symbol::new(&e, "fraud");
+    let bond = client.slash_with_reason(&admin, &identity, &(300 * UNIT), &reason);
+    assert_eq!(bond.slashed_amount, 400 * UNIT);
+
+    let id = client.get_latest_slash_record_id();
+    assert_eq!(id, 2);
+    let record = client.get_slash_record(&id);
+    assert_eq!(record.id, 2);
+    assert_eq!(record.identity, identity);
+    assert_eq!(record.requested_amount, 300 * UNIT);
+    assert_eq!(record.applied_amount, 300 * UNIT);
+    assert_eq!(record.cumulative_slashed, 400 * UNIT);
+    assert_eq!(record.reason, reason);
+    assert_eq!(record.admin, admin);
+    assert_eq!(record.timestamp, 5000);
+}
+
+#[test]
+fn test_slash_with_reason_sequential_ids_and_immutability() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+
+    client.slash_with_reason(&admin, &identity, &(100 * UNIT), &Symbol::new(&e, "late"));
+    let first = client.get_slash_record(&1);
+
+    for i in 2..=4_u64 {
+        client.slash_with_reason(&admin, &identity, &(100 * UNIT), &Symbol::new(&e, "spam"));
+        assert_eq!(client.get_latest_slash_record_id(), i);
+        assert_eq!(client.get_slash_record(&i).id, i);
+    }
+
+    // Earlier records are never rewritten by later slashes
+    assert_eq!(client.get_slash_record(&1), first);
+    assert_eq!(first.cumulative_slashed, 100 * UNIT);
+    assert_eq!(client.get_slash_record(&4).cumulative_slashed, 400 * UNIT);
+}
+
+#[test]
+fn test_slash_with_reason_records_capped_amount() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.slash(&admin, &identity, &(800 * UNIT), &symbol_short!("test"), &0);
+
+    client.slash_with_reason(&admin, &identity, &(500 * UNIT), &Symbol::new(&e, "fraud"));
+    let record = client.get_slash_record(&2);
+    assert_eq!(record.requested_amount, 500 * UNIT);
+    assert_eq!(record.applied_amount, 200 * UNIT);
+    assert_eq!(record.cumulative_slashed, 1000 * UNIT);
+}
+
+#[test]
+fn test_slash_with_reason_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+
+    let random = Address::generate(&e);
+    let result =
+        client.try_slash_with_reason(&random, &identity, &(100 * UNIT), &Symbol::new(&e, "x"));
+    assert_eq!(result.err(), Some(Ok(BondError::NotAdmin.into())));
+    assert_eq!(client.get_latest_slash_record_id(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+fn test_get_missing_slash_record() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.get_slash_record(&1);
+}
+
+// ============================================================================
+// Category 12: Batch Slashing
+// ============================================================================
+
+#[test]
+fn test_batch_slash_applies_each_and_caps() {
+    let e = Env::default();
+    let (client, admin, first) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    let second = Address::generate(&e);
+    client.create_bond(&second, &(500 * UNIT), &86400_u64, &false, &0_u64);
+
+    let updated = client.batch_slash(
+        &admin,
+        &vec![
+            &e,
+            (first.clone(), 300 * UNIT),
+            (second.clone(), 800 * UNIT),
+        ],
+    );
+    assert_eq!(updated.len(), 2);
+    assert_eq!(updated.get(0).unwrap().slashed_amount, 300 * UNIT);
+    assert_eq!(updated.get(1).unwrap().slashed_amount, 500 * UNIT);
+    assert_eq!(
+        client.get_identity_state(&second).slashed_amount,
+        500 * UNIT
+    );
+}
+
+#[test]
+fn test_batch_slash_skips_missing_bond_and_counts_events() {
+    let e = Env::default();
+    let (client, admin, first) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    let missing = Address::generate(&e);
+    let second = Address::generate(&e);
+    client.create_bond(&second, &(500 * UNIT), &86400_u64, &false, &0_u64);
+
+    let updated = client.batch_slash(
+        &admin,
+        &vec![
+            &e,
+            (first.clone(), 100 * UNIT),
+            (missing.clone(), 100 * UNIT),
+            (second.clone(), 100 * UNIT),
+        ],
+    );
+    assert_eq!(updated.len(), 2);
+
+    let events = e.events().all();
+    let slashed = Symbol::new(&e, "bond_slashed");
+    let skipped = Symbol::new(&e, "slash_skipped");
+    let count = |name: &Symbol| {
+        events
+            .iter()
+            .filter(|(_, topics, _)| *topics == (name.clone(),).into_val(&e))
+            .count()
+    };
+    assert_eq!(count(&slashed), 2);
+    assert_eq!(count(&skipped), 1);
+    let (_, _, data) = events
+        .iter()
+        .find(|(_, topics, _)| *topics == (skipped.clone(),).into_val(&e))
+        .unwrap();
+    let skipped_identity: Address = data.into_val(&e);
+    assert_eq!(skipped_identity, missing);
+}
+
+#[test]
+fn test_batch_slash_is_atomic() {
+    let e = Env::default();
+    let (client, admin, first) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    let closed = Address::generate(&e);
+    client.create_bond(&closed, &(500 * UNIT), &86400_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&closed, &(500 * UNIT));
+
+    // The inactive bond fails the batch, so the first slash is rolled back too
+    let result = client.try_batch_slash(
+        &admin,
+        &vec![
+            &e,
+            (first.clone(), 100 * UNIT),
+            (closed.clone(), 100 * UNIT),
+        ],
+    );
+    assert_eq!(result.err(), Some(Ok(BondError::BondNotActive.into())));
+    assert_eq!(client.get_identity_state(&first).slashed_amount, 0);
+}
+
+#[test]
+fn test_batch_slash_rejects_duplicate_identity() {
+    let e = Env::default();
+    let (client, admin, first) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    let second = Address::generate(&e);
+    client.create_bond(&second, &(500 * UNIT), &86400_u64, &false, &0_u64);
+
+    let result = client.try_batch_slash(
+        &admin,
+        &vec![
+            &e,
+            (first.clone(), 100 * UNIT),
+            (second.clone(), 100 * UNIT),
+            (first.clone(), 100 * UNIT),
+        ],
+    );
+    assert_eq!(result.err(), Some(Ok(BondError::DuplicateTarget.into())));
+    assert_eq!(client.get_identity_state(&first).slashed_amount, 0);
+    assert_eq!(client.get_identity_state(&second).slashed_amount, 0);
+}
+
+#[test]
+fn test_batch_slash_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    let random = Address::generate(&e);
+    let result = client.try_batch_slash(&random, &vec![&e, (identity.clone(), 100 * UNIT)]);
+    assert_eq!(result.err(), Some(Ok(BondError::NotAdmin.into())));
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
+}
+
+// ============================================================================
+// Category 13: Slash Recovery
+// ============================================================================
+
+#[test]
+fn test_recover_slash_partial() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.slash(&admin, &identity, &(400 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(client.get_recoverable_slash_amount(&identity), 400 * UNIT);
+
+    let bond = client.recover_slash(&admin, &identity, &(150 * UNIT));
+    assert_eq!(bond.slashed_amount, 250 * UNIT);
+    assert_eq!(bond.bonded_amount, 1000 * UNIT);
+
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "slash_recovered"),).into_val(&e));
+    let data: (Address, i128, i128) = data.into_val(&e);
+    assert_eq!(data, (identity.clone(), 150 * UNIT, 250 * UNIT));
+    assert_eq!(client.get_recoverable_slash_amount(&identity), 250 * UNIT);
+}
+
+#[test]
+fn test_recover_slash_full_restores_withdrawable() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.slash(
+        &admin,
+        &identity,
+        &(1000 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
+    client.recover_slash(&admin, &identity, &(1000 * UNIT));
+    assert_eq!(client.get_recoverable_slash_amount(&identity), 0);
+
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    let bond = client.withdraw(&identity, &(1000 * UNIT));
+    assert_eq!(bond.bonded_amount, 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #47)")]
+fn test_recover_slash_exceeding_slashed_rejected() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
+    client.recover_slash(&admin, &identity, &(101 * UNIT));
+}
+
+#[test]
+fn test_recover_slash_invalid_amounts() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
+    for amount in [0, -UNIT] {
+        assert_eq!(
+            client.try_recover_slash(&admin, &identity, &amount).err(),
+            Some(Ok(BondError::InvalidAmount.into()))
+        );
+    }
+}
+
+#[test]
+fn test_recover_slash_requires_admin() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
+    let random = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_recover_slash(&random, &identity, &(100 * UNIT))
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(client.get_recoverable_slash_amount(&identity), 100 * UNIT);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_recover_slash_without_bond() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    client.recover_slash(&admin, &identity, &UNIT);
+}
+
+// ============================================================================
+// Category 14: Slash Disputes
+// ============================================================================
+
+const DISPUTE_WINDOW: u64 = 3600;
+
+/// Bond with a dispute window configured and one pending 300-token slash (record 1) at t=1000.
+fn setup_pending_slash(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, identity) = setup_with_bond(e, 1000 * UNIT, 86400_u64);
+    client.set_slash_config(
+        &admin,
+        &SlashConfig {
+            slash_dispute_window_secs: DISPUTE_WINDOW,
+        },
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    client.slash_with_reason(&admin, &identity, &(300 * UNIT), &Symbol::new(e, "fraud"));
+    (client, admin, identity)
+}
+
+#[test]
+fn test_slash_final_without_dispute_window() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    assert_eq!(client.get_slash_config().slash_dispute_window_secs, 0);
+    client.slash_with_reason(&admin, &identity, &(100 * UNIT), &Symbol::new(&e, "late"));
+    assert!(!client.get_slash_record(&1).pending);
+    assert_eq!(
+        client.try_dispute_slash(&1).err(),
+        Some(Ok(SlashDisputeError::NotPending.into()))
+    );
+}
+
+#[test]
+fn test_pending_slash_locks_amount() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_pending_slash(&e);
+    let record = client.get_slash_record(&1);
+    assert!(record.pending);
+    assert!(!record.disputed);
+    assert_eq!(record.dispute_ends_at, 1000 + DISPUTE_WINDOW);
+
+    // Locked, but not yet counted against the available balance
+    assert_eq!(balances(&client, &identity), (1000 * UNIT, 0, 1000 * UNIT));
+    assert_eq!(client.get_encumbrances(&identity).pending_slash, 300 * UNIT);
+
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    assert_eq!(
+        client.try_withdraw(&identity, &(1000 * UNIT)).err(),
+        Some(Ok(BondError::InsufficientBalance.into()))
+    );
+    assert_eq!(
+        client.try_close_bond(&identity).err(),
+        Some(Ok(BondError::BondNotEmpty.into()))
+    );
+    client.withdraw(&identity, &(700 * UNIT));
+    assert_eq!(balances(&client, &identity), (300 * UNIT, 0, 300 * UNIT));
+}
+
+#[test]
+fn test_pending_slash_capped_by_pending_total() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_pending_slash(&e);
+    client.slash_with_reason(&admin, &identity, &(900 * UNIT), &Symbol::new(&e, "fraud"));
+    assert_eq!(client.get_slash_record(&2).applied_amount, 700 * UNIT);
+    assert_eq!(
+        client.get_encumbrances(&identity).pending_slash,
+        1000 * UNIT
+    );
+    assert_eq!(
+        client.try_slash_bond(&admin, &identity, &UNIT).err(),
+        Some(Ok(BondError::SlashExceedsBond.into()))
+    );
+}
+
+#[test]
+fn test_undisputed_slash_finalized_after_window() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_pending_slash(&e);
+
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1000 + DISPUTE_WINDOW - 1);
+    assert_eq!(
+        client.try_finalize_slash(&1).err(),
+        Some(Ok(SlashDisputeError::WindowOpen.into()))
+    );
+
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1000 + DISPUTE_WINDOW);
+    assert_eq!(
+        client.try_dispute_slash(&1).err(),
+        Some(Ok(SlashDisputeError::WindowClosed.into()))
+    );
+    let record = client.finalize_slash(&1);
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "slash_finalized"),).into_val(&e));
+    let id: u64 = data.into_val(&e);
+    assert_eq!(id, 1);
+
+    assert!(!record.pending);
+    assert_eq!(record.applied_amount, 300 * UNIT);
+    assert_eq!(
+        balances(&client, &identity),
+        (1000 * UNIT, 300 * UNIT, 700 * UNIT)
+    );
+    assert_eq!(client.get_encumbrances(&identity).pending_slash, 0);
+    assert_eq!(
+        client.try_finalize_slash(&1).err(),
+        Some(Ok(SlashDisputeError::NotPending.into()))
+    );
+}
+
+#[test]
+fn test_disputed_slash_upheld() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_pending_slash(&e);
+
+    let record = client.dispute_slash(&1);
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "slash_disputed"),).into_val(&e));
+    let data: (u64, Address) = data.into_val(&e);
+    assert_eq!(data, (1, identity.clone()));
+    assert!(record.disputed);
+
+    assert_eq!(
+        client.try_dispute_slash(&1).err(),
+        Some(Ok(SlashDisputeError::AlreadyDisputed.into()))
+    );
+    // A disputed slash is not finalised by the window passing
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1000 + DISPUTE_WINDOW);
+    assert_eq!(
+        client.try_finalize_slash(&1).err(),
+        Some(Ok(SlashDisputeError::UnderDispute.into()))
+    );
+
+    let record = client.adjudicate_slash(&admin, &1, &true);
+    assert!(!record.pending);
+    assert_eq!(
+        balances(&client, &identity),
+        (1000 * UNIT, 300 * UNIT, 700 * UNIT)
+    );
+    assert_eq!(client.get_encumbrances(&identity).pending_slash, 0);
+    assert_eq!(
+        client.try_adjudicate_slash(&admin, &1, &true).err(),
+        Some(Ok(SlashDisputeError::NotPending.into()))
+    );
+}
+
+#[test]
+fn test_disputed_slash_overturned_releases_amount() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_pending_slash(&e);
+    client.slash(&admin, &identity, &(50 * UNIT), &symbol_short!("test"), &0);
+    client.dispute_slash(&1);
+
+    client.adjudicate_slash(&admin, &1, &false);
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (Symbol::new(&e, "slash_adjudicated"),).into_val(&e));
+    let data: (u64, bool) = data.into_val(&e);
+    assert_eq!(data, (1, false));
+
+    // Only the disputed slash is released; nothing was ever counted as slashed
+    assert_eq!(balances(&client, &identity), (1000 * UNIT, 0, 1000 * UNIT));
+    assert_eq!(client.get_encumbrances(&identity).pending_slash, 50 * UNIT);
+    assert!(!client.get_slash_record(&1).pending);
+}
+
+#[test]
+fn test_overturned_slash_withdrawable_after_withdraw_bond() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let sac = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    StellarAssetClient::new(&e, &sac.address()).mint(&identity, &(1000 * UNIT));
+    client.set_token(&admin, &sac.address());
+    client.create_bond(&identity, &(1000 * UNIT), &86400_u64, &false, &0_u64);
+    client.set_slash_config(
+        &admin,
+        &SlashConfig {
+            slash_dispute_window_secs: DISPUTE_WINDOW,
+        },
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    client.slash_with_reason(&admin, &identity, &(300 * UNIT), &Symbol::new(&e, "fraud"));
+    client.dispute_slash(&1);
+
+    // The pending slash stays locked in the bond, which stays open
+    e.ledger().with_mut(|li| li.timestamp = 86400);
+    assert_eq!(client.withdraw_bond(&identity), 700 * UNIT);
+    let bond = client.get_identity_state(&identity);
+    assert!(bond.active);
+    assert_eq!((bond.bonded_amount, bond.slashed_amount), (300 * UNIT, 0));
+
+    client.adjudicate_slash(&admin, &1, &false);
+    client.withdraw(&identity, &(300 * UNIT));
+    let token = TokenClient::new(&e, &sac.address());
+    assert_eq!(token.balance(&identity), 1000 * UNIT);
+    assert!(!client.get_identity_state(&identity).active);
+    assert_eq!(
+        client
+            .get_slash_summary(&identity, &0, &u64::MAX)
+            .overturned_count,
+        1
+    );
+}
+
+#[test]
+fn test_adjudicate_requires_dispute_and_admin() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup_pending_slash(&e);
+    assert_eq!(
+        client.try_adjudicate_slash(&admin, &1, &false).err(),
+        Some(Ok(SlashDisputeError::NotDisputed.into()))
+    );
+    client.dispute_slash(&1);
+    assert_eq!(
+        client
+            .try_adjudicate_slash(&Address::generate(&e), &1, &false)
+            .err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
+    assert_eq!(
+        client.try_dispute_slash(&2).err(),
+        Some(Ok(BondError::SlashRecordNotFound.into()))
+    );
+}
+
+// ============================================================================
+// Category 15: Balance Views
+// ============================================================================
+
+fn balances(client: &CredenceBondClient<'_>, identity: &Address) -> (i128, i128, i128) {
+    (
+        client.get_bonded_amount(identity),
+        client.get_slashed_amount(identity),
+        client.get_available_balance(identity),
+    )
+}
+
+#[test]
+fn test_balance_views_without_slash() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    assert_eq!(balances(&client, &identity), (1000 * UNIT, 0, 1000 * UNIT));
+}
+
+#[test]
+fn test_balance_views_track_slashes() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.slash(&admin, &identity, &(300 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(
+        balances(&client, &identity),
+        (1000 * UNIT, 300 * UNIT, 700 * UNIT)
+    );
+    client.slash(&admin, &identity, &(200 * UNIT), &symbol_short!("test"), &0);
+    assert_eq!(
+        balances(&client, &identity),
+        (1000 * UNIT, 500 * UNIT, 500 * UNIT)
+    );
+    // Over-slash is capped, leaving nothing available
+    client.slash(
+        &admin,
+        &identity,
+        &(5000 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    );
+    assert_eq!(balances(&client, &identity), (1000 * UNIT, 1000 * UNIT, 0));
+}
+
+#[test]
+fn test_balance_views_after_recovery() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.slash(&admin, &identity, &(400 * UNIT), &symbol_short!("test"), &0);
+    client.recover_slash(&admin, &identity, &(100 * UNIT));
+    assert_eq!(
+        balances(&client, &identity),
+        (1000 * UNIT, 300 * UNIT, 700 * UNIT)
+    );
+}
+
+#[test]
+fn test_available_balance_after_withdraw_bond() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.slash(&admin, &identity, &(300 * UNIT), &symbol_short!("test"), &0);
+    e.ledger().with_mut(|li| li.timestamp = 86400);
+    assert_eq!(client.withdraw_bond(&identity), 700 * UNIT);
+    assert_eq!(client.get_available_balance(&identity), 0);
+}
+
+#[test]
+fn test_balance_views_require_bond() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let no_bond = Some(Ok(BondError::NoBond.into()));
+    assert_eq!(client.try_get_available_balance(&identity).err(), no_bond);
+    assert_eq!(client.try_get_bonded_amount(&identity).err(), no_bond);
+    assert_eq!(client.try_get_slashed_amount(&identity).err(), no_bond);
+}
+
+// ============================================================================
+// Category 16: Slash Summaries
+// ============================================================================
+
+#[test]
+fn test_slash_summary_clean_window() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    e.ledger().with_mut(|li| li.timestamp = 5000);
+    client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
+
+    let summary = client.get_slash_summary(&identity, &0, &4999);
+    assert_eq!((summary.count, summary.total), (0, 0));
+    assert!(summary.record_ids.is_empty());
+    assert!(client.attest_clean_record(&identity, &0, &4999));
+    assert!(client.attest_clean_record(&identity, &5001, &u64::MAX));
+    // Never bonded, never slashed
+    assert!(client.attest_clean_record(&Address::generate(&e), &0, &u64::MAX));
+}
+
+#[test]
+fn test_slash_summary_counts_executed_slashes() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("test"), &0);
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    client.slash_with_reason(&admin, &identity, &(200 * UNIT), &Symbol::new(&e, "fraud"));
+    e.ledger().with_mut(|li| li.timestamp = 3000);
+    client.slash(
+        &admin,
+        &identity,
+        &(5000 * UNIT),
+        &symbol_short!("test"),
+        &0,
+    ); // capped at the 700 left
+
+    let summary = client.get_slash_summary(&identity, &1000, &2000);
+    assert_eq!((summary.count, summary.total), (2, 300 * UNIT));
+    assert_eq!(summary.record_ids, vec![&e, 1_u64, 2]);
+    assert_eq!(summary.overturned_count, 0);
+    assert!(!client.attest_clean_record(&identity, &1000, &1000));
+
+    let summary = client.get_slash_summary(&identity, &0, &u64::MAX);
+    assert_eq!((summary.count, summary.total), (3, 1000 * UNIT));
+}
+
+#[test]
+fn test_slash_summary_excludes_overturned_slashes() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_pending_slash(&e);
+    client.dispute_slash(&1);
+    client.adjudicate_slash(&admin, &1, &false);
+
+    let summary = client.get_slash_summary(&identity, &0, &u64::MAX);
+    assert_eq!((summary.count, summary.total), (0, 0));
+    assert!(summary.record_ids.is_empty());
+    assert_eq!(summary.overturned_count, 1);
+    assert!(client.attest_clean_record(&identity, &0, &u64::MAX));
+
+    // A pending slash still counts against the record
+    client.slash_with_reason(&admin, &identity, &(50 * UNIT), &Symbol::new(&e, "late"));
+    let summary = client.get_slash_summary(&identity, &0, &u64::MAX);
+    assert_eq!((summary.count, summary.total), (1, 50 * UNIT));
+    assert_eq!(summary.record_ids, vec![&e, 2_u64]);
+    assert_eq!(summary.overturned_count, 1);
+    assert!(!client.attest_clean_record(&identity, &0, &u64::MAX));
+}
+
+#[test]
+fn test_slash_summary_covers_batch_slash() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    client.batch_slash(&admin, &vec![&e, (identity.clone(), 10 * UNIT)]);
+    let summary = client.get_slash_summary(&identity, &0, &u64::MAX);
+    assert_eq!((summary.count, summary.total), (1, 10 * UNIT));
+}
+
+// ============================================================================
+// Category 17: Slash Records per Identity
+// ============================================================================
+
+#[test]
+fn test_slash_writes_sequential_records_per_identity() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    let other = Address::generate(&e);
+    client.create_bond(&other, &(1000 * UNIT), &86400_u64, &false, &0_u64);
+
+    client.slash(&admin, &identity, &(100 * UNIT), &symbol_short!("late"), &0);
+    let recorded = e.events().all().iter().find(|(_, topics, _)| {
+        *topics == (Symbol::new(&e, "slash_recorded"), identity.clone()).into_val(&e)
+    });
+    let data: (u64, Symbol) = recorded.unwrap().2.into_val(&e);
+    assert_eq!(data, (1, symbol_short!("late")));
+
+    client.slash(&admin, &other, &(100 * UNIT), &symbol_short!("spam"), &0);
+    client.slash(
+        &admin,
+        &identity,
+        &(200 * UNIT),
+        &symbol_short!("fraud"),
+        &0,
+    );
+    assert_eq!(client.get_latest_slash_record_id(), 3);
+
+    let records = client.get_slash_records_for_identity(&identity, &0, &10);
+    assert_eq!(records.len(), 2);
+    let first = records.get(0).unwrap();
+    let second = records.get(1).unwrap();
+    assert_eq!((first.id, first.reason), (1, symbol_short!("late")));
+    assert_eq!((second.id, second.reason), (3, symbol_short!("fraud")));
+    assert_eq!(second.cumulative_slashed, 300 * UNIT);
+
+    let page = client.get_slash_records_for_identity(&identity, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, 3);
+    assert!(client
+        .get_slash_records_for_identity(&identity, &2, &10)
+        .is_empty());
+    assert_eq!(
+        client
+            .get_slash_records_for_identity(&other, &0, &10)
+            .get(0)
+            .unwrap()
+            .id,
+        2
+    );
+}
+
+#[test]
+fn test_slash_record_keeps_requested_and_applied_amounts() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000 * UNIT, 86400_u64);
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(1500 * UNIT),
+        &symbol_short!("fraud"),
+        &0,
+    );
+    assert_eq!(bond.slashed_amount, 1000 * UNIT);
+
+    let record = client.get_slash_record(&1);
+    assert_eq!(record.requested_amount, 1500 * UNIT);
+    assert_eq!(record.applied_amount, 1000 * UNIT);
+    assert_eq!(record.cumulative_slashed, 1000 * UNIT);
+    assert_eq!(record.admin, admin);
+}
+
+// ============================================================================
+// Category 18: Slash Warm-up
+// ============================================================================
+
+const WARMUP: u64 = 3600;
+
+/// Bond created at t=1000 with a one-hour warm-up window.
+fn setup_warmup(e: &Env, is_rolling: bool) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, identity) = setup(e);
+    client.set_slash_warmup(&admin, &WARMUP);
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let notice = if is_rolling { 60_u64 } else { 0 };
+    client.create_bond(&identity, &(1000 * UNIT), &600_u64, &is_rolling, &notice);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_slash_rejected_during_warmup() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_warmup(&e, false);
+    assert_eq!(client.get_slash_warmup(), WARMUP);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + WARMUP - 1);
+
+    let fraud = symbol_short!("fraud");
+    // No incident time, or one predating the bond
+    for incident_at in [0_u64, 999, 1000] {
+        assert_eq!(
+            client
+                .try_slash(&admin, &identity, &(100 * UNIT), &fraud, &incident_at)
+                .err(),
+            Some(Ok(BondError::BondInWarmup.into()))
+        );
+    }
+    // An incident time in the future does not count either
+    assert_eq!(
+        client
+            .try_slash(&admin, &identity, &(100 * UNIT), &fraud, &(1000 + WARMUP))
+            .err(),
+        Some(Ok(BondError::BondInWarmup.into()))
+    );
+    assert_eq!(
+        client
+            .try_batch_slash(&admin, &vec![&e, (identity.clone(), 100 * UNIT)])
+            .err(),
+        Some(Ok(BondError::BondInWarmup.into()))
+    );
+    assert_eq!(
+        client
+            .try_slash_bond(&admin, &identity, &(100 * UNIT))
+            .err(),
+        Some(Ok(BondError::BondInWarmup.into()))
+    );
+    assert_eq!(client.get_identity_state(&identity).slashed_amount, 0);
+}
+
+#[test]
+fn test_slash_during_warmup_for_later_incident() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_warmup(&e, false);
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(100 * UNIT),
+        &symbol_short!("fraud"),
+        &1050,
+    );
+    assert_eq!(bond.slashed_amount, 100 * UNIT);
+    let record = client.get_slash_record(&1);
+    assert_eq!((record.timestamp, record.incident_at), (1100, 1050));
+}
+
+#[test]
+fn test_slash_after_warmup() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_warmup(&e, false);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + WARMUP);
+
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(100 * UNIT),
+        &symbol_short!("fraud"),
+        &0,
+    );
+    assert_eq!(bond.slashed_amount, 100 * UNIT);
+    assert_eq!(client.get_slash_record(&1).incident_at, 0);
+}
+
+#[test]
+fn test_rolling_renewal_does_not_restart_warmup() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_warmup(&e, true);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + WARMUP);
+    let bond = client.renew_if_rolling(&identity);
+    assert_eq!(bond.bond_start, 1000 + WARMUP);
+
+    let bond = client.slash(
+        &admin,
+        &identity,
+        &(100 * UNIT),
+        &symbol_short!("fraud"),
+        &0,
+    );
+    assert_eq!(bond.slashed_amount, 100 * UNIT);
+}
+
+#[test]
+fn test_set_slash_warmup_admin_only() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    assert_eq!(client.get_slash_warmup(), 0);
+    assert_eq!(
+        client.try_set_slash_warmup(&identity, &WARMUP).err(),
+        Some(Ok(BondError::NotAdmin.into()))
+    );
 }
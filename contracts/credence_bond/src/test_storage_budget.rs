@@ -0,0 +1,125 @@
+//! Tests for the instance storage budget: non-critical writes are refused once the soft
+//! limit is reached, while withdrawals and slashes keep working.
+
+#![cfg(test)]
+
+use crate::storage_budget::{ATTESTATION_BASE_BYTES, DEFAULT_SOFT_LIMIT_BYTES};
+use crate::{CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, Env, String};
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, attester)
+}
+
+fn attest(e: &Env, client: &CredenceBondClient<'_>, attester: &Address, data: &str) {
+    let subject = Address::generate(e);
+    let nonce = client.get_nonce(attester);
+    client.add_attestation(attester, &subject, &String::from_str(e, data), &nonce);
+}
+
+/// Approximate size of one attestation written by `fill_budget`.
+const FILL_BYTES: u32 = ATTESTATION_BASE_BYTES + 3;
+
+/// Fill the budget with attestations until the next one is refused.
+fn fill_budget(e: &Env, client: &CredenceBondClient<'_>, attester: &Address) {
+    let mut i = 0;
+    loop {
+        let subject = Address::generate(e);
+        let nonce = client.get_nonce(attester);
+        let data = String::from_str(e, "kyc");
+        if client
+            .try_add_attestation(attester, &subject, &data, &nonce)
+            .is_err()
+        {
+            break;
+        }
+        i += 1;
+        assert!(i < 1000, "budget never filled");
+    }
+}
+
+#[test]
+fn test_default_budget() {
+    let e = Env::default();
+    let (client, _admin, _attester) = setup(&e);
+    assert_eq!(client.get_storage_budget(), (0, DEFAULT_SOFT_LIMIT_BYTES));
+}
+
+#[test]
+fn test_attestations_charge_budget() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    attest(&e, &client, &attester, "kyc");
+    assert_eq!(client.get_storage_budget().0, FILL_BYTES);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+fn test_attestation_refused_when_budget_exhausted() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_storage_soft_limit(&admin, &(ATTESTATION_BASE_BYTES * 2));
+    attest(&e, &client, &attester, "a");
+    attest(&e, &client, &attester, "b");
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_zero_soft_limit_rejected() {
+    let e = Env::default();
+    let (client, admin, _attester) = setup(&e);
+    client.set_storage_soft_limit(&admin, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_soft_limit_requires_admin() {
+    let e = Env::default();
+    let (client, _admin, _attester) = setup(&e);
+    client.set_storage_soft_limit(&Address::generate(&e), &1024);
+}
+
+#[test]
+fn test_changelog_outside_instance_budget() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_storage_soft_limit(&admin, &(FILL_BYTES * 4));
+    fill_budget(&e, &client, &attester);
+    let (used, _) = client.get_storage_budget();
+
+    // The changelog lives in persistent storage, so an exhausted budget does not affect it
+    let identity = Address::generate(&e);
+    client.enable_changelog(&identity, &identity);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    let logged = client.get_changelog(&identity, &0, &100).len();
+    assert!(logged > 0);
+    client.top_up(&identity, &1_000_000);
+    assert_eq!(client.get_changelog(&identity, &0, &100).len(), logged + 1);
+    assert_eq!(client.get_storage_budget().0, used);
+}
+
+#[test]
+fn test_critical_operations_succeed_when_budget_exhausted() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let identity = Address::generate(&e);
+    client.enable_changelog(&identity, &identity);
+    client.create_bond(&identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+
+    let (used, _) = client.get_storage_budget();
+    client.set_storage_soft_limit(&admin, &(used + FILL_BYTES * 4));
+    fill_budget(&e, &client, &attester);
+
+    client.slash(&admin, &identity, &100_000_000, &symbol_short!("test"), &0);
+    e.ledger().with_mut(|li| li.timestamp = 86401);
+    let bond = client.withdraw(&identity, &900_000_000);
+    assert_eq!(bond.bonded_amount, 100_000_000);
+}
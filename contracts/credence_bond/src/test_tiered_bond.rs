@@ -1,20 +1,29 @@
-//! Tests for Tiered Bond System: Bronze, Silver, Gold, Platinum by bonded amount.
+//! Tests for Tiered Bond System: Bronze, Silver, Gold, Platinum, Diamond by bonded amount.
 
 #![cfg(test)]
 
-use crate::tiered_bond::{get_tier_for_amount, TIER_BRONZE_MAX, TIER_GOLD_MAX, TIER_SILVER_MAX};
-use crate::{BondTier, CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env};
+use crate::tiered_bond::{
+    default_tier_config, get_tier_for_amount_with_config, tier_rank, LegacyTierConfig,
+    MAX_TIER_HISTORY, TIER_BRONZE_MAX, TIER_GOLD_MAX, TIER_PLATINUM_MAX, TIER_SILVER_MAX,
+};
+use crate::validation::MIN_BOND_AMOUNT;
+use crate::{BondTier, CredenceBond, CredenceBondClient, TierChangeRecord, TierConfig};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
-    let contract_id = e.register_contract(None, CredenceBond);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
     client.initialize(&admin);
     (client, admin)
 }
 
+fn get_tier_for_amount(amount: i128) -> BondTier {
+    get_tier_for_amount_with_config(amount, &default_tier_config())
+}
+
 #[test]
 fn test_tier_thresholds() {
     assert_eq!(get_tier_for_amount(0), BondTier::Bronze);
@@ -24,7 +33,12 @@ fn test_tier_thresholds() {
     assert_eq!(get_tier_for_amount(TIER_SILVER_MAX), BondTier::Gold);
     assert_eq!(get_tier_for_amount(TIER_GOLD_MAX - 1), BondTier::Gold);
     assert_eq!(get_tier_for_amount(TIER_GOLD_MAX), BondTier::Platinum);
-    assert_eq!(get_tier_for_amount(i128::MAX), BondTier::Platinum);
+    assert_eq!(
+        get_tier_for_amount(TIER_PLATINUM_MAX - 1),
+        BondTier::Platinum
+    );
+    assert_eq!(get_tier_for_amount(TIER_PLATINUM_MAX), BondTier::Diamond);
+    assert_eq!(get_tier_for_amount(i128::MAX), BondTier::Diamond);
 }
 
 #[test]
@@ -33,7 +47,7 @@ fn test_get_tier_after_create_bond() {
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
     client.create_bond(&identity, &(TIER_SILVER_MAX), &86400_u64, &false, &0_u64);
-    let tier = client.get_tier();
+    let tier = client.get_tier(&identity);
     assert_eq!(tier, BondTier::Gold);
 }
 
@@ -43,9 +57,9 @@ fn test_tier_upgrade_on_top_up() {
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
     client.create_bond(&identity, &(TIER_BRONZE_MAX), &86400_u64, &false, &0_u64);
-    assert_eq!(client.get_tier(), BondTier::Silver);
-    client.top_up(&(TIER_SILVER_MAX - TIER_BRONZE_MAX));
-    assert_eq!(client.get_tier(), BondTier::Gold);
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+    client.top_up(&identity, &(TIER_SILVER_MAX - TIER_BRONZE_MAX));
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
 }
 
 #[test]
@@ -54,10 +68,11 @@ fn test_tier_downgrade_on_withdraw() {
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
     client.create_bond(&identity, &(TIER_GOLD_MAX), &86400_u64, &false, &0_u64);
-    assert_eq!(client.get_tier(), BondTier::Platinum);
+    assert_eq!(client.get_tier(&identity), BondTier::Platinum);
     let withdraw_to_silver = TIER_GOLD_MAX - TIER_SILVER_MAX + 1;
-    client.withdraw(&withdraw_to_silver);
-    assert_eq!(client.get_tier(), BondTier::Silver);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    client.withdraw(&identity, &withdraw_to_silver);
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
 }
 
 #[test]
@@ -72,7 +87,587 @@ fn test_tier_unchanged_within_threshold() {
         &false,
         &0_u64,
     );
-    assert_eq!(client.get_tier(), BondTier::Bronze);
-    client.top_up(&(TIER_BRONZE_MAX / 2 - 1));
-    assert_eq!(client.get_tier(), BondTier::Bronze);
+    assert_eq!(client.get_tier(&identity), BondTier::Bronze);
+    client.top_up(&identity, &(TIER_BRONZE_MAX / 2 - 1));
+    assert_eq!(client.get_tier(&identity), BondTier::Bronze);
+}
+
+fn custom_config() -> TierConfig {
+    TierConfig {
+        bronze_min: 0,
+        silver_min: 2_000_000,
+        gold_min: 10_000_000,
+        platinum_min: 50_000_000,
+        diamond_min: 200_000_000,
+    }
+}
+
+#[test]
+fn test_default_tier_config() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_tier_config(), default_tier_config());
+    assert_eq!(client.get_tier_config().silver_min, TIER_BRONZE_MAX);
+}
+
+#[test]
+fn test_tier_for_amount_with_config() {
+    let config = custom_config();
+    assert_eq!(
+        get_tier_for_amount_with_config(1_999_999, &config),
+        BondTier::Bronze
+    );
+    assert_eq!(
+        get_tier_for_amount_with_config(2_000_000, &config),
+        BondTier::Silver
+    );
+    assert_eq!(
+        get_tier_for_amount_with_config(10_000_000, &config),
+        BondTier::Gold
+    );
+    assert_eq!(
+        get_tier_for_amount_with_config(50_000_000, &config),
+        BondTier::Platinum
+    );
+}
+
+#[test]
+fn test_custom_thresholds_change_tier_assignment() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &10_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_tier(&identity), BondTier::Bronze);
+
+    client.set_tier_config(&admin, &custom_config());
+    assert_eq!(client.get_tier_config(), custom_config());
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+
+    client.top_up(&identity, &40_000_000);
+    assert_eq!(client.get_tier(&identity), BondTier::Platinum);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_tier_config_must_ascend() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let mut config = custom_config();
+    config.gold_min = config.silver_min;
+    client.set_tier_config(&admin, &config);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_tier_config_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.set_tier_config(&Address::generate(&e), &custom_config());
+}
+
+#[test]
+fn test_set_tier_thresholds_updates_tier_without_touching_bond() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    assert_eq!(
+        client.get_tier_thresholds(),
+        (TIER_BRONZE_MAX, TIER_SILVER_MAX, TIER_GOLD_MAX)
+    );
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &10_000_000, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_tier(&identity), BondTier::Bronze);
+
+    client.set_tier_thresholds(&admin, &2_000_000, &10_000_000, &50_000_000);
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "tier_thresholds_updated"),).into_val(&e)
+    );
+    let data: (i128, i128, i128, i128, i128) = data.into_val(&e);
+    assert_eq!(
+        data,
+        (0, 2_000_000, 10_000_000, 50_000_000, TIER_PLATINUM_MAX)
+    );
+
+    assert_eq!(
+        client.get_tier_thresholds(),
+        (2_000_000, 10_000_000, 50_000_000)
+    );
+    // The Diamond minimum is kept
+    assert_eq!(
+        client.get_tier_config(),
+        TierConfig {
+            diamond_min: TIER_PLATINUM_MAX,
+            ..custom_config()
+        }
+    );
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_set_tier_thresholds_must_ascend() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_tier_thresholds(&admin, &10_000_000, &10_000_000, &50_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_tier_thresholds_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.set_tier_thresholds(&Address::generate(&e), &2_000_000, &10_000_000, &50_000_000);
+}
+
+#[test]
+fn test_preview_tier_boundaries() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.preview_tier(&0), BondTier::Bronze);
+    assert_eq!(
+        client.preview_tier(&(TIER_BRONZE_MAX - 1)),
+        BondTier::Bronze
+    );
+    assert_eq!(client.preview_tier(&TIER_BRONZE_MAX), BondTier::Silver);
+    assert_eq!(client.preview_tier(&TIER_SILVER_MAX), BondTier::Gold);
+    assert_eq!(client.preview_tier(&TIER_GOLD_MAX), BondTier::Platinum);
+}
+
+#[test]
+fn test_preview_tier_uses_configured_thresholds() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_tier_config(&admin, &custom_config());
+    assert_eq!(client.preview_tier(&10_000_000), BondTier::Gold);
+}
+
+#[test]
+fn test_amount_to_next_tier() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(
+        &identity,
+        &(TIER_BRONZE_MAX - MIN_BOND_AMOUNT),
+        &86400_u64,
+        &false,
+        &0_u64,
+    );
+    assert_eq!(client.amount_to_next_tier(&identity), MIN_BOND_AMOUNT);
+
+    // Exactly on a boundary: the full width of the Silver band is needed
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(
+        client.amount_to_next_tier(&identity),
+        TIER_SILVER_MAX - TIER_BRONZE_MAX
+    );
+
+    let needed = client.amount_to_next_tier(&identity);
+    client.top_up(&identity, &needed);
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+    assert_eq!(
+        client.amount_to_next_tier(&identity),
+        TIER_GOLD_MAX - TIER_SILVER_MAX
+    );
+}
+
+#[test]
+fn test_amount_to_next_tier_zero_at_diamond() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let platinum = Address::generate(&e);
+    client.create_bond(&platinum, &TIER_GOLD_MAX, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_tier(&platinum), BondTier::Platinum);
+    assert_eq!(
+        client.amount_to_next_tier(&platinum),
+        TIER_PLATINUM_MAX - TIER_GOLD_MAX
+    );
+
+    let diamond = Address::generate(&e);
+    client.create_bond(&diamond, &TIER_PLATINUM_MAX, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_tier(&diamond), BondTier::Diamond);
+    assert_eq!(client.amount_to_next_tier(&diamond), 0);
+}
+
+/// Gold bond sitting exactly on the Gold threshold, past its lock-up.
+fn setup_gold_on_threshold(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin) = setup(e);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &TIER_SILVER_MAX, &86400_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+    (client, admin, identity)
+}
+
+fn tier_changed_count(e: &Env) -> usize {
+    e.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == (Symbol::new(e, "tier_changed"),).into_val(e))
+        .count()
+}
+
+#[test]
+fn test_without_hysteresis_small_withdrawal_demotes() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_gold_on_threshold(&e);
+    assert_eq!(client.get_tier_hysteresis(), 0);
+    client.withdraw(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(tier_changed_count(&e), 1);
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+}
+
+#[test]
+fn test_hysteresis_withdrawal_inside_margin_keeps_tier() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_gold_on_threshold(&e);
+    client.set_tier_hysteresis(&admin, &1000); // 10%
+
+    client.withdraw(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(tier_changed_count(&e), 0);
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+    assert!(client.attest_min_tier(&identity, &BondTier::Gold));
+
+    // Topping back up to the threshold emits nothing either
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(tier_changed_count(&e), 0);
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+}
+
+#[test]
+fn test_hysteresis_withdrawal_past_margin_demotes() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_gold_on_threshold(&e);
+    client.set_tier_hysteresis(&admin, &1000); // Gold kept down to 4500 tokens
+
+    // Exactly at the lowered threshold the tier is kept
+    client.withdraw(&identity, &(TIER_SILVER_MAX / 10));
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+
+    client.withdraw(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(tier_changed_count(&e), 1);
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+
+    // Upgrades use the nominal threshold, not the lowered one
+    client.top_up(&identity, &(TIER_SILVER_MAX / 10));
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+}
+
+#[test]
+fn test_hysteresis_config_bounds() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    assert_eq!(
+        client.try_set_tier_hysteresis(&admin, &5001).err(),
+        Some(Ok(crate::BondError::InvalidConfig.into()))
+    );
+    assert_eq!(
+        client
+            .try_set_tier_hysteresis(&Address::generate(&e), &1000)
+            .err(),
+        Some(Ok(crate::BondError::NotAdmin.into()))
+    );
+    client.set_tier_hysteresis(&admin, &5000);
+    assert_eq!(client.get_tier_hysteresis(), 5000);
+}
+
+/// Bond one `MIN_BOND_AMOUNT` below Silver, created at t=1000 with its lock-up over at t=2000.
+fn setup_below_silver(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    let (client, _admin) = setup(e);
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let identity = Address::generate(e);
+    client.create_bond(
+        &identity,
+        &(TIER_BRONZE_MAX - MIN_BOND_AMOUNT),
+        &100_u64,
+        &false,
+        &0_u64,
+    );
+    e.ledger().with_mut(|li| li.timestamp = 2000); // lock-up ended
+    (client, identity)
+}
+
+#[test]
+fn test_tier_history_records_changes() {
+    let e = Env::default();
+    let (client, identity) = setup_below_silver(&e);
+    assert_eq!(client.get_tier_history(&identity, &0, &10).len(), 0);
+    assert_eq!(client.get_tier_since(&identity), 1000);
+
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
+    e.ledger().with_mut(|li| li.timestamp = 3000);
+    client.withdraw(&identity, &MIN_BOND_AMOUNT);
+
+    let history = client.get_tier_history(&identity, &0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(
+        history.get(0).unwrap(),
+        TierChangeRecord {
+            old_tier: BondTier::Bronze,
+            new_tier: BondTier::Silver,
+            amount: TIER_BRONZE_MAX,
+            timestamp: 2000,
+        }
+    );
+    assert_eq!(
+        history.get(1).unwrap(),
+        TierChangeRecord {
+            old_tier: BondTier::Silver,
+            new_tier: BondTier::Bronze,
+            amount: TIER_BRONZE_MAX - MIN_BOND_AMOUNT,
+            timestamp: 3000,
+        }
+    );
+    assert_eq!(client.get_tier_since(&identity), 3000);
+
+    // Paging by sequence number
+    let page = client.get_tier_history(&identity, &1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().new_tier, BondTier::Bronze);
+}
+
+#[test]
+fn test_withdraw_bond_records_tier_change() {
+    let e = Env::default();
+    let (client, identity) = setup_below_silver(&e);
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+
+    e.ledger().with_mut(|li| li.timestamp = 3000);
+    client.withdraw_bond(&identity);
+    assert_eq!(client.get_tier(&identity), BondTier::Bronze);
+    let history = client.get_tier_history(&identity, &0, &10);
+    assert_eq!(history.len(), 2);
+    assert_eq!(
+        history.get(1).unwrap(),
+        TierChangeRecord {
+            old_tier: BondTier::Silver,
+            new_tier: BondTier::Bronze,
+            amount: 0,
+            timestamp: 3000,
+        }
+    );
+}
+
+#[test]
+fn test_tier_history_skips_unchanged_tier() {
+    let e = Env::default();
+    let (client, identity) = setup_below_silver(&e);
+    client.withdraw(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(client.get_tier_history(&identity, &0, &10).len(), 0);
+    assert_eq!(client.get_tier_since(&identity), 1000);
+}
+
+#[test]
+fn test_tier_history_pruned_to_cap() {
+    let e = Env::default();
+    let (client, identity) = setup_below_silver(&e);
+    for _ in 0..30 {
+        client.top_up(&identity, &MIN_BOND_AMOUNT);
+        client.withdraw(&identity, &MIN_BOND_AMOUNT);
+    }
+
+    // 60 changes recorded, the oldest 10 pruned
+    let history = client.get_tier_history(&identity, &0, &100);
+    assert_eq!(history.len(), MAX_TIER_HISTORY);
+    assert_eq!(history.get(0).unwrap().new_tier, BondTier::Silver);
+    let tail = client.get_tier_history(&identity, &58, &100);
+    assert_eq!(tail.len(), 2);
+    assert_eq!(client.get_tier_history(&identity, &60, &10).len(), 0);
+}
+
+const QUALIFICATION: u64 = 1000;
+
+/// Silver bond created at t=1000 (lock-up over at t=1100) with a qualification period set.
+fn setup_qualifying(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin) = setup(e);
+    client.set_tier_qualification_period(&admin, &QUALIFICATION);
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &TIER_BRONZE_MAX, &100_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+fn at(e: &Env, timestamp: u64) {
+    e.ledger().with_mut(|li| li.timestamp = timestamp);
+}
+
+#[test]
+fn test_effective_tier_defaults_to_instant_tier() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_tier_qualification_period(), 0);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &TIER_GOLD_MAX, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Platinum);
+}
+
+#[test]
+fn test_effective_tier_requires_holding_period() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_qualifying(&e);
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Bronze);
+
+    at(&e, 1000 + QUALIFICATION - 1);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Bronze);
+    at(&e, 1000 + QUALIFICATION);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Silver);
+
+    // Topping up to Gold starts the Gold clock; Silver stays qualified
+    client.top_up(&identity, &(TIER_SILVER_MAX - TIER_BRONZE_MAX));
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Silver);
+    at(&e, 1000 + 2 * QUALIFICATION);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Gold);
+}
+
+#[test]
+fn test_withdrawal_below_threshold_resets_clock() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_qualifying(&e);
+    client.top_up(&identity, &(TIER_SILVER_MAX - TIER_BRONZE_MAX));
+    at(&e, 3000);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Gold);
+
+    client.withdraw(&identity, &MIN_BOND_AMOUNT);
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(client.get_tier(&identity), BondTier::Gold);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Silver);
+    at(&e, 3000 + QUALIFICATION);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Gold);
+}
+
+#[test]
+fn test_slash_below_threshold_resets_clock() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_qualifying(&e);
+    at(&e, 3000);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Silver);
+
+    client.slash(
+        &admin,
+        &identity,
+        &MIN_BOND_AMOUNT,
+        &symbol_short!("test"),
+        &0,
+    );
+    // The instantaneous tier follows the bonded amount; the effective tier drops at once
+    assert_eq!(client.get_tier(&identity), BondTier::Silver);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Bronze);
+
+    client.recover_slash(&admin, &identity, &MIN_BOND_AMOUNT);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Bronze);
+    at(&e, 3000 + QUALIFICATION);
+    assert_eq!(client.get_effective_tier(&identity), BondTier::Silver);
+}
+
+#[test]
+fn test_set_qualification_period_admin_only() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client
+            .try_set_tier_qualification_period(&Address::generate(&e), &QUALIFICATION)
+            .err(),
+        Some(Ok(crate::BondError::NotAdmin.into()))
+    );
+}
+
+#[test]
+fn test_tier_rank_orders_all_five_tiers() {
+    let tiers = [
+        BondTier::Bronze,
+        BondTier::Silver,
+        BondTier::Gold,
+        BondTier::Platinum,
+        BondTier::Diamond,
+    ];
+    for (i, tier) in tiers.iter().enumerate() {
+        assert_eq!(tier_rank(tier), i as u32);
+    }
+    // Each threshold lands one rank higher than the amount just below it
+    let config = default_tier_config();
+    for min in [
+        config.silver_min,
+        config.gold_min,
+        config.platinum_min,
+        config.diamond_min,
+    ] {
+        assert_eq!(
+            tier_rank(&get_tier_for_amount_with_config(min, &config)),
+            tier_rank(&get_tier_for_amount_with_config(min - 1, &config)) + 1
+        );
+    }
+}
+
+#[test]
+fn test_top_up_into_diamond() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(
+        &identity,
+        &(TIER_PLATINUM_MAX - MIN_BOND_AMOUNT),
+        &86400_u64,
+        &false,
+        &0_u64,
+    );
+    assert_eq!(client.get_tier(&identity), BondTier::Platinum);
+    assert!(!client.attest_min_tier(&identity, &BondTier::Diamond));
+
+    client.top_up(&identity, &MIN_BOND_AMOUNT);
+    let tier_changed = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == (Symbol::new(&e, "tier_changed"),).into_val(&e))
+        .expect("no tier_changed event");
+    let data: (Address, BondTier) = tier_changed.2.into_val(&e);
+    assert_eq!(data, (identity.clone(), BondTier::Diamond));
+
+    assert_eq!(client.get_tier(&identity), BondTier::Diamond);
+    assert!(client.attest_min_tier(&identity, &BondTier::Diamond));
+    assert!(client.attest_min_tier(&identity, &BondTier::Platinum));
+    let change = client.get_tier_history(&identity, &1, &1).get(0).unwrap();
+    assert_eq!(
+        (change.old_tier, change.new_tier),
+        (BondTier::Platinum, BondTier::Diamond)
+    );
+}
+
+#[test]
+fn test_config_set_before_diamond_still_read() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let legacy = LegacyTierConfig {
+        bronze_min: 0,
+        silver_min: 2_000_000,
+        gold_min: 10_000_000,
+        platinum_min: 50_000_000,
+    };
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .set(&Symbol::new(&e, "tier_config"), &legacy);
+    });
+    assert_eq!(
+        client.get_tier_config(),
+        TierConfig {
+            diamond_min: TIER_PLATINUM_MAX,
+            ..custom_config()
+        }
+    );
+    assert_eq!(client.preview_tier(&50_000_000), BondTier::Platinum);
+
+    // The next write replaces it
+    client.set_tier_thresholds(&admin, &3_000_000, &10_000_000, &50_000_000);
+    assert_eq!(client.get_tier_config().silver_min, 3_000_000);
+    e.as_contract(&client.address, || {
+        assert!(!e.storage().instance().has(&Symbol::new(&e, "tier_config")));
+    });
 }
@@ -0,0 +1,207 @@
+//! Tests for bond token transfers: deposits pull funds into the contract, withdrawals pay
+//! the destination and early exit penalties go to the treasury.
+
+#![cfg(test)]
+
+use crate::{BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, IntoVal};
+
+const START_BALANCE: i128 = 10_000_000_000;
+
+struct Setup<'a> {
+    client: CredenceBondClient<'a>,
+    token: TokenClient<'a>,
+    admin: Address,
+    identity: Address,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+
+    let issuer = Address::generate(e);
+    let sac = e.register_stellar_asset_contract_v2(issuer);
+    let identity = Address::generate(e);
+    StellarAssetClient::new(e, &sac.address()).mint(&identity, &START_BALANCE);
+    client.set_token(&admin, &sac.address());
+
+    Setup {
+        client,
+        token: TokenClient::new(e, &sac.address()),
+        admin,
+        identity,
+    }
+}
+
+#[test]
+fn test_no_token_by_default() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e));
+    assert_eq!(client.get_token(), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_token_requires_admin() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .set_token(&Address::generate(&e), &Address::generate(&e));
+}
+
+#[test]
+fn test_create_bond_and_top_up_transfer_in() {
+    let e = Env::default();
+    let s = setup(&e);
+    let contract = s.client.address.clone();
+
+    s.client
+        .create_bond(&s.identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(s.token.balance(&s.identity), START_BALANCE - 1_000_000_000);
+    assert_eq!(s.token.balance(&contract), 1_000_000_000);
+
+    s.client.top_up(&s.identity, &500_000_000);
+    assert_eq!(s.token.balance(&s.identity), START_BALANCE - 1_500_000_000);
+    assert_eq!(s.token.balance(&contract), 1_500_000_000);
+}
+
+#[test]
+fn test_deposit_premium_stays_in_contract() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client.set_deposit_premium(&s.admin, &100);
+
+    let bond = s
+        .client
+        .create_bond(&s.identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 990_000_000);
+    assert_eq!(s.token.balance(&s.client.address), 1_000_000_000);
+}
+
+#[test]
+fn test_withdraw_transfers_out() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .create_bond(&s.identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 86401);
+
+    s.client.withdraw(&s.identity, &400_000_000);
+    assert_eq!(s.token.balance(&s.identity), START_BALANCE - 600_000_000);
+    assert_eq!(s.token.balance(&s.client.address), 600_000_000);
+}
+
+#[test]
+fn test_withdraw_bond_transfers_out() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .create_bond(&s.identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    e.ledger().with_mut(|li| li.timestamp = 86401);
+
+    assert_eq!(s.client.withdraw_bond(&s.identity), 1_000_000_000);
+    assert_eq!(s.token.balance(&s.identity), START_BALANCE);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_negative_withdrawal_rejected() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .set_early_exit_config(&s.admin, &Address::generate(&e), &1000);
+    s.client
+        .create_bond(&s.identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(
+        s.client
+            .try_withdraw_early(&s.identity, &-5_000_000_000)
+            .err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+    e.ledger().with_mut(|li| li.timestamp = 86401);
+    for amount in [-5_000_000_000_i128, 0] {
+        assert_eq!(
+            s.client.try_withdraw(&s.identity, &amount).err(),
+            Some(Ok(BondError::InvalidAmount.into()))
+        );
+    }
+
+    // The bond cannot be inflated and then drained
+    assert_eq!(
+        s.client.get_identity_state(&s.identity).bonded_amount,
+        1_000_000_000
+    );
+    assert!(s.client.try_withdraw(&s.identity, &2_000_000_000).is_err());
+    assert_eq!(s.token.balance(&s.client.address), 1_000_000_000);
+}
+
+#[test]
+fn test_withdraw_early_splits_penalty_to_treasury() {
+    let e = Env::default();
+    let s = setup(&e);
+    let treasury = Address::generate(&e);
+    s.client.set_early_exit_config(&s.admin, &treasury, &1000);
+    s.client
+        .create_bond(&s.identity, &1_000_000_000_i128, &1000_u64, &false, &0_u64);
+
+    // Half the lock-up remaining: penalty = 1000 * 10% * 0.5 = 5%
+    e.ledger().with_mut(|li| li.timestamp = 500);
+    s.client.withdraw_early(&s.identity, &1_000_000_000);
+
+    assert_eq!(s.token.balance(&treasury), 50_000_000);
+    assert_eq!(s.token.balance(&s.identity), START_BALANCE - 50_000_000);
+    assert_eq!(s.token.balance(&s.client.address), 0);
+}
+
+#[test]
+fn test_failed_transfer_leaves_bond_unchanged() {
+    let e = Env::default();
+    let s = setup(&e);
+    s.client
+        .create_bond(&s.identity, &1_000_000_000_i128, &86400_u64, &false, &0_u64);
+
+    // Top-up larger than the identity's remaining token balance
+    let result = s.client.try_top_up(&s.identity, &START_BALANCE);
+    assert!(result.is_err());
+    assert_eq!(
+        s.client.get_identity_state(&s.identity).bonded_amount,
+        1_000_000_000
+    );
+    assert_eq!(s.token.balance(&s.client.address), 1_000_000_000);
+}
+
+#[test]
+fn test_transfer_requires_identity_auth() {
+    let e = Env::default();
+    let s = setup(&e);
+    let amount = 1_000_000_000_i128;
+
+    // Authorize only the bond contract call; the nested token transfer is covered by the
+    // same root authorization.
+    e.set_auths(&[]);
+    s.client
+        .mock_auths(&[MockAuth {
+            address: &s.identity,
+            invoke: &MockAuthInvoke {
+                contract: &s.client.address,
+                fn_name: "create_bond",
+                args: (s.identity.clone(), amount, 86400_u64, false, 0_u64).into_val(&e),
+                sub_invokes: &[MockAuthInvoke {
+                    contract: &s.token.address,
+                    fn_name: "transfer",
+                    args: (s.identity.clone(), s.client.address.clone(), amount).into_val(&e),
+                    sub_invokes: &[],
+                }],
+            },
+        }])
+        .create_bond(&s.identity, &amount, &86400_u64, &false, &0_u64);
+    assert_eq!(s.token.balance(&s.client.address), amount);
+}
@@ -0,0 +1,107 @@
+//! Tests for `top_up_and_extend`: both mutations land together, matching separate
+//! `top_up` + `extend_duration` calls, and a failure in either leaves the bond unchanged.
+
+#![cfg(test)]
+
+use crate::{BondError, BondTier, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Env, IntoVal, Symbol};
+
+const BOND_AMOUNT: i128 = 500_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_top_up_and_extend_applies_both() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+
+    let bond = client.top_up_and_extend(&identity, &BOND_AMOUNT, &3600_u64);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT * 2);
+    assert_eq!(bond.bond_duration, 86400 + 3600);
+    let stored = client.get_identity_state(&identity);
+    assert_eq!(stored.bonded_amount, bond.bonded_amount);
+    assert_eq!(stored.bond_duration, bond.bond_duration);
+}
+
+#[test]
+fn test_top_up_and_extend_matches_separate_calls() {
+    let e = Env::default();
+    let (client, _admin, combined) = setup(&e);
+    let separate = Address::generate(&e);
+    client.create_bond(&separate, &BOND_AMOUNT, &86400_u64, &false, &0_u64);
+
+    let a = client.top_up_and_extend(&combined, &BOND_AMOUNT, &3600_u64);
+    client.top_up(&separate, &BOND_AMOUNT);
+    let b = client.extend_duration(&separate, &3600_u64);
+
+    assert_eq!(a.bonded_amount, b.bonded_amount);
+    assert_eq!(a.bond_duration, b.bond_duration);
+    assert_eq!(a.bond_start, b.bond_start);
+    assert_eq!(client.get_tier(&combined), client.get_tier(&separate));
+}
+
+#[test]
+fn test_top_up_and_extend_emits_event() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+
+    client.top_up_and_extend(&identity, &BOND_AMOUNT, &3600_u64);
+    let events = e.events().all();
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "bond_topped_up_and_extended"),).into_val(&e)
+    );
+    let data: (Address, i128, i128, u64, u64, BondTier) = data.into_val(&e);
+    assert_eq!(
+        data,
+        (
+            identity,
+            BOND_AMOUNT,
+            BOND_AMOUNT * 2,
+            86400,
+            86400 + 3600,
+            BondTier::Silver
+        )
+    );
+}
+
+#[test]
+fn test_duration_overflow_leaves_amount_unchanged() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+
+    let result = client.try_top_up_and_extend(&identity, &BOND_AMOUNT, &u64::MAX);
+    assert_eq!(result.err(), Some(Ok(BondError::Overflow.into())));
+    let bond = client.get_identity_state(&identity);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT);
+    assert_eq!(bond.bond_duration, 86400);
+}
+
+#[test]
+fn test_invalid_amount_leaves_duration_unchanged() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+
+    let result = client.try_top_up_and_extend(&identity, &1, &3600_u64);
+    assert!(result.is_err());
+    assert_eq!(client.get_identity_state(&identity).bond_duration, 86400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_top_up_and_extend_requires_bond() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.top_up_and_extend(&Address::generate(&e), &BOND_AMOUNT, &3600_u64);
+}
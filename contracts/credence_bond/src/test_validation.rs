@@ -2,17 +2,18 @@
 //!
 //! Tests the validation functions for bond amounts to ensure they properly enforce
 //! minimum and maximum limits.
-//! 
+//!
 
 #![cfg(test)]
 
-use super::validation::{validate_bond_amount, MIN_BOND_AMOUNT, MAX_BOND_AMOUNT};
-use super::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env};
+use super::validation::{validate_bond_amount, MAX_BOND_AMOUNT, MIN_BOND_AMOUNT};
+use super::{AmountError, BondError, CredenceBond, CredenceBondClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, Address, Env};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
-    let contract_id = e.register_contract(None, CredenceBond);
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = Address::generate(e);
     client.initialize(&admin);
@@ -25,48 +26,55 @@ fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
 
 #[test]
 fn test_validate_bond_amount_valid() {
+    let e = Env::default();
     // Test valid amounts within range
-    validate_bond_amount(MIN_BOND_AMOUNT);
-    validate_bond_amount(MAX_BOND_AMOUNT);
-    validate_bond_amount((MIN_BOND_AMOUNT + MAX_BOND_AMOUNT) / 2);
-    validate_bond_amount(MIN_BOND_AMOUNT + 1);
-    validate_bond_amount(MAX_BOND_AMOUNT - 1);
+    validate_bond_amount(&e, MIN_BOND_AMOUNT);
+    validate_bond_amount(&e, MAX_BOND_AMOUNT);
+    validate_bond_amount(&e, (MIN_BOND_AMOUNT + MAX_BOND_AMOUNT) / 2);
+    validate_bond_amount(&e, MIN_BOND_AMOUNT + 1);
+    validate_bond_amount(&e, MAX_BOND_AMOUNT - 1);
 }
 
 #[test]
-#[should_panic(expected = "bond amount below minimum required")]
+#[should_panic(expected = "Error(Contract, #701)")]
 fn test_validate_bond_amount_below_minimum() {
-    validate_bond_amount(MIN_BOND_AMOUNT - 1);
+    let e = Env::default();
+    validate_bond_amount(&e, MIN_BOND_AMOUNT - 1);
 }
 
 #[test]
-#[should_panic(expected = "bond amount below minimum required")]
+#[should_panic(expected = "Error(Contract, #701)")]
 fn test_validate_bond_amount_zero() {
-    validate_bond_amount(0);
+    let e = Env::default();
+    validate_bond_amount(&e, 0);
 }
 
 #[test]
-#[should_panic(expected = "bond amount cannot be negative")]
+#[should_panic(expected = "Error(Contract, #5)")]
 fn test_validate_bond_amount_negative() {
-    validate_bond_amount(-1);
+    let e = Env::default();
+    validate_bond_amount(&e, -1);
 }
 
 #[test]
-#[should_panic(expected = "bond amount cannot be negative")]
+#[should_panic(expected = "Error(Contract, #5)")]
 fn test_validate_bond_amount_large_negative() {
-    validate_bond_amount(-1000000);
+    let e = Env::default();
+    validate_bond_amount(&e, -1000000);
 }
 
 #[test]
-#[should_panic(expected = "bond amount exceeds maximum allowed")]
+#[should_panic(expected = "Error(Contract, #702)")]
 fn test_validate_bond_amount_above_maximum() {
-    validate_bond_amount(MAX_BOND_AMOUNT + 1);
+    let e = Env::default();
+    validate_bond_amount(&e, MAX_BOND_AMOUNT + 1);
 }
 
 #[test]
-#[should_panic(expected = "bond amount exceeds maximum allowed")]
+#[should_panic(expected = "Error(Contract, #702)")]
 fn test_validate_bond_amount_max_i128() {
-    validate_bond_amount(i128::MAX);
+    let e = Env::default();
+    validate_bond_amount(&e, i128::MAX);
 }
 
 // ============================================================================
@@ -78,56 +86,85 @@ fn test_create_bond_with_valid_amount() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
+
     // Test with minimum valid amount
-    let bond = client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64);
+    let bond = client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64, &false, &0_u64);
     assert_eq!(bond.bonded_amount, MIN_BOND_AMOUNT);
     assert!(bond.active);
-    
+
     // Test with maximum valid amount
-    let bond2 = client.create_bond(&identity, &MAX_BOND_AMOUNT, &86400_u64);
+    let other = Address::generate(&e);
+    let bond2 = client.create_bond(&other, &MAX_BOND_AMOUNT, &86400_u64, &false, &0_u64);
     assert_eq!(bond2.bonded_amount, MAX_BOND_AMOUNT);
     assert!(bond2.active);
 }
 
 #[test]
-#[should_panic(expected = "bond amount below minimum required")]
 fn test_create_bond_with_amount_below_minimum() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
-    client.create_bond(&identity, &(MIN_BOND_AMOUNT - 1), &86400_u64);
+
+    assert_eq!(
+        client
+            .try_create_bond(
+                &identity,
+                &(MIN_BOND_AMOUNT - 1),
+                &86400_u64,
+                &false,
+                &0_u64,
+            )
+            .err(),
+        Some(Ok(AmountError::BelowMinimum.into()))
+    );
 }
 
 #[test]
-#[should_panic(expected = "bond amount below minimum required")]
 fn test_create_bond_with_zero_amount() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
-    client.create_bond(&identity, &0_i128, &86400_u64);
+
+    assert_eq!(
+        client
+            .try_create_bond(&identity, &0_i128, &86400_u64, &false, &0_u64)
+            .err(),
+        Some(Ok(AmountError::BelowMinimum.into()))
+    );
 }
 
 #[test]
-#[should_panic(expected = "bond amount cannot be negative")]
 fn test_create_bond_with_negative_amount() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
-    client.create_bond(&identity, &(-1000_i128), &86400_u64);
+
+    assert_eq!(
+        client
+            .try_create_bond(&identity, &(-1000_i128), &86400_u64, &false, &0_u64)
+            .err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
 }
 
 #[test]
-#[should_panic(expected = "bond amount exceeds maximum allowed")]
 fn test_create_bond_with_amount_above_maximum() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
-    client.create_bond(&identity, &(MAX_BOND_AMOUNT + 1), &86400_u64);
+
+    assert_eq!(
+        client
+            .try_create_bond(
+                &identity,
+                &(MAX_BOND_AMOUNT + 1),
+                &86400_u64,
+                &false,
+                &0_u64,
+            )
+            .err(),
+        Some(Ok(AmountError::AboveMaximum.into()))
+    );
 }
 
 // ============================================================================
@@ -139,42 +176,42 @@ fn test_top_up_with_valid_amount() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
+
     // Create initial bond
-    client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64);
-    
+    client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64, &false, &0_u64);
+
     // Top up with valid amount
-    let bond = client.top_up(&1000000); // 1 additional token
+    let bond = client.top_up(&identity, &1000000); // 1 additional token
     assert_eq!(bond.bonded_amount, MIN_BOND_AMOUNT + 1000000);
     assert!(bond.active);
 }
 
 #[test]
-#[should_panic(expected = "top-up amount below minimum required: 0 (minimum: 1000000)")]
+#[should_panic(expected = "Error(Contract, #5)")]
 fn test_top_up_with_zero_amount() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
+
     // Create initial bond
-    client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64);
-    
+    client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64, &false, &0_u64);
+
     // Try to top up with zero amount
-    client.top_up(&0_i128);
+    client.top_up(&identity, &0_i128);
 }
 
 #[test]
-#[should_panic(expected = "top-up amount below minimum required: -1000 (minimum: 1000000)")]
+#[should_panic(expected = "Error(Contract, #5)")]
 fn test_top_up_with_negative_amount() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
+
     // Create initial bond
-    client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64);
-    
+    client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64, &false, &0_u64);
+
     // Try to top up with negative amount
-    client.top_up(&(-1000_i128));
+    client.top_up(&identity, &(-1000_i128));
 }
 
 // ============================================================================
@@ -183,33 +220,36 @@ fn test_top_up_with_negative_amount() {
 
 #[test]
 fn test_boundary_values() {
+    let e = Env::default();
     // Test exactly at minimum boundary
-    validate_bond_amount(MIN_BOND_AMOUNT);
-    
+    validate_bond_amount(&e, MIN_BOND_AMOUNT);
+
     // Test exactly at maximum boundary
-    validate_bond_amount(MAX_BOND_AMOUNT);
-    
+    validate_bond_amount(&e, MAX_BOND_AMOUNT);
+
     // Test just above minimum
-    validate_bond_amount(MIN_BOND_AMOUNT + 1);
-    
+    validate_bond_amount(&e, MIN_BOND_AMOUNT + 1);
+
     // Test just below maximum
-    validate_bond_amount(MAX_BOND_AMOUNT - 1);
+    validate_bond_amount(&e, MAX_BOND_AMOUNT - 1);
 }
 
 // ============================================================================
-// ERROR MESSAGE VERIFICATION
+// ERROR CODE VERIFICATION
 // ============================================================================
 
 #[test]
-#[should_panic(expected = "bond amount below minimum required: 999999 (minimum: 1000000)")]
-fn test_error_message_includes_amount_and_minimum() {
-    validate_bond_amount(999999); // MIN_BOND_AMOUNT - 1
+#[should_panic(expected = "Error(Contract, #701)")]
+fn test_error_code_for_amount_below_minimum() {
+    let e = Env::default();
+    validate_bond_amount(&e, 999999); // MIN_BOND_AMOUNT - 1
 }
 
 #[test]
-#[should_panic(expected = "bond amount exceeds maximum allowed: 100000000000001 (maximum: 100000000000000)")]
-fn test_error_message_includes_amount_and_maximum() {
-    validate_bond_amount(MAX_BOND_AMOUNT + 1);
+#[should_panic(expected = "Error(Contract, #702)")]
+fn test_error_code_for_amount_above_maximum() {
+    let e = Env::default();
+    validate_bond_amount(&e, MAX_BOND_AMOUNT + 1);
 }
 
 // ============================================================================
@@ -221,30 +261,213 @@ fn test_create_bond_then_top_up_valid_scenario() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
+
     // Create bond with minimum amount
-    let bond = client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64);
+    let bond = client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64, &false, &0_u64);
     assert_eq!(bond.bonded_amount, MIN_BOND_AMOUNT);
-    
+
     // Top up with valid amount
-    let bond = client.top_up(&1000000); // 1 additional token
+    let bond = client.top_up(&identity, &1000000); // 1 additional token
     assert_eq!(bond.bonded_amount, MIN_BOND_AMOUNT + 1000000);
-    
+
     // Top up again with another valid amount
-    let bond = client.top_up(&5000000); // 5 additional tokens
+    let bond = client.top_up(&identity, &5000000); // 5 additional tokens
     assert_eq!(bond.bonded_amount, MIN_BOND_AMOUNT + 1000000 + 5000000);
 }
 
 #[test]
-#[should_panic(expected = "top-up amount below minimum required: 0 (minimum: 1000000)")]
+#[should_panic(expected = "Error(Contract, #5)")]
 fn test_create_bond_with_min_amount_then_invalid_top_up() {
     let e = Env::default();
     let (client, _admin) = setup(&e);
     let identity = Address::generate(&e);
-    
+
     // Create bond with minimum amount
-    client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64);
-    
+    client.create_bond(&identity, &MIN_BOND_AMOUNT, &86400_u64, &false, &0_u64);
+
     // Try to top up with zero (should fail)
-    client.top_up(&0_i128);
-}
\ No newline at end of file
+    client.top_up(&identity, &0_i128);
+}
+
+// ============================================================================
+// STRICT AMOUNTS (QUANTUM CONFORMANCE)
+// ============================================================================
+
+const QUANTUM: i128 = 1_000_000;
+
+#[test]
+fn test_non_conforming_amounts_accepted_when_not_strict() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_amount_quantum(&admin, &QUANTUM);
+    let identity = Address::generate(&e);
+
+    let bond = client.create_bond(&identity, &1_000_000_001, &86400_u64, &false, &0_u64);
+    assert_eq!(bond.bonded_amount, 1_000_000_001);
+    assert_eq!(client.get_strict_amounts(), (false, QUANTUM));
+}
+
+#[test]
+fn test_conforming_amounts_accepted_when_strict() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_amount_quantum(&admin, &QUANTUM);
+    client.set_strict_amounts(&admin, &true);
+    let identity = Address::generate(&e);
+
+    client.create_bond(&identity, &(10 * QUANTUM), &86400_u64, &false, &0_u64);
+    client.top_up(&identity, &(2 * QUANTUM));
+    client.slash(&admin, &identity, &QUANTUM, &symbol_short!("test"), &0);
+    e.ledger().with_mut(|li| li.timestamp = 86400); // lock-up ended
+    let bond = client.withdraw(&identity, &QUANTUM);
+    assert_eq!(bond.bonded_amount, 11 * QUANTUM);
+    assert_eq!(bond.slashed_amount, QUANTUM);
+}
+
+#[test]
+fn test_strict_rejects_non_conforming_create_bond() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_amount_quantum(&admin, &QUANTUM);
+    client.set_strict_amounts(&admin, &true);
+    let identity = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_create_bond(&identity, &1_000_000_001, &86400_u64, &false, &0_u64)
+            .err(),
+        Some(Ok(AmountError::NonConformingAmount.into()))
+    );
+}
+
+#[test]
+fn test_strict_rejects_non_conforming_top_up() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &(10 * QUANTUM), &86400_u64, &false, &0_u64);
+    client.set_amount_quantum(&admin, &QUANTUM);
+    client.set_strict_amounts(&admin, &true);
+    assert_eq!(
+        client.try_top_up(&identity, &(QUANTUM + 1)).err(),
+        Some(Ok(AmountError::NonConformingAmount.into()))
+    );
+}
+
+#[test]
+fn test_strict_rejects_non_conforming_withdraw() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &(10 * QUANTUM), &86400_u64, &false, &0_u64);
+    client.set_amount_quantum(&admin, &QUANTUM);
+    client.set_strict_amounts(&admin, &true);
+    assert_eq!(
+        client.try_withdraw(&identity, &(QUANTUM - 1)).err(),
+        Some(Ok(AmountError::NonConformingAmount.into()))
+    );
+}
+
+#[test]
+fn test_strict_rejects_non_conforming_slash() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let identity = Address::generate(&e);
+    client.create_bond(&identity, &(10 * QUANTUM), &86400_u64, &false, &0_u64);
+    client.set_amount_quantum(&admin, &QUANTUM);
+    client.set_strict_amounts(&admin, &true);
+    assert_eq!(
+        client
+            .try_slash(&admin, &identity, &1, &symbol_short!("test"), &0)
+            .err(),
+        Some(Ok(AmountError::NonConformingAmount.into()))
+    );
+}
+
+#[test]
+fn test_set_amount_quantum_zero() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    assert_eq!(
+        client.try_set_amount_quantum(&admin, &0).err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_strict_amounts_non_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let other = Address::generate(&e);
+    client.set_strict_amounts(&other, &true);
+}
+
+#[test]
+fn test_min_bond_amount_defaults_to_protocol_minimum() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(client.get_min_bond_amount(), MIN_BOND_AMOUNT);
+}
+
+#[test]
+fn test_configured_min_bond_amount_enforced() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_min_bond_amount(&admin, &(100 * MIN_BOND_AMOUNT));
+    let identity = Address::generate(&e);
+    assert_eq!(
+        client
+            .try_create_bond(
+                &identity,
+                &(100 * MIN_BOND_AMOUNT - 1),
+                &86400_u64,
+                &false,
+                &0_u64,
+            )
+            .err(),
+        Some(Ok(BondError::InvalidAmount.into()))
+    );
+}
+
+#[test]
+fn test_min_bond_amount_can_be_updated() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_min_bond_amount(&admin, &(100 * MIN_BOND_AMOUNT));
+    let identity = Address::generate(&e);
+    let bond = client.create_bond(
+        &identity,
+        &(100 * MIN_BOND_AMOUNT),
+        &86400_u64,
+        &false,
+        &0_u64,
+    );
+    assert_eq!(bond.bonded_amount, 100 * MIN_BOND_AMOUNT);
+
+    client.set_min_bond_amount(&admin, &(10 * MIN_BOND_AMOUNT));
+    assert_eq!(client.get_min_bond_amount(), 10 * MIN_BOND_AMOUNT);
+    let other = Address::generate(&e);
+    client.create_bond(&other, &(10 * MIN_BOND_AMOUNT), &86400_u64, &false, &0_u64);
+
+    // Top-ups of an existing bond are not subject to the new-bond minimum
+    let bond = client.top_up(&identity, &MIN_BOND_AMOUNT);
+    assert_eq!(bond.bonded_amount, 101 * MIN_BOND_AMOUNT);
+}
+
+#[test]
+fn test_min_bond_amount_below_protocol_minimum_rejected() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    assert_eq!(
+        client.try_set_min_bond_amount(&admin, &0).err(),
+        Some(Ok(BondError::InvalidConfig.into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_min_bond_amount_requires_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    client.set_min_bond_amount(&Address::generate(&e), &(10 * MIN_BOND_AMOUNT));
+}
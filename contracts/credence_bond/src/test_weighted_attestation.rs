@@ -9,12 +9,12 @@ use soroban_sdk::{Env, String};
 fn setup(
     e: &Env,
 ) -> (
-    CredenceBondClient,
+    CredenceBondClient<'_>,
     soroban_sdk::Address,
     soroban_sdk::Address,
 ) {
     e.mock_all_auths();
-    let contract_id = e.register_contract(None, CredenceBond);
+    let contract_id = e.register(CredenceBond, ());
     let client = CredenceBondClient::new(e, &contract_id);
     let admin = soroban_sdk::Address::generate(e);
     client.initialize(&admin);
@@ -78,3 +78,15 @@ fn get_weight_config_returns_set_values() {
     assert_eq!(mult, 200);
     assert_eq!(max, 10_000);
 }
+
+#[test]
+fn negative_stake_rejected() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    assert_eq!(
+        client
+            .try_set_attester_stake(&admin, &attester, &-1i128)
+            .err(),
+        Some(Ok(AttestationError::NegativeStake.into()))
+    );
+}
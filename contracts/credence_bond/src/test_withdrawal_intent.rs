@@ -0,0 +1,164 @@
+//! Tests for signed withdrawal intents executed by relayers: deadline, nonce replay,
+//! penalty path selection and tamper resistance.
+
+#![cfg(test)]
+
+use crate::{CredenceBond, CredenceBondClient, DataKey, WithdrawalIntent};
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::{Address, Env, IntoVal};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+fn intent(
+    e: &Env,
+    identity: &Address,
+    amount: i128,
+    deadline: u64,
+    nonce: u64,
+) -> WithdrawalIntent {
+    WithdrawalIntent {
+        identity: identity.clone(),
+        amount,
+        destination: Address::generate(e),
+        deadline,
+        nonce,
+    }
+}
+
+/// Authorize exactly `signed` on behalf of its identity (nothing else is mocked).
+fn sign(e: &Env, client: &CredenceBondClient, signed: &WithdrawalIntent) {
+    e.mock_auths(&[MockAuth {
+        address: &signed.identity,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "execute_withdrawal_intent",
+            args: (signed.clone(),).into_val(e),
+            sub_invokes: &[],
+        },
+    }]);
+}
+
+#[test]
+fn test_relayer_executes_intent_after_lock_up() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    let signed = intent(&e, &identity, BOND_AMOUNT / 4, 1300, 7);
+
+    sign(&e, &client, &signed);
+    let bond = client.execute_withdrawal_intent(&signed);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT - BOND_AMOUNT / 4);
+    assert!(client.is_intent_nonce_used(&identity, &7));
+    assert!(!client.is_intent_nonce_used(&identity, &8));
+}
+
+#[test]
+fn test_used_nonce_kept_in_persistent_storage_until_deadline() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    // 30 days at ~5s per ledger
+    let deadline = 1200 + 30 * 86400;
+    let signed = intent(&e, &identity, 1_000_000, deadline, 3);
+    sign(&e, &client, &signed);
+    client.execute_withdrawal_intent(&signed);
+
+    let key = DataKey::IntentNonce(identity.clone(), 3);
+    e.as_contract(&client.address, || {
+        assert!(!e.storage().instance().has(&key));
+        assert_eq!(e.storage().persistent().get::<_, u64>(&key), Some(deadline));
+        assert_eq!(e.storage().persistent().get_ttl(&key), 518_401);
+    });
+}
+
+#[test]
+fn test_intent_before_lock_up_uses_early_exit_path() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_early_exit_config(&admin, &treasury, &1000_u32);
+    e.ledger().with_mut(|li| li.timestamp = 1050);
+    let signed = intent(&e, &identity, BOND_AMOUNT / 4, 1100, 0);
+
+    sign(&e, &client, &signed);
+    let bond = client.execute_withdrawal_intent(&signed);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT - BOND_AMOUNT / 4);
+}
+
+#[test]
+fn test_nonces_can_be_used_out_of_order() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    let second = intent(&e, &identity, 1_000_000, 2000, 2);
+    let first = intent(&e, &identity, 1_000_000, 2000, 1);
+
+    sign(&e, &client, &second);
+    client.execute_withdrawal_intent(&second);
+    sign(&e, &client, &first);
+    let bond = client.execute_withdrawal_intent(&first);
+    assert_eq!(bond.bonded_amount, BOND_AMOUNT - 2_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+fn test_expired_intent_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    let signed = intent(&e, &identity, 1_000_000, 1100, 0);
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    sign(&e, &client, &signed);
+    client.execute_withdrawal_intent(&signed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #801)")]
+fn test_replayed_intent_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    let signed = intent(&e, &identity, 1_000_000, 2000, 0);
+    sign(&e, &client, &signed);
+    client.execute_withdrawal_intent(&signed);
+    sign(&e, &client, &signed);
+    client.execute_withdrawal_intent(&signed);
+}
+
+#[test]
+fn test_relayer_cannot_alter_intent_fields() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    let signed = intent(&e, &identity, 1_000_000, 2000, 0);
+
+    let mut bigger = signed.clone();
+    bigger.amount = BOND_AMOUNT;
+    let mut redirected = signed.clone();
+    redirected.destination = Address::generate(&e);
+    let mut extended = signed.clone();
+    extended.deadline = u64::MAX;
+    let mut renonced = signed.clone();
+    renonced.nonce = 1;
+
+    for altered in [bigger, redirected, extended, renonced] {
+        sign(&e, &client, &signed);
+        assert!(client.try_execute_withdrawal_intent(&altered).is_err());
+    }
+    assert_eq!(
+        client.get_identity_state(&identity).bonded_amount,
+        BOND_AMOUNT
+    );
+}
@@ -0,0 +1,287 @@
+//! Tests for withdrawal receipts: sequential ids across withdrawal paths, receipt contents,
+//! per-identity index and persistence.
+
+#![cfg(test)]
+
+use crate::bond_store::{BUMP_TARGET, BUMP_THRESHOLD};
+use crate::receipts::DEFAULT_RECEIPT_RETENTION;
+use crate::{CredenceBond, CredenceBondClient, DataKey, ReceiptError, WithdrawalIntent};
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Env, Symbol};
+
+const BOND_AMOUNT: i128 = 1_000_000_000;
+
+fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let identity = Address::generate(e);
+    client.create_bond(&identity, &BOND_AMOUNT, &100_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+#[test]
+fn test_no_receipts_initially() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    assert_eq!(client.get_receipt_count(), 0);
+    assert_eq!(client.get_identity_receipts(&identity, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_receipt_ids_monotone_across_paths() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_early_exit_config(&admin, &treasury, &1000_u32);
+
+    // Early exit at half the lock-up: penalty = 10% * 50% of the amount.
+    e.ledger().with_mut(|li| li.timestamp = 1050);
+    client.withdraw_early(&identity, &100_000_000);
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    client.withdraw(&identity, &200_000_000);
+    client.withdraw_bond(&identity);
+
+    assert_eq!(client.get_receipt_count(), 3);
+    let ids = client.get_identity_receipts(&identity, &0, &10);
+    assert_eq!(ids.len(), 3);
+
+    let early = client.get_withdrawal_receipt(&ids.get(0).unwrap());
+    assert_eq!(early.id, 1);
+    assert_eq!(early.path, Symbol::new(&e, "withdraw_early"));
+    assert_eq!(early.gross, 100_000_000);
+    assert_eq!(early.penalty, 5_000_000);
+    assert_eq!(early.net, 95_000_000);
+    assert_eq!(early.destination, identity);
+    assert_eq!(early.timestamp, 1050);
+
+    let normal = client.get_withdrawal_receipt(&ids.get(1).unwrap());
+    assert_eq!(normal.id, 2);
+    assert_eq!(normal.path, Symbol::new(&e, "withdraw"));
+    assert_eq!(normal.penalty, 0);
+    assert_eq!(normal.net, 200_000_000);
+
+    let full = client.get_withdrawal_receipt(&ids.get(2).unwrap());
+    assert_eq!(full.id, 3);
+    assert_eq!(full.path, Symbol::new(&e, "withdraw_bond"));
+    assert_eq!(full.gross, 700_000_000);
+}
+
+#[test]
+fn test_intent_receipt_credits_destination() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1200);
+    let destination = Address::generate(&e);
+    client.execute_withdrawal_intent(&WithdrawalIntent {
+        identity: identity.clone(),
+        amount: 1_000_000,
+        destination: destination.clone(),
+        deadline: 2000,
+        nonce: 0,
+    });
+
+    let receipt = client.get_withdrawal_receipt(&1);
+    assert_eq!(receipt.identity, identity);
+    assert_eq!(receipt.destination, destination);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #900)")]
+fn test_unknown_receipt() {
+    let e = Env::default();
+    let (client, _admin, _identity) = setup(&e);
+    client.get_withdrawal_receipt(&1);
+}
+
+#[test]
+fn test_identity_receipts_paged_and_capped() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    assert_eq!(client.get_receipt_retention(), DEFAULT_RECEIPT_RETENTION);
+    client.set_receipt_retention(&admin, &3);
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    for _ in 0..5 {
+        client.withdraw(&identity, &1_000_000);
+    }
+
+    assert_eq!(
+        client.get_identity_receipts(&identity, &0, &10),
+        vec![&e, 3_u64, 4, 5]
+    );
+    assert_eq!(
+        client.get_identity_receipts(&identity, &1, &1),
+        vec![&e, 4_u64]
+    );
+    assert_eq!(client.get_identity_receipts(&identity, &3, &10).len(), 0);
+    // Dropped from the index, still retrievable by id
+    assert_eq!(client.get_withdrawal_receipt(&1).id, 1);
+}
+
+#[test]
+fn test_zero_receipt_retention_rejected() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup(&e);
+    assert_eq!(
+        client.try_set_receipt_retention(&admin, &0).err(),
+        Some(Ok(ReceiptError::InvalidRetention.into()))
+    );
+}
+
+fn receipt_ttl(e: &Env, client: &CredenceBondClient, key: &DataKey) -> u32 {
+    e.as_contract(&client.address, || e.storage().persistent().get_ttl(key))
+}
+
+#[test]
+fn test_receipts_outlive_minimum_ttl() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    client.withdraw(&identity, &1_000_000);
+    let keys = [
+        DataKey::WithdrawalReceipt(1),
+        DataKey::ChargeBreakdown(1),
+        DataKey::IdentityReceipts(identity.clone()),
+    ];
+    for key in &keys {
+        assert_eq!(receipt_ttl(&e, &client, key), BUMP_TARGET);
+    }
+
+    // Age well past the minimum persistent TTL, keeping the contract instance alive
+    let min_ttl = e.ledger().get().min_persistent_entry_ttl;
+    let elapsed = BUMP_TARGET - BUMP_THRESHOLD + 1;
+    assert!(elapsed > min_ttl);
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .extend_ttl(BUMP_TARGET, 2 * BUMP_TARGET)
+    });
+    e.ledger().with_mut(|li| li.sequence_number += elapsed);
+
+    assert_eq!(client.get_withdrawal_receipt(&1).gross, 1_000_000);
+    assert_eq!(client.explain_charge(&1).final_amount, 0);
+    assert_eq!(
+        client.get_identity_receipts(&identity, &0, &10),
+        vec![&e, 1_u64]
+    );
+    for key in &keys {
+        assert_eq!(receipt_ttl(&e, &client, key), BUMP_TARGET);
+    }
+}
+
+fn sum(items: &soroban_sdk::Vec<(Symbol, i128)>) -> i128 {
+    items.iter().map(|(_, amount)| amount).sum()
+}
+
+#[test]
+fn test_explain_charge_with_surge_surcharge() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_early_exit_config(&admin, &treasury, &1000_u32);
+    client.set_surge_penalty(&admin, &vec![&e, (1_000_u32, 15_000_u32)], &100_u64);
+
+    client.withdraw_early(&identity, &300_000_000);
+    client.withdraw_early(&identity, &100_000_001);
+
+    let first = client.explain_charge(&1);
+    assert_eq!(first.surcharges.len(), 0);
+    assert_eq!(first.final_amount, first.base_amount);
+
+    // base = floor(100_000_001 * 10%) = 10_000_000; surge 1.5x -> 15_000_000
+    let breakdown = client.explain_charge(&2);
+    assert_eq!(breakdown.rate_bps, 1000);
+    assert_eq!(breakdown.rounding, Symbol::new(&e, "floor"));
+    assert_eq!(breakdown.base_amount, 10_000_000);
+    assert_eq!(
+        breakdown.surcharges,
+        vec![&e, (Symbol::new(&e, "surge"), 5_000_000_i128)]
+    );
+    assert_eq!(
+        breakdown.base_amount + sum(&breakdown.surcharges) - sum(&breakdown.discounts),
+        breakdown.final_amount
+    );
+    assert_eq!(
+        breakdown.final_amount,
+        client.get_withdrawal_receipt(&2).penalty
+    );
+}
+
+#[test]
+fn test_explain_charge_without_penalty() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    client.withdraw(&identity, &1_000_000);
+    let breakdown = client.explain_charge(&1);
+    assert_eq!(breakdown.final_amount, 0);
+    assert_eq!(breakdown.rounding, Symbol::new(&e, "none"));
+}
+
+/// Minimal deterministic generator for property-style tests (no external crates).
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self, bound: i128) -> i128 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        ((self.0 >> 33) as i128) % bound
+    }
+}
+
+fn adjustments(
+    e: &Env,
+    rng: &mut Lcg,
+    name: &str,
+    bound: i128,
+) -> soroban_sdk::Vec<(Symbol, i128)> {
+    let mut out = soroban_sdk::Vec::new(e);
+    for _ in 0..rng.next(4) {
+        out.push_back((Symbol::new(e, name), rng.next(bound)));
+    }
+    out
+}
+
+#[test]
+fn test_finalize_charge_invariants_hold_for_random_adjustments() {
+    let e = Env::default();
+    let mut rng = Lcg(42);
+    for _ in 0..500 {
+        let gross = rng.next(BOND_AMOUNT) + 1;
+        let base = rng.next(gross + 1);
+        let discounts = adjustments(&e, &mut rng, "waiver", gross);
+        let surcharges = adjustments(&e, &mut rng, "surge", gross);
+
+        let b =
+            crate::receipts::finalize_charge(&e, gross, base, 500, "floor", discounts, surcharges);
+        let fee = b.final_amount;
+        let net = gross - fee;
+        assert!(0 <= fee && fee <= gross);
+        assert!(net <= gross);
+        assert_eq!(net + fee, gross);
+
+        let added: i128 = b.surcharges.iter().map(|(_, a)| a).sum();
+        let removed: i128 = b.discounts.iter().map(|(_, a)| a).sum();
+        assert_eq!(b.base_amount + added - removed, fee);
+    }
+}
+
+#[test]
+fn test_finalize_charge_records_clamps() {
+    let e = Env::default();
+    let discounts = vec![&e, (Symbol::new(&e, "waiver"), 300)];
+    let b = crate::receipts::finalize_charge(&e, 1000, 100, 0, "floor", discounts, vec![&e]);
+    assert_eq!(b.final_amount, 0);
+    assert_eq!(b.surcharges, vec![&e, (Symbol::new(&e, "floor_zero"), 200)]);
+
+    let surcharges = vec![&e, (Symbol::new(&e, "surge"), 5000)];
+    let b = crate::receipts::finalize_charge(&e, 1000, 100, 0, "floor", vec![&e], surcharges);
+    assert_eq!(b.final_amount, 1000);
+    assert_eq!(b.discounts, vec![&e, (Symbol::new(&e, "cap_gross"), 4100)]);
+}
@@ -1,43 +1,504 @@
 //! Tiered Bond System
 //!
-//! Assigns identity tiers (Bronze, Silver, Gold, Platinum) based on bonded amount thresholds.
+//! Assigns identity tiers (Bronze, Silver, Gold, Platinum, Diamond) based on bonded amount
+//! thresholds.
 //! Supports tier upgrade on bond increase and tier downgrade on partial withdrawal.
 //! Emits tier change events when tier changes.
+//!
+//! Thresholds are admin-configurable via `TierConfig`; the constants below are the defaults
+//! used until a config is set. Tiers are ordered by `tier_rank`, never by enum declaration
+//! order.
+//!
+//! ## Legacy config
+//! Configs set before Diamond existed have no `diamond_min` and are stored under the former
+//! key. They are still read, with the default Diamond minimum (or `platinum_min + 1` if that
+//! is higher), until the next config write replaces them.
+//!
+//! ## Hysteresis
+//! Each identity's tier is recorded in persistent storage when its bond changes, with the same
+//! TTL bumps as bonds. With a hysteresis margin set, a
+//! recorded tier is kept until the bonded amount falls below `threshold * (1 - margin)`, so a
+//! small withdrawal just under a threshold does not demote. Upgrades use the nominal thresholds.
+//!
+//! ## History
+//! Every tier change is also stored per identity in persistent storage under a sequence
+//! number, so the contract can answer when an identity reached its tier without an indexer.
+//! Only the latest `MAX_TIER_HISTORY` changes are kept; older ones are pruned as new ones land.
+//!
+//! ## Qualification
+//! For each tier above Bronze, every bond write records since when the bond's unslashed amount
+//! has continuously met the tier minimum; a withdrawal or slash below it clears the entry. With
+//! a qualification period set, the effective tier is the highest tier held that long, capped
+//! at the instantaneous tier. With no period (the default) the effective tier is the
+//! instantaneous tier.
+
+use crate::bond_store::{BUMP_TARGET, BUMP_THRESHOLD};
+use crate::{events, BondError, BondTier, IdentityBond, TierChangeRecord, TierConfig};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Symbol, Vec};
+
+/// Storage key for the tier threshold config.
+const KEY_TIER_CONFIG: &str = "tier_thresholds";
+/// Storage key for configs set before Diamond existed (`LegacyTierConfig`).
+const KEY_LEGACY_TIER_CONFIG: &str = "tier_config";
+/// Storage key for the downgrade hysteresis margin (bps).
+const KEY_TIER_HYSTERESIS: &str = "tier_hysteresis";
+/// Storage key for the tier qualification period (seconds).
+const KEY_TIER_QUALIFICATION: &str = "tier_qualification";
 
-use crate::BondTier;
-use soroban_sdk::Env;
+/// Marks a tier the bond does not currently meet in the qualification record.
+const NOT_QUALIFIED: u64 = u64::MAX;
+
+/// Maximum hysteresis margin: 50%.
+pub const MAX_HYSTERESIS_BPS: u32 = 5_000;
+
+/// Maximum tier changes kept per identity.
+pub const MAX_TIER_HISTORY: u32 = 50;
+
+/// Storage keys for per-identity tier state.
+#[contracttype]
+pub enum TierDataKey {
+    /// Tier last recorded for the identity's bond (persistent storage).
+    Recorded(Address),
+    /// Tier change by (identity, sequence number).
+    Change(Address, u32),
+    /// Number of tier changes ever recorded for the identity.
+    ChangeCount(Address),
+    /// Since when the bond has met the Silver, Gold, Platinum and Diamond minimums
+    /// (`NOT_QUALIFIED` if it does not). Records written before Diamond have three entries.
+    QualifiedSince(Address),
+}
+
+/// Threshold config layout used before Diamond existed.
+#[contracttype]
+#[derive(Clone)]
+pub(crate) struct LegacyTierConfig {
+    pub bronze_min: i128,
+    pub silver_min: i128,
+    pub gold_min: i128,
+    pub platinum_min: i128,
+}
 
 /// Tier thresholds (in smallest unit, e.g. 6 decimals for USDC).
-/// Bronze: [0, BRONZE_MAX), Silver: [BRONZE_MAX, SILVER_MAX), Gold: [SILVER_MAX, GOLD_MAX), Platinum: [GOLD_MAX, ..)
+/// Bronze: [0, BRONZE_MAX), Silver: [BRONZE_MAX, SILVER_MAX), Gold: [SILVER_MAX, GOLD_MAX),
+/// Platinum: [GOLD_MAX, PLATINUM_MAX), Diamond: [PLATINUM_MAX, ..)
 pub const TIER_BRONZE_MAX: i128 = 1_000_000_000; // 1000 * 10^6
 pub const TIER_SILVER_MAX: i128 = 5_000_000_000; // 5000 * 10^6
 pub const TIER_GOLD_MAX: i128 = 20_000_000_000; // 20000 * 10^6
+pub const TIER_PLATINUM_MAX: i128 = 100_000_000_000; // 100000 * 10^6
+
+/// Returns the default thresholds (Bronze from 0, then the `TIER_*_MAX` breakpoints).
+#[must_use]
+pub fn default_tier_config() -> TierConfig {
+    TierConfig {
+        bronze_min: 0,
+        silver_min: TIER_BRONZE_MAX,
+        gold_min: TIER_SILVER_MAX,
+        platinum_min: TIER_GOLD_MAX,
+        diamond_min: TIER_PLATINUM_MAX,
+    }
+}
+
+/// Read a config set before Diamond existed, completing it with a Diamond minimum.
+fn load_legacy_config(e: &Env) -> Option<TierConfig> {
+    e.storage()
+        .instance()
+        .get::<_, LegacyTierConfig>(&Symbol::new(e, KEY_LEGACY_TIER_CONFIG))
+        .map(|legacy| TierConfig {
+            bronze_min: legacy.bronze_min,
+            silver_min: legacy.silver_min,
+            gold_min: legacy.gold_min,
+            platinum_min: legacy.platinum_min,
+            diamond_min: TIER_PLATINUM_MAX.max(legacy.platinum_min.saturating_add(1)),
+        })
+}
+
+/// Returns the configured thresholds, or the defaults if none are set.
+#[must_use]
+pub fn get_tier_config(e: &Env) -> TierConfig {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_TIER_CONFIG))
+        .or_else(|| load_legacy_config(e))
+        .unwrap_or_else(default_tier_config)
+}
+
+/// Validate tier thresholds.
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if `bronze_min` is negative or the minimums are not strictly
+///   ascending
+pub fn validate_tier_config(e: &Env, config: &TierConfig) {
+    if config.bronze_min < 0
+        || config.silver_min <= config.bronze_min
+        || config.gold_min <= config.silver_min
+        || config.platinum_min <= config.gold_min
+        || config.diamond_min <= config.platinum_min
+    {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+}
+
+/// Set the tier thresholds. Only admin should call (enforced by caller).
+/// Emits `tier_thresholds_updated` with (bronze_min, silver_min, gold_min, platinum_min,
+/// diamond_min).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if `bronze_min` is negative or the minimums are not strictly
+///   ascending
+pub fn set_tier_config(e: &Env, config: &TierConfig) {
+    validate_tier_config(e, config);
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_TIER_CONFIG), config);
+    e.storage()
+        .instance()
+        .remove(&Symbol::new(e, KEY_LEGACY_TIER_CONFIG));
+    events::publish(
+        e,
+        (Symbol::new(e, "tier_thresholds_updated"),),
+        (
+            config.bronze_min,
+            config.silver_min,
+            config.gold_min,
+            config.platinum_min,
+            config.diamond_min,
+        ),
+    );
+}
 
-/// Returns the tier for a given bonded amount.
+/// Returns the tier for a bonded amount under `config`. Amounts below `silver_min`
+/// (including any below `bronze_min`) are Bronze.
 #[must_use]
-pub fn get_tier_for_amount(amount: i128) -> BondTier {
-    if amount < TIER_BRONZE_MAX {
+pub fn get_tier_for_amount_with_config(amount: i128, config: &TierConfig) -> BondTier {
+    if amount < config.silver_min {
         BondTier::Bronze
-    } else if amount < TIER_SILVER_MAX {
+    } else if amount < config.gold_min {
         BondTier::Silver
-    } else if amount < TIER_GOLD_MAX {
+    } else if amount < config.platinum_min {
         BondTier::Gold
-    } else {
+    } else if amount < config.diamond_min {
         BondTier::Platinum
+    } else {
+        BondTier::Diamond
+    }
+}
+
+/// Returns the minimum amount of the tier above `tier` under `config` (`None` at Diamond).
+#[must_use]
+pub fn next_tier_min(tier: BondTier, config: &TierConfig) -> Option<i128> {
+    match tier {
+        BondTier::Bronze => Some(config.silver_min),
+        BondTier::Silver => Some(config.gold_min),
+        BondTier::Gold => Some(config.platinum_min),
+        BondTier::Platinum => Some(config.diamond_min),
+        BondTier::Diamond => None,
+    }
+}
+
+/// Returns the tier for a bonded amount under the configured thresholds.
+#[must_use]
+pub fn get_tier(e: &Env, amount: i128) -> BondTier {
+    get_tier_for_amount_with_config(amount, &get_tier_config(e))
+}
+
+/// Returns the downgrade hysteresis margin in bps (default 0 = none).
+#[must_use]
+pub fn get_hysteresis_bps(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_TIER_HYSTERESIS))
+        .unwrap_or(0)
+}
+
+/// Set the downgrade hysteresis margin. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if `margin_bps` exceeds `MAX_HYSTERESIS_BPS`
+pub fn set_hysteresis_bps(e: &Env, margin_bps: u32) {
+    if margin_bps > MAX_HYSTERESIS_BPS {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_TIER_HYSTERESIS), &margin_bps);
+}
+
+/// Returns the tier last recorded for the identity's bond, if any.
+#[must_use]
+pub fn get_recorded_tier(e: &Env, identity: &Address) -> Option<BondTier> {
+    e.storage()
+        .persistent()
+        .get(&TierDataKey::Recorded(identity.clone()))
+}
+
+/// Extend the TTL of the identity's recorded tier alongside its bond.
+pub fn bump_ttl(e: &Env, identity: &Address) {
+    crate::bond_store::bump_if_present(e, &TierDataKey::Recorded(identity.clone()));
+}
+
+/// Forget the identity's recorded tier (a new bond starts from its nominal tier).
+pub fn clear_recorded_tier(e: &Env, identity: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&TierDataKey::Recorded(identity.clone()));
+}
+
+/// Returns the tier's position in the tier order, Bronze = 0 up to Diamond = 4. Compare
+/// tiers with this rather than relying on enum declaration order.
+#[must_use]
+pub fn tier_rank(tier: &BondTier) -> u32 {
+    match tier {
+        BondTier::Bronze => 0,
+        BondTier::Silver => 1,
+        BondTier::Gold => 2,
+        BondTier::Platinum => 3,
+        BondTier::Diamond => 4,
     }
 }
 
-/// Emits a tier change event if the tier changed.
+fn tier_from_rank(rank: u32) -> BondTier {
+    match rank {
+        0 => BondTier::Bronze,
+        1 => BondTier::Silver,
+        2 => BondTier::Gold,
+        3 => BondTier::Platinum,
+        _ => BondTier::Diamond,
+    }
+}
+
+/// Returns the tier qualification period in seconds (0 = none).
+#[must_use]
+pub fn get_qualification_period(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_TIER_QUALIFICATION))
+        .unwrap_or(0)
+}
+
+/// Set the tier qualification period. Only admin should call (enforced by caller).
+pub fn set_qualification_period(e: &Env, period: u64) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_TIER_QUALIFICATION), &period);
+}
+
+/// The amount a bond qualifies for tiers with: unslashed, and 0 once the bond is closed.
+fn qualifying_amount(bond: &IdentityBond) -> i128 {
+    if bond.active {
+        bond.bonded_amount.saturating_sub(bond.slashed_amount)
+    } else {
+        0
+    }
+}
+
+/// Since-timestamps for the tiers above Bronze that `amount` meets, carrying over `held`
+/// entries that are still met and starting met-but-unheld ones at `now`.
+fn qualified_since(e: &Env, amount: i128, held: &Vec<u64>, now: u64) -> Vec<u64> {
+    let config = get_tier_config(e);
+    let mut since = Vec::new(e);
+    for (i, min) in [
+        config.silver_min,
+        config.gold_min,
+        config.platinum_min,
+        config.diamond_min,
+    ]
+    .iter()
+    .enumerate()
+    {
+        let entry = if amount < *min {
+            NOT_QUALIFIED
+        } else {
+            match held.get(i as u32) {
+                Some(t) if t != NOT_QUALIFIED => t,
+                _ => now,
+            }
+        };
+        since.push_back(entry);
+    }
+    since
+}
+
+/// Update the bond's qualification record after a bond write.
+pub fn track_qualification(e: &Env, bond: &IdentityBond) {
+    let key = TierDataKey::QualifiedSince(bond.identity.clone());
+    let held: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(e));
+    let since = qualified_since(e, qualifying_amount(bond), &held, e.ledger().timestamp());
+    if since != held {
+        e.storage().instance().set(&key, &since);
+    }
+}
+
+/// Returns the highest tier the bond has met for at least the qualification period, capped at
+/// its instantaneous tier. Bonds written before qualification tracking count from `bond_start`.
+#[must_use]
+pub fn get_effective_tier(e: &Env, bond: &IdentityBond) -> BondTier {
+    let instant = get_identity_tier(e, &bond.identity, bond.bonded_amount);
+    let period = get_qualification_period(e);
+    if period == 0 {
+        return instant;
+    }
+    let since: Vec<u64> = e
+        .storage()
+        .instance()
+        .get(&TierDataKey::QualifiedSince(bond.identity.clone()))
+        .unwrap_or_else(|| {
+            let untracked = Vec::from_array(e, [bond.bond_start; 4]);
+            qualified_since(e, qualifying_amount(bond), &untracked, bond.bond_start)
+        });
+    let now = e.ledger().timestamp();
+    let mut effective = 0;
+    for (i, t) in since.iter().enumerate() {
+        if t != NOT_QUALIFIED && now.saturating_sub(t) >= period {
+            effective = i as u32 + 1;
+        }
+    }
+    tier_from_rank(effective.min(tier_rank(&instant)))
+}
+
+/// Thresholds lowered by `margin_bps`, used to decide whether a recorded tier is kept.
+fn downgrade_config(config: &TierConfig, margin_bps: u32) -> TierConfig {
+    let keep = i128::from(10_000 - margin_bps);
+    let lower = |min: i128| min.saturating_mul(keep) / 10_000;
+    TierConfig {
+        bronze_min: lower(config.bronze_min),
+        silver_min: lower(config.silver_min),
+        gold_min: lower(config.gold_min),
+        platinum_min: lower(config.platinum_min),
+        diamond_min: lower(config.diamond_min),
+    }
+}
+
+/// Returns the tier for `amount` given the tier previously held: upgrades at the nominal
+/// thresholds, downgrades only below the thresholds lowered by the hysteresis margin.
+#[must_use]
+pub fn get_tier_with_hysteresis(e: &Env, amount: i128, held: Option<BondTier>) -> BondTier {
+    let config = get_tier_config(e);
+    let nominal = get_tier_for_amount_with_config(amount, &config);
+    let margin_bps = get_hysteresis_bps(e);
+    match held {
+        Some(held) if margin_bps > 0 && tier_rank(&held) > tier_rank(&nominal) => {
+            let kept =
+                get_tier_for_amount_with_config(amount, &downgrade_config(&config, margin_bps));
+            if tier_rank(&kept) < tier_rank(&held) {
+                kept
+            } else {
+                held
+            }
+        }
+        _ => nominal,
+    }
+}
+
+/// Returns the identity's tier for its current bonded amount, honouring the recorded tier.
+#[must_use]
+pub fn get_identity_tier(e: &Env, identity: &Address, amount: i128) -> BondTier {
+    get_tier_with_hysteresis(e, amount, get_recorded_tier(e, identity))
+}
+
+/// Recompute the identity's tier after its bonded amount moved from `old_amount` to
+/// `new_amount`, record it and emit `tier_changed` if it changed. Returns the new tier.
+pub fn update_tier(e: &Env, identity: &Address, old_amount: i128, new_amount: i128) -> BondTier {
+    let old_tier = get_identity_tier(e, identity, old_amount);
+    let new_tier = get_tier_with_hysteresis(e, new_amount, Some(old_tier.clone()));
+    let key = TierDataKey::Recorded(identity.clone());
+    e.storage().persistent().set(&key, &new_tier);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+    if emit_tier_change_if_needed(e, identity, old_tier.clone(), new_tier.clone()) {
+        record_tier_change(
+            e,
+            identity,
+            &TierChangeRecord {
+                old_tier,
+                new_tier: new_tier.clone(),
+                amount: new_amount,
+                timestamp: e.ledger().timestamp(),
+            },
+        );
+    }
+    new_tier
+}
+
+/// Emits a tier change event if the tier changed. Returns whether it did.
 pub fn emit_tier_change_if_needed(
     e: &Env,
     identity: &soroban_sdk::Address,
     old_tier: BondTier,
     new_tier: BondTier,
-) {
-    if core::mem::discriminant(&old_tier) != core::mem::discriminant(&new_tier) {
-        e.events().publish(
-            (soroban_sdk::Symbol::new(e, "tier_changed"),),
-            (identity.clone(), new_tier),
-        );
+) -> bool {
+    if core::mem::discriminant(&old_tier) == core::mem::discriminant(&new_tier) {
+        return false;
+    }
+    events::publish(
+        e,
+        (soroban_sdk::Symbol::new(e, "tier_changed"),),
+        (identity.clone(), new_tier),
+    );
+    true
+}
+
+/// Returns the number of tier changes ever recorded for the identity (including pruned ones).
+#[must_use]
+pub fn get_tier_change_count(e: &Env, identity: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&TierDataKey::ChangeCount(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Append a tier change to the identity's history, pruning the oldest entry once
+/// `MAX_TIER_HISTORY` are kept.
+pub fn record_tier_change(e: &Env, identity: &Address, record: &TierChangeRecord) {
+    let seq = get_tier_change_count(e, identity);
+    let storage = e.storage().persistent();
+    storage.set(&TierDataKey::Change(identity.clone(), seq), record);
+    storage.set(&TierDataKey::ChangeCount(identity.clone()), &(seq + 1));
+    if seq >= MAX_TIER_HISTORY {
+        storage.remove(&TierDataKey::Change(
+            identity.clone(),
+            seq - MAX_TIER_HISTORY,
+        ));
+    }
+}
+
+/// Returns up to `limit` (capped at `MAX_TIER_HISTORY`) tier changes, oldest first, starting
+/// at sequence number `start`. Pruned sequence numbers are skipped.
+#[must_use]
+pub fn get_tier_history(
+    e: &Env,
+    identity: &Address,
+    start: u32,
+    limit: u32,
+) -> Vec<TierChangeRecord> {
+    let count = get_tier_change_count(e, identity);
+    let first = start.max(count.saturating_sub(MAX_TIER_HISTORY));
+    let end = first.saturating_add(limit.min(MAX_TIER_HISTORY)).min(count);
+    let mut history = Vec::new(e);
+    for seq in first..end {
+        if let Some(record) = e
+            .storage()
+            .persistent()
+            .get(&TierDataKey::Change(identity.clone(), seq))
+        {
+            history.push_back(record);
+        }
+    }
+    history
+}
+
+/// Returns the identity's latest tier change, if any is recorded.
+#[must_use]
+pub fn latest_tier_change(e: &Env, identity: &Address) -> Option<TierChangeRecord> {
+    let count = get_tier_change_count(e, identity);
+    if count == 0 {
+        return None;
     }
+    e.storage()
+        .persistent()
+        .get(&TierDataKey::Change(identity.clone(), count - 1))
 }
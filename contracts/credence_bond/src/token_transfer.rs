@@ -0,0 +1,70 @@
+//! Bond Token Transfers
+//!
+//! Moves the bonded token between identities, the contract and the treasury, and burns
+//! penalties in burn mode. The token address is set by the admin via `set_token`; until it is
+//! set, bonds are bookkeeping only and every transfer here is a no-op.
+//!
+//! Inbound transfers run before the bond is persisted and outbound transfers after, so a
+//! failing token call aborts the invocation and no bond mutation is kept.
+
+use soroban_sdk::{panic_with_error, token, Address, Env, Symbol};
+
+use crate::BondError;
+
+/// Storage key for the bond token address.
+const KEY_TOKEN: &str = "bond_token";
+
+/// Returns the configured bond token, if any.
+#[must_use]
+pub fn get_token(e: &Env) -> Option<Address> {
+    e.storage().instance().get(&Symbol::new(e, KEY_TOKEN))
+}
+
+/// Set the bond token. Only admin should call (enforced by caller).
+pub fn set_token(e: &Env, token: &Address) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_TOKEN), token);
+}
+
+/// Pull `amount` from `from` into the contract. No-op when no token is set or amount is zero;
+/// fails with `BondError::InvalidAmount` for a negative amount.
+pub fn transfer_in(e: &Env, from: &Address, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    if amount == 0 {
+        return;
+    }
+    if let Some(token) = get_token(e) {
+        token::Client::new(e, &token).transfer(from, &e.current_contract_address(), &amount);
+    }
+}
+
+/// Pay `amount` from the contract to `to`. No-op when no token is set or amount is zero;
+/// fails with `BondError::InvalidAmount` for a negative amount.
+pub fn transfer_out(e: &Env, to: &Address, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    if amount == 0 {
+        return;
+    }
+    if let Some(token) = get_token(e) {
+        token::Client::new(e, &token).transfer(&e.current_contract_address(), to, &amount);
+    }
+}
+
+/// Burn `amount` of the contract's own balance. No-op when no token is set or amount is zero;
+/// fails with `BondError::InvalidAmount` for a negative amount.
+pub fn burn(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic_with_error!(e, BondError::InvalidAmount);
+    }
+    if amount == 0 {
+        return;
+    }
+    if let Some(token) = get_token(e) {
+        token::Client::new(e, &token).burn(&e.current_contract_address(), &amount);
+    }
+}
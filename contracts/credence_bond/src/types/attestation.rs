@@ -4,7 +4,9 @@
 //! subject (identity), timestamp, weight. Supports serialization via ContractType
 //! and validation methods for storage efficiency and safety.
 
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, String};
+
+use crate::AttestationError;
 
 /// Maximum allowed attestation weight (prevents overflow and caps influence).
 pub const MAX_ATTESTATION_WEIGHT: u32 = 1_000_000;
@@ -41,14 +43,15 @@ impl Attestation {
     /// Validates that weight is within allowed bounds.
     ///
     /// # Errors
-    /// Panics if `weight` is zero or exceeds `MAX_ATTESTATION_WEIGHT`.
+    /// * `AttestationError::ZeroWeight` if `weight` is zero
+    /// * `AttestationError::WeightExceedsMaximum` if `weight` exceeds `MAX_ATTESTATION_WEIGHT`
     #[inline]
-    pub fn validate_weight(weight: u32) {
+    pub fn validate_weight(e: &Env, weight: u32) {
         if weight == 0 {
-            panic!("attestation weight must be positive");
+            panic_with_error!(e, AttestationError::ZeroWeight);
         }
         if weight > MAX_ATTESTATION_WEIGHT {
-            panic!("attestation weight exceeds maximum");
+            panic_with_error!(e, AttestationError::WeightExceedsMaximum);
         }
     }
 
@@ -2,6 +2,14 @@
 //!
 //! Provides validation functions for bond amounts to ensure they fall within acceptable ranges.
 //! This module centralizes the validation logic for minimum and maximum bond amounts.
+//!
+//! In strict mode every amount parameter must also be an exact multiple of a configured
+//! quantum (e.g. the token's smallest accepted increment), so amounts computed off-chain
+//! with stray precision are rejected instead of silently bonded.
+
+use soroban_sdk::{panic_with_error, Env, Symbol};
+
+use crate::{AmountError, BondError};
 
 /// Minimum bond amount (1 USDC with 6 decimals = 1_000_000)
 pub const MIN_BOND_AMOUNT: i128 = 1_000_000; // 1 token (assuming 6 decimals like USDC)
@@ -10,29 +18,128 @@ pub const MIN_BOND_AMOUNT: i128 = 1_000_000; // 1 token (assuming 6 decimals lik
 pub const MAX_BOND_AMOUNT: i128 = 100_000_000_000_000; // 100M tokens (assuming 6 decimals)
 
 /// Validates that a bond amount is within acceptable bounds.
-/// 
+///
 /// # Arguments
 /// * `amount` - The bond amount to validate
-/// 
+///
 /// # Panics
-/// * If amount is less than MIN_BOND_AMOUNT
-/// * If amount is greater than MAX_BOND_AMOUNT
-/// * If amount is negative
-pub fn validate_bond_amount(amount: i128) {
+/// * `BondError::InvalidAmount` if amount is negative
+/// * `AmountError::BelowMinimum` if amount is less than MIN_BOND_AMOUNT
+/// * `AmountError::AboveMaximum` if amount is greater than MAX_BOND_AMOUNT
+pub fn validate_bond_amount(e: &Env, amount: i128) {
     if amount < 0 {
-        panic!("bond amount cannot be negative");
+        panic_with_error!(e, BondError::InvalidAmount);
     }
-    
+
     if amount < MIN_BOND_AMOUNT {
-        panic!("bond amount below minimum required: {} (minimum: {})", amount, MIN_BOND_AMOUNT);
+        panic_with_error!(e, AmountError::BelowMinimum);
     }
-    
+
     if amount > MAX_BOND_AMOUNT {
-        panic!("bond amount exceeds maximum allowed: {} (maximum: {})", amount, MAX_BOND_AMOUNT);
+        panic_with_error!(e, AmountError::AboveMaximum);
+    }
+}
+
+/// Storage key for the admin-configured minimum bond amount.
+const KEY_MIN_BOND: &str = "min_bond";
+
+/// Returns the minimum amount a new bond must lock (default `MIN_BOND_AMOUNT`).
+#[must_use]
+pub fn get_min_bond_amount(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_MIN_BOND))
+        .unwrap_or(MIN_BOND_AMOUNT)
+}
+
+/// Validate a minimum bond amount against the protocol bounds.
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if min_amount is outside `MIN_BOND_AMOUNT..=MAX_BOND_AMOUNT`
+pub fn validate_min_bond_amount(e: &Env, min_amount: i128) {
+    if !(MIN_BOND_AMOUNT..=MAX_BOND_AMOUNT).contains(&min_amount) {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+}
+
+/// Set the minimum bond amount. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if min_amount is outside `MIN_BOND_AMOUNT..=MAX_BOND_AMOUNT`
+pub fn set_min_bond_amount(e: &Env, min_amount: i128) {
+    validate_min_bond_amount(e, min_amount);
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_MIN_BOND), &min_amount);
+}
+
+/// Validates that a new bond meets the configured minimum (on top of `validate_bond_amount`).
+///
+/// # Panics
+/// * `BondError::InvalidAmount` if amount is below the configured minimum bond amount
+pub fn validate_min_bond(e: &Env, amount: i128) {
+    if amount < get_min_bond_amount(e) {
+        panic_with_error!(e, BondError::InvalidAmount);
     }
 }
 
+/// Storage key for the strict amounts flag.
+const KEY_STRICT_AMOUNTS: &str = "strict_amounts";
+/// Storage key for the amount quantum used in strict mode.
+const KEY_AMOUNT_QUANTUM: &str = "amount_quantum";
+
+/// Returns true if strict amount mode is enabled (default false).
+#[must_use]
+pub fn is_strict_amounts(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_STRICT_AMOUNTS))
+        .unwrap_or(false)
+}
+
+/// Enable or disable strict amount mode. Only admin should call (enforced by caller).
+pub fn set_strict_amounts(e: &Env, enabled: bool) {
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_STRICT_AMOUNTS), &enabled);
+}
+
+/// Returns the configured amount quantum (default 1, i.e. every amount conforms).
+#[must_use]
+pub fn get_amount_quantum(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_AMOUNT_QUANTUM))
+        .unwrap_or(1)
+}
+
+/// Set the amount quantum. Only admin should call (enforced by caller).
+///
+/// # Panics
+/// * `BondError::InvalidConfig` if quantum is not positive
+pub fn set_amount_quantum(e: &Env, quantum: i128) {
+    if quantum <= 0 {
+        panic_with_error!(e, BondError::InvalidConfig);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_AMOUNT_QUANTUM), &quantum);
+}
 
+/// Validates that an amount parameter conforms to the configured quantum when strict mode is on.
+/// Shared by every entrypoint that accepts an amount (create, top-up, withdraw, slash).
+///
+/// # Panics
+/// * `AmountError::NonConformingAmount` if strict mode is enabled and amount is not an exact
+///   multiple of the quantum
+pub fn validate_amount_conforms(e: &Env, amount: i128) {
+    if !is_strict_amounts(e) {
+        return;
+    }
+    if amount % get_amount_quantum(e) != 0 {
+        panic_with_error!(e, AmountError::NonConformingAmount);
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -40,35 +147,38 @@ mod tests {
 
     #[test]
     fn test_validate_bond_amount_valid() {
+        let e = Env::default();
         // Test valid amounts within range
-        validate_bond_amount(MIN_BOND_AMOUNT);
-        validate_bond_amount(MAX_BOND_AMOUNT);
-        validate_bond_amount((MIN_BOND_AMOUNT + MAX_BOND_AMOUNT) / 2);
+        validate_bond_amount(&e, MIN_BOND_AMOUNT);
+        validate_bond_amount(&e, MAX_BOND_AMOUNT);
+        validate_bond_amount(&e, (MIN_BOND_AMOUNT + MAX_BOND_AMOUNT) / 2);
     }
 
     #[test]
-    #[should_panic(expected = "bond amount below minimum required")]
+    #[should_panic(expected = "Error(Contract, #701)")]
     fn test_validate_bond_amount_below_minimum() {
-        validate_bond_amount(MIN_BOND_AMOUNT - 1);
+        let e = Env::default();
+        validate_bond_amount(&e, MIN_BOND_AMOUNT - 1);
     }
 
     #[test]
-    #[should_panic(expected = "bond amount below minimum required")]
+    #[should_panic(expected = "Error(Contract, #701)")]
     fn test_validate_bond_amount_zero() {
-        validate_bond_amount(0);
+        let e = Env::default();
+        validate_bond_amount(&e, 0);
     }
 
     #[test]
-    #[should_panic(expected = "bond amount cannot be negative")]
+    #[should_panic(expected = "Error(Contract, #5)")]
     fn test_validate_bond_amount_negative() {
-        validate_bond_amount(-1);
+        let e = Env::default();
+        validate_bond_amount(&e, -1);
     }
 
     #[test]
-    #[should_panic(expected = "bond amount exceeds maximum allowed")]
+    #[should_panic(expected = "Error(Contract, #702)")]
     fn test_validate_bond_amount_above_maximum() {
-        validate_bond_amount(MAX_BOND_AMOUNT + 1);
+        let e = Env::default();
+        validate_bond_amount(&e, MAX_BOND_AMOUNT + 1);
     }
-
-
-}
\ No newline at end of file
+}
@@ -4,10 +4,10 @@
 //! a configurable multiplier and a protocol cap. When attester bond changes,
 //! new attestations use the new weight; existing attestations retain their stored weight.
 
-use soroban_sdk::Env;
+use soroban_sdk::{panic_with_error, Env};
 
 use crate::types::attestation::MAX_ATTESTATION_WEIGHT;
-use crate::DataKey;
+use crate::{AttestationError, DataKey};
 
 /// Default weight multiplier in basis points (1 = 0.01%). weight = stake * multiplier_bps / 10_000.
 pub const DEFAULT_WEIGHT_MULTIPLIER_BPS: u32 = 100;
@@ -47,9 +47,10 @@ pub fn get_attester_stake(e: &Env, attester: &soroban_sdk::Address) -> i128 {
 }
 
 /// Sets attester stake (e.g. from bond). Caller must be admin.
+/// Panics with `AttestationError::NegativeStake` if `amount` is negative.
 pub fn set_attester_stake(e: &Env, attester: &soroban_sdk::Address, amount: i128) {
     if amount < 0 {
-        panic!("attester stake cannot be negative");
+        panic_with_error!(e, AttestationError::NegativeStake);
     }
     e.storage()
         .instance()